@@ -0,0 +1,112 @@
+use crate::date_time::FileTime;
+use crate::structs::Guid;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+
+/// The type of a directory entry.
+#[derive(Debug, Serialize, Deserialize, PartialEq, Clone, Copy)]
+pub enum ObjectType {
+    Unallocated,
+    Storage,
+    Stream,
+    RootStorage,
+    UNKNOWN(u8),
+}
+
+impl From<u8> for ObjectType {
+    fn from(num: u8) -> Self {
+        match num {
+            0x00 => ObjectType::Unallocated,
+            0x01 => ObjectType::Storage,
+            0x02 => ObjectType::Stream,
+            0x05 => ObjectType::RootStorage,
+            other => ObjectType::UNKNOWN(other),
+        }
+    }
+}
+
+/// [Compound File Directory Entry](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-cfb/60fe8611-66c3-496b-b70d-a504c94c9ace) struct parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DirectoryEntry {
+    /// The index of the entry in the directory (the stream ID).
+    pub id: u32,
+    pub name: String,
+    pub object_type: ObjectType,
+    pub color: u8,
+    pub left_sibling_id: u32,
+    pub right_sibling_id: u32,
+    pub child_id: u32,
+    pub clsid: Guid,
+    pub state_bits: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ctime: Option<FileTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mtime: Option<FileTime>,
+    pub starting_sector: u32,
+    pub stream_size: u64,
+}
+
+impl DirectoryEntry {
+    pub const SIZE: usize = 0x80;
+    /// The value of sibling and child IDs that do not point to an entry.
+    pub const NOSTREAM: u32 = 0xFFFFFFFF;
+
+    pub fn from_buffer(buf: &[u8], id: u32) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf), id)
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R, id: u32) -> Result<Self, ReaderError> {
+        let mut name_data = [0; 64];
+        r.read_exact(&mut name_data)?;
+        let name_size = r.read_u16::<LittleEndian>()? as usize;
+        let name_chars = name_data[..name_size.min(64)]
+            .chunks_exact(2)
+            .map(|c| u16::from_le_bytes([c[0], c[1]]))
+            .take_while(|c| *c != 0)
+            .collect::<Vec<u16>>();
+        let name = String::from_utf16_lossy(&name_chars);
+        let object_type = ObjectType::from(r.read_u8()?);
+        let color = r.read_u8()?;
+        let left_sibling_id = r.read_u32::<LittleEndian>()?;
+        let right_sibling_id = r.read_u32::<LittleEndian>()?;
+        let child_id = r.read_u32::<LittleEndian>()?;
+        let clsid = Guid::from_reader(r)?;
+        let state_bits = r.read_u32::<LittleEndian>()?;
+        let ctime = match r.read_u64::<LittleEndian>()? {
+            0 => None,
+            timestamp => Some(FileTime::new(timestamp)),
+        };
+        let mtime = match r.read_u64::<LittleEndian>()? {
+            0 => None,
+            timestamp => Some(FileTime::new(timestamp)),
+        };
+        let starting_sector = r.read_u32::<LittleEndian>()?;
+        let stream_size = r.read_u64::<LittleEndian>()?;
+
+        Ok(Self {
+            id,
+            name,
+            object_type,
+            color,
+            left_sibling_id,
+            right_sibling_id,
+            child_id,
+            clsid,
+            state_bits,
+            ctime,
+            mtime,
+            starting_sector,
+            stream_size,
+        })
+    }
+
+    pub fn is_stream(&self) -> bool {
+        self.object_type == ObjectType::Stream
+    }
+
+    pub fn is_storage(&self) -> bool {
+        self.object_type == ObjectType::Storage || self.object_type == ObjectType::RootStorage
+    }
+}
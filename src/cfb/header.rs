@@ -0,0 +1,123 @@
+use crate::structs::Guid;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Error, ErrorKind, Read};
+
+/// [Compound File Header](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-cfb/05060311-bfce-4b12-874d-71fd4ce63aea) struct parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CompoundFileHeader {
+    #[serde(skip, default = "CompoundFileHeader::default_signature")]
+    pub signature: u64,
+    pub clsid: Guid,
+    pub minor_version: u16,
+    pub major_version: u16,
+    pub byte_order: u16,
+    pub sector_shift: u16,
+    pub mini_sector_shift: u16,
+    pub num_directory_sectors: u32,
+    pub num_fat_sectors: u32,
+    pub first_directory_sector: u32,
+    pub transaction_signature: u32,
+    pub mini_stream_cutoff_size: u32,
+    pub first_mini_fat_sector: u32,
+    pub num_mini_fat_sectors: u32,
+    pub first_difat_sector: u32,
+    pub num_difat_sectors: u32,
+    /// The first 109 FAT sector locations.
+    pub difat: Vec<u32>,
+}
+
+impl CompoundFileHeader {
+    pub const SIGNATURE: u64 = 0xE11AB1A1E011CFD0;
+    pub const SIZE: usize = 0x200;
+
+    fn default_signature() -> u64 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let signature = r.read_u64::<LittleEndian>()?;
+        if signature != Self::SIGNATURE {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "CompoundFileHeader : Invalid signature, expected '{:#X}' found '{:#X}'",
+                    Self::SIGNATURE,
+                    signature
+                ),
+            )));
+        }
+        let clsid = Guid::from_reader(r)?;
+        let minor_version = r.read_u16::<LittleEndian>()?;
+        let major_version = r.read_u16::<LittleEndian>()?;
+        let byte_order = r.read_u16::<LittleEndian>()?;
+        let sector_shift = r.read_u16::<LittleEndian>()?;
+        if sector_shift != 9 && sector_shift != 12 {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "CompoundFileHeader : Invalid sector shift '{}', expected 9 or 12",
+                    sector_shift
+                ),
+            )));
+        }
+        let mini_sector_shift = r.read_u16::<LittleEndian>()?;
+        if mini_sector_shift >= sector_shift {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "CompoundFileHeader : Invalid mini sector shift '{}'",
+                    mini_sector_shift
+                ),
+            )));
+        }
+        let mut reserved = [0; 6];
+        r.read_exact(&mut reserved)?;
+        let num_directory_sectors = r.read_u32::<LittleEndian>()?;
+        let num_fat_sectors = r.read_u32::<LittleEndian>()?;
+        let first_directory_sector = r.read_u32::<LittleEndian>()?;
+        let transaction_signature = r.read_u32::<LittleEndian>()?;
+        let mini_stream_cutoff_size = r.read_u32::<LittleEndian>()?;
+        let first_mini_fat_sector = r.read_u32::<LittleEndian>()?;
+        let num_mini_fat_sectors = r.read_u32::<LittleEndian>()?;
+        let first_difat_sector = r.read_u32::<LittleEndian>()?;
+        let num_difat_sectors = r.read_u32::<LittleEndian>()?;
+        let mut difat = vec![0; 109];
+        r.read_u32_into::<LittleEndian>(&mut difat)?;
+
+        Ok(Self {
+            signature,
+            clsid,
+            minor_version,
+            major_version,
+            byte_order,
+            sector_shift,
+            mini_sector_shift,
+            num_directory_sectors,
+            num_fat_sectors,
+            first_directory_sector,
+            transaction_signature,
+            mini_stream_cutoff_size,
+            first_mini_fat_sector,
+            num_mini_fat_sectors,
+            first_difat_sector,
+            num_difat_sectors,
+            difat,
+        })
+    }
+
+    /// The size of a sector in bytes (512 for version 3 and 4096 for version 4).
+    pub fn sector_size(&self) -> usize {
+        1 << self.sector_shift
+    }
+
+    /// The size of a mini stream sector in bytes (64).
+    pub fn mini_sector_size(&self) -> usize {
+        1 << self.mini_sector_shift
+    }
+}
@@ -0,0 +1,330 @@
+//! This module contains a read-only [Compound File Binary (CFB)](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-cfb/53989ce4-7b05-4f8d-829b-d08d6148375b)
+//! file format reader. Jump lists, Thumbs.db files and legacy Office documents are stored in compound files.
+#![allow(clippy::upper_case_acronyms)]
+#![cfg(feature = "cfb")]
+mod directory;
+mod header;
+mod property_set;
+mod stream;
+mod summary_information;
+
+pub use directory::{DirectoryEntry, ObjectType};
+pub use header::CompoundFileHeader;
+pub use property_set::{PropertySet, PropertySetProperty, PropertySetStream};
+pub use stream::Stream;
+pub use summary_information::{DocumentSummaryInformation, SummaryInformation};
+
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::{BufReader, Error, ErrorKind, Read, Seek, SeekFrom};
+
+/// Sector IDs with a special meaning.
+pub const MAXREGSECT: u32 = 0xFFFFFFFA;
+pub const DIFSECT: u32 = 0xFFFFFFFC;
+pub const FATSECT: u32 = 0xFFFFFFFD;
+pub const ENDOFCHAIN: u32 = 0xFFFFFFFE;
+pub const FREESECT: u32 = 0xFFFFFFFF;
+
+fn invalid_data(message: String) -> ReaderError {
+    ReaderError::from(Error::new(ErrorKind::InvalidData, message))
+}
+
+/// Follow the sector chain that starts at `start` in `table` (the FAT or the miniFAT).
+fn read_chain(table: &[u32], start: u32) -> Result<Vec<u32>, ReaderError> {
+    let mut chain = vec![];
+    let mut sector = start;
+    while sector != ENDOFCHAIN {
+        if sector > MAXREGSECT || sector as usize >= table.len() {
+            return Err(invalid_data(format!(
+                "CompoundFile : Invalid sector '{:#X}' in the chain that starts at '{:#X}'",
+                sector, start
+            )));
+        }
+        if chain.len() > table.len() {
+            return Err(invalid_data(format!(
+                "CompoundFile : Loop in the sector chain that starts at '{:#X}'",
+                start
+            )));
+        }
+        chain.push(sector);
+        sector = table[sector as usize];
+    }
+    Ok(chain)
+}
+
+/// [Compound File](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-cfb/53989ce4-7b05-4f8d-829b-d08d6148375b) reader.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::io::Read;
+/// use winparsingtools::cfb::CompoundFile;
+///
+/// let mut compound_file = CompoundFile::from_path("1b4dd67f29cb1962.automaticDestinations-ms").unwrap();
+/// for (path, entry) in compound_file.walk() {
+///     println!("{} ({} bytes)", path, entry.stream_size);
+/// }
+/// let mut dest_list = vec![];
+/// compound_file.open_stream("DestList").unwrap().read_to_end(&mut dest_list).unwrap();
+/// ```
+#[derive(Debug, Serialize)]
+pub struct CompoundFile<R: Read + Seek> {
+    #[serde(skip)]
+    reader: R,
+    pub header: CompoundFileHeader,
+    #[serde(skip)]
+    pub fat: Vec<u32>,
+    #[serde(skip)]
+    pub mini_fat: Vec<u32>,
+    pub entries: Vec<DirectoryEntry>,
+    /// The file offsets of the sectors of the mini stream.
+    #[serde(skip)]
+    mini_stream_sectors: Vec<u64>,
+}
+
+impl CompoundFile<BufReader<File>> {
+    pub fn from_path(path: &str) -> Result<Self, ReaderError> {
+        Self::from_reader(BufReader::new(File::open(path)?))
+    }
+}
+
+impl<R: Read + Seek> CompoundFile<R> {
+    pub fn from_reader(mut reader: R) -> Result<Self, ReaderError> {
+        reader.seek(SeekFrom::Start(0))?;
+        let header = CompoundFileHeader::from_reader(&mut reader)?;
+        let sector_size = header.sector_size();
+        let entries_per_sector = sector_size / 4;
+
+        // The DIFAT has the locations of the FAT sectors, a file can't have more DIFAT sectors than sectors
+        let file_sectors = reader.seek(SeekFrom::End(0))? / sector_size as u64;
+        let mut difat = header.difat.clone();
+        let mut difat_sector = header.first_difat_sector;
+        let mut visited = HashSet::new();
+        while difat_sector <= MAXREGSECT && (visited.len() as u32) < header.num_difat_sectors {
+            if !visited.insert(difat_sector) || visited.len() as u64 > file_sectors {
+                return Err(invalid_data(format!(
+                    "CompoundFile : Loop in the DIFAT chain at sector '{:#X}'",
+                    difat_sector
+                )));
+            }
+            let mut sector = vec![0; entries_per_sector];
+            reader.seek(SeekFrom::Start(Self::offset(&header, difat_sector)))?;
+            reader.read_u32_into::<LittleEndian>(&mut sector)?;
+            difat_sector = sector.pop().unwrap_or(ENDOFCHAIN);
+            difat.extend(sector);
+        }
+
+        let mut fat = vec![];
+        for fat_sector in difat
+            .iter()
+            .filter(|s| **s <= MAXREGSECT)
+            .take(header.num_fat_sectors as usize)
+        {
+            let mut sector = vec![0; entries_per_sector];
+            reader.seek(SeekFrom::Start(Self::offset(&header, *fat_sector)))?;
+            reader.read_u32_into::<LittleEndian>(&mut sector)?;
+            fat.extend(sector);
+        }
+
+        let mut mini_fat = vec![];
+        if header.num_mini_fat_sectors > 0 {
+            for mini_fat_sector in read_chain(&fat, header.first_mini_fat_sector)? {
+                let mut sector = vec![0; entries_per_sector];
+                reader.seek(SeekFrom::Start(Self::offset(&header, mini_fat_sector)))?;
+                reader.read_u32_into::<LittleEndian>(&mut sector)?;
+                mini_fat.extend(sector);
+            }
+        }
+
+        let mut entries = vec![];
+        for directory_sector in read_chain(&fat, header.first_directory_sector)? {
+            let mut sector = vec![0; sector_size];
+            reader.seek(SeekFrom::Start(Self::offset(&header, directory_sector)))?;
+            reader.read_exact(&mut sector)?;
+            for entry in sector.chunks_exact(DirectoryEntry::SIZE) {
+                let mut entry = DirectoryEntry::from_buffer(entry, entries.len() as u32)?;
+                if header.major_version == 3 {
+                    // Version 3 files may have garbage in the high 32 bits of the stream size
+                    entry.stream_size &= 0xFFFFFFFF;
+                }
+                entries.push(entry);
+            }
+        }
+        let root = match entries.first() {
+            Some(root) if root.object_type == ObjectType::RootStorage => root,
+            _ => {
+                return Err(invalid_data(
+                    "CompoundFile : The first directory entry is not the root storage".to_string(),
+                ))
+            }
+        };
+
+        let mini_stream_sectors = match root.stream_size {
+            0 => vec![],
+            _ => read_chain(&fat, root.starting_sector)?
+                .iter()
+                .map(|sector| Self::offset(&header, *sector))
+                .collect(),
+        };
+
+        Ok(Self {
+            reader,
+            header,
+            fat,
+            mini_fat,
+            entries,
+            mini_stream_sectors,
+        })
+    }
+
+    /// The file offset of `sector`.
+    fn offset(header: &CompoundFileHeader, sector: u32) -> u64 {
+        (sector as u64 + 1) * header.sector_size() as u64
+    }
+
+    /// Returns the root storage entry.
+    pub fn root(&self) -> &DirectoryEntry {
+        &self.entries[0]
+    }
+
+    /// Returns the entry with stream ID `id`.
+    pub fn entry(&self, id: u32) -> Option<&DirectoryEntry> {
+        self.entries.get(id as usize)
+    }
+
+    /// Returns the children of the storage entry `parent` ordered as they are stored in the tree.
+    pub fn children(&self, parent: &DirectoryEntry) -> Vec<&DirectoryEntry> {
+        let mut children = vec![];
+        let mut stack = vec![];
+        let mut visited = vec![false; self.entries.len()];
+        let mut current = parent.child_id;
+        // In-order traversal of the red-black tree
+        loop {
+            while let Some(entry) = self.entry(current) {
+                if visited[current as usize] {
+                    break;
+                }
+                visited[current as usize] = true;
+                stack.push(entry);
+                current = entry.left_sibling_id;
+            }
+            match stack.pop() {
+                Some(entry) => {
+                    children.push(entry);
+                    current = entry.right_sibling_id;
+                }
+                None => break,
+            }
+        }
+        children
+    }
+
+    /// Returns the entry at `path`. The path is relative to the root storage, storage names are
+    /// separated by `/` or `\` (e.g. `Storage/Stream`). Names are compared case-insensitively.
+    pub fn find(&self, path: &str) -> Option<&DirectoryEntry> {
+        let mut entry = self.root();
+        for name in path.split(['/', '\\']).filter(|n| !n.is_empty()) {
+            entry = self
+                .children(entry)
+                .into_iter()
+                .find(|child| child.name.to_uppercase() == name.to_uppercase())?;
+        }
+        Some(entry)
+    }
+
+    /// Returns all the entries under the root storage with their paths (e.g. `Storage/Stream`).
+    /// Every entry is returned once, even if the directory tree has cycles.
+    pub fn walk(&self) -> Vec<(String, &DirectoryEntry)> {
+        let mut entries = vec![];
+        let mut visited = vec![false; self.entries.len()];
+        visited[0] = true;
+        let mut stack = vec![(String::new(), self.root())];
+        while let Some((path, storage)) = stack.pop() {
+            for child in self.children(storage) {
+                match visited.get_mut(child.id as usize) {
+                    Some(visited) if !*visited => *visited = true,
+                    _ => continue,
+                }
+                let child_path = match path.is_empty() {
+                    true => child.name.clone(),
+                    false => format!("{}/{}", path, child.name),
+                };
+                if child.is_storage() {
+                    stack.push((child_path.clone(), child));
+                }
+                entries.push((child_path, child));
+            }
+        }
+        entries
+    }
+
+    /// Open the stream at `path`, see `CompoundFile::find`.
+    pub fn open_stream(&mut self, path: &str) -> Result<Stream<'_, R>, ReaderError> {
+        let id = match self.find(path) {
+            Some(entry) if entry.is_stream() => entry.id,
+            _ => {
+                return Err(ReaderError::from(Error::new(
+                    ErrorKind::NotFound,
+                    format!("CompoundFile : Stream '{}' was not found", path),
+                )))
+            }
+        };
+        self.open_stream_by_id(id)
+    }
+
+    /// Open the stream with stream ID `id`.
+    pub fn open_stream_by_id(&mut self, id: u32) -> Result<Stream<'_, R>, ReaderError> {
+        let entry = match self.entry(id) {
+            Some(entry) if entry.is_stream() => entry,
+            _ => {
+                return Err(ReaderError::from(Error::new(
+                    ErrorKind::NotFound,
+                    format!("CompoundFile : Stream ID '{}' was not found", id),
+                )))
+            }
+        };
+        let size = entry.stream_size;
+        let (sectors, sector_size) = match size < self.header.mini_stream_cutoff_size as u64 {
+            _ if size == 0 => (vec![], self.header.sector_size() as u64),
+            true => {
+                let mini_sector_size = self.header.mini_sector_size() as u64;
+                let sector_size = self.header.sector_size() as u64;
+                let mut sectors = vec![];
+                for mini_sector in read_chain(&self.mini_fat, entry.starting_sector)? {
+                    let offset = mini_sector as u64 * mini_sector_size;
+                    match self
+                        .mini_stream_sectors
+                        .get((offset / sector_size) as usize)
+                    {
+                        Some(sector) => sectors.push(sector + offset % sector_size),
+                        None => {
+                            return Err(invalid_data(format!(
+                                "CompoundFile : Mini sector '{:#X}' is outside of the mini stream",
+                                mini_sector
+                            )))
+                        }
+                    }
+                }
+                (sectors, mini_sector_size)
+            }
+            false => (
+                read_chain(&self.fat, entry.starting_sector)?
+                    .iter()
+                    .map(|sector| Self::offset(&self.header, *sector))
+                    .collect(),
+                self.header.sector_size() as u64,
+            ),
+        };
+        Ok(Stream::new(&mut self.reader, sectors, sector_size, size))
+    }
+
+    /// Read the whole stream at `path`.
+    pub fn read_stream(&mut self, path: &str) -> Result<Vec<u8>, ReaderError> {
+        let mut data = vec![];
+        self.open_stream(path)?.read_to_end(&mut data)?;
+        Ok(data)
+    }
+}
@@ -0,0 +1,308 @@
+use super::invalid_data;
+use crate::structs::{property_key_name, Guid, PropVariant};
+use crate::utils::{read_code_page_string, CP_WINUNICODE};
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+
+/// A property of a property set.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertySetProperty {
+    pub id: u32,
+    /// The name from the dictionary property, or the canonical name of the property key (e.g. `System.Author`).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    pub value: PropVariant,
+}
+
+/// [PropertySet](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleps) parser.
+/// The dictionary (`0`), code page (`1`), locale (`0x80000000`) and behavior (`0x80000003`) properties are parsed into their own fields.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertySet {
+    pub format_id: Guid,
+    pub size: u32,
+    /// The code page used to decode the strings, `CP_WINUNICODE` (`1200`) for Unicode property sets.
+    pub code_page: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub locale: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub behavior: Option<u32>,
+    /// The names of the user defined properties.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dictionary: BTreeMap<u32, String>,
+    pub properties: Vec<PropertySetProperty>,
+}
+
+impl PropertySet {
+    pub const DICTIONARY_PROPERTY_ID: u32 = 0x00000000;
+    pub const CODE_PAGE_PROPERTY_ID: u32 = 0x00000001;
+    pub const LOCALE_PROPERTY_ID: u32 = 0x80000000;
+    pub const BEHAVIOR_PROPERTY_ID: u32 = 0x80000003;
+
+    /// Parse the property set with the format ID `format_id`, `buf` starts at the beginning of the property set.
+    pub fn from_buffer(buf: &[u8], format_id: Guid) -> Result<Self, ReaderError> {
+        let mut r = Cursor::new(buf);
+        let size = r.read_u32::<LittleEndian>()?;
+        let number_of_properties = r.read_u32::<LittleEndian>()?;
+        if size as usize > buf.len() || number_of_properties as usize > (size as usize) / 8 {
+            return Err(invalid_data(format!(
+                "PropertySet : Invalid size '{}' or number of properties '{}'",
+                size, number_of_properties
+            )));
+        }
+        let buf = &buf[..size as usize];
+        let mut offsets = vec![];
+        for _ in 0..number_of_properties {
+            let id = r.read_u32::<LittleEndian>()?;
+            let offset = r.read_u32::<LittleEndian>()?;
+            if offset as usize >= buf.len() {
+                return Err(invalid_data(format!(
+                    "PropertySet : The offset '{}' of the property '{:#X}' is outside the property set",
+                    offset, id
+                )));
+            }
+            offsets.push((id, offset as usize));
+        }
+
+        // The value of a property ends at the start of the next property
+        let mut sorted_offsets = offsets
+            .iter()
+            .map(|(_, offset)| *offset)
+            .collect::<Vec<usize>>();
+        sorted_offsets.sort_unstable();
+        let value_data = |offset: usize| -> &[u8] {
+            let end = sorted_offsets
+                .iter()
+                .find(|&&next| next > offset)
+                .copied()
+                .unwrap_or(buf.len());
+            &buf[offset..end]
+        };
+
+        let code_page = match offsets
+            .iter()
+            .find(|(id, _)| *id == Self::CODE_PAGE_PROPERTY_ID)
+        {
+            Some((_, offset)) => match PropVariant::from_buffer(value_data(*offset))? {
+                PropVariant::I2(code_page) => code_page as u16,
+                PropVariant::UI2(code_page) => code_page,
+                other => {
+                    return Err(invalid_data(format!(
+                        "PropertySet : Invalid code page property '{:?}'",
+                        other
+                    )))
+                }
+            },
+            None => CP_WINUNICODE,
+        };
+
+        let mut locale = None;
+        let mut behavior = None;
+        let mut dictionary = BTreeMap::new();
+        let mut values = vec![];
+        for (id, offset) in offsets {
+            let mut value_reader = Cursor::new(value_data(offset));
+            match id {
+                Self::CODE_PAGE_PROPERTY_ID => {}
+                Self::DICTIONARY_PROPERTY_ID => {
+                    dictionary = Self::read_dictionary(&mut value_reader, code_page)?;
+                }
+                Self::LOCALE_PROPERTY_ID | Self::BEHAVIOR_PROPERTY_ID => {
+                    let value = match PropVariant::from_reader(&mut value_reader)? {
+                        PropVariant::UI4(value) => value,
+                        PropVariant::I4(value) => value as u32,
+                        other => {
+                            return Err(invalid_data(format!(
+                                "PropertySet : Invalid locale or behavior property '{:?}'",
+                                other
+                            )))
+                        }
+                    };
+                    match id {
+                        Self::LOCALE_PROPERTY_ID => locale = Some(value),
+                        _ => behavior = Some(value),
+                    }
+                }
+                _ => {
+                    // Values that fail to parse are kept as raw bytes
+                    let value =
+                        PropVariant::from_reader_with_code_page(&mut value_reader, code_page)
+                            .unwrap_or_else(|_| {
+                                let data = value_data(offset);
+                                PropVariant::Unknown {
+                                    vt: u16::from_le_bytes([data[0], *data.get(1).unwrap_or(&0)]),
+                                    data: data.get(4..).unwrap_or_default().to_vec(),
+                                }
+                            });
+                    values.push((id, value));
+                }
+            }
+        }
+
+        let format_id_string = format_id.to_string();
+        let properties = values
+            .into_iter()
+            .map(|(id, value)| PropertySetProperty {
+                id,
+                name: dictionary
+                    .get(&id)
+                    .cloned()
+                    .or_else(|| property_key_name(&format_id_string, id)),
+                value,
+            })
+            .collect();
+
+        Ok(Self {
+            format_id,
+            size,
+            code_page,
+            locale,
+            behavior,
+            dictionary,
+            properties,
+        })
+    }
+
+    /// Read the dictionary property, the names are not padded in property sets that do not use `CP_WINUNICODE`.
+    fn read_dictionary<R: Read>(
+        r: &mut R,
+        code_page: u16,
+    ) -> Result<BTreeMap<u32, String>, ReaderError> {
+        let number_of_entries = r.read_u32::<LittleEndian>()?;
+        let mut dictionary = BTreeMap::new();
+        for _ in 0..number_of_entries {
+            let id = r.read_u32::<LittleEndian>()?;
+            let length = r.read_u32::<LittleEndian>()? as usize;
+            let name = match code_page {
+                CP_WINUNICODE => {
+                    let size = length.checked_mul(2).ok_or_else(|| {
+                        invalid_data(format!("PropertySet : Invalid name length '{}'", length))
+                    })?;
+                    let name = read_code_page_string(r, size, code_page)?;
+                    r.take(((4 - size % 4) % 4) as u64)
+                        .read_to_end(&mut vec![])?;
+                    name
+                }
+                _ => read_code_page_string(r, length, code_page)?,
+            };
+            dictionary.insert(id, name);
+        }
+        Ok(dictionary)
+    }
+
+    /// Returns the value of the property with the ID `id`.
+    pub fn get(&self, id: u32) -> Option<&PropVariant> {
+        self.properties
+            .iter()
+            .find(|property| property.id == id)
+            .map(|property| &property.value)
+    }
+
+    /// Returns the value of the property with the name `name` from the dictionary.
+    pub fn get_by_name(&self, name: &str) -> Option<&PropVariant> {
+        self.properties
+            .iter()
+            .find(|property| {
+                matches!(&property.name, Some(property_name) if property_name == name)
+                    && self.dictionary.contains_key(&property.id)
+            })
+            .map(|property| &property.value)
+    }
+}
+
+/// [PropertySetStream](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleps) parser.
+/// Property set streams (e.g. `\u{5}SummaryInformation`) store the metadata of legacy Office documents, MSI packages and Thumbs.db files.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertySetStream {
+    #[serde(skip, default = "PropertySetStream::default_byte_order")]
+    pub byte_order: u16,
+    pub version: u16,
+    /// The operating system version (low word) and platform (high word) of the application that wrote the stream.
+    pub system_identifier: u32,
+    pub clsid: Guid,
+    pub property_sets: Vec<PropertySet>,
+}
+
+impl Default for PropertySetStream {
+    /// An empty version 0 property set stream.
+    fn default() -> Self {
+        Self {
+            byte_order: Self::BYTE_ORDER,
+            version: 0,
+            system_identifier: 0,
+            clsid: Guid::default(),
+            property_sets: vec![],
+        }
+    }
+}
+
+impl PropertySetStream {
+    pub const BYTE_ORDER: u16 = 0xFFFE;
+
+    fn default_byte_order() -> u16 {
+        Self::BYTE_ORDER
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        let mut r = Cursor::new(buf);
+        let byte_order = r.read_u16::<LittleEndian>()?;
+        if byte_order != Self::BYTE_ORDER {
+            return Err(invalid_data(format!(
+                "PropertySetStream : Invalid byte order '{:#06X}'",
+                byte_order
+            )));
+        }
+        let version = r.read_u16::<LittleEndian>()?;
+        let system_identifier = r.read_u32::<LittleEndian>()?;
+        let clsid = Guid::from_reader(&mut r)?;
+        let number_of_property_sets = r.read_u32::<LittleEndian>()?;
+        if !(1..=2).contains(&number_of_property_sets) {
+            return Err(invalid_data(format!(
+                "PropertySetStream : Invalid number of property sets '{}'",
+                number_of_property_sets
+            )));
+        }
+        let mut headers = vec![];
+        for _ in 0..number_of_property_sets {
+            let format_id = Guid::from_reader(&mut r)?;
+            let offset = r.read_u32::<LittleEndian>()? as usize;
+            headers.push((format_id, offset));
+        }
+        let mut property_sets = vec![];
+        for (format_id, offset) in headers {
+            if offset >= buf.len() {
+                return Err(invalid_data(format!(
+                    "PropertySetStream : The offset '{}' of the property set '{}' is outside the stream",
+                    offset, format_id
+                )));
+            }
+            property_sets.push(PropertySet::from_buffer(&buf[offset..], format_id)?);
+        }
+
+        Ok(Self {
+            byte_order,
+            version,
+            system_identifier,
+            clsid,
+            property_sets,
+        })
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let mut buf = vec![];
+        r.read_to_end(&mut buf)?;
+        Self::from_buffer(&buf)
+    }
+
+    /// Returns the property set with the format ID `format_id` (e.g. `F29F85E0-4FF9-1068-AB91-08002B27B3D9`).
+    pub fn property_set(&self, format_id: &str) -> Option<&PropertySet> {
+        self.property_sets.iter().find(|property_set| {
+            property_set
+                .format_id
+                .to_string()
+                .eq_ignore_ascii_case(format_id)
+        })
+    }
+}
@@ -0,0 +1,74 @@
+use std::io::{Error, ErrorKind, Read, Result, Seek, SeekFrom};
+
+/// A stream stored in a compound file. The stream data is spread over sectors (or mini sectors), `Stream`
+/// maps the stream offsets to the file offsets of the sectors.
+#[derive(Debug)]
+pub struct Stream<'a, R: Read + Seek> {
+    reader: &'a mut R,
+    /// The file offset of every sector of the stream.
+    sectors: Vec<u64>,
+    sector_size: u64,
+    size: u64,
+    position: u64,
+}
+
+impl<'a, R: Read + Seek> Stream<'a, R> {
+    pub(crate) fn new(reader: &'a mut R, sectors: Vec<u64>, sector_size: u64, size: u64) -> Self {
+        // Do not read past the sectors if the size is bigger than the chain
+        let size = size.min(sectors.len() as u64 * sector_size);
+        Self {
+            reader,
+            sectors,
+            sector_size,
+            size,
+            position: 0,
+        }
+    }
+
+    /// The size of the stream in bytes.
+    pub fn len(&self) -> u64 {
+        self.size
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.size == 0
+    }
+}
+
+impl<'a, R: Read + Seek> Read for Stream<'a, R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        if self.position >= self.size || buf.is_empty() {
+            return Ok(0);
+        }
+        let sector = (self.position / self.sector_size) as usize;
+        let sector_offset = self.position % self.sector_size;
+        let len = (self.sector_size - sector_offset)
+            .min(self.size - self.position)
+            .min(buf.len() as u64) as usize;
+        self.reader
+            .seek(SeekFrom::Start(self.sectors[sector] + sector_offset))?;
+        let read = self.reader.read(&mut buf[..len])?;
+        self.position += read as u64;
+        Ok(read)
+    }
+}
+
+impl<'a, R: Read + Seek> Seek for Stream<'a, R> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let position = match pos {
+            SeekFrom::Start(offset) => Some(offset),
+            SeekFrom::End(offset) => self.size.checked_add_signed(offset),
+            SeekFrom::Current(offset) => self.position.checked_add_signed(offset),
+        };
+        match position {
+            Some(position) => {
+                self.position = position;
+                Ok(position)
+            }
+            None => Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Stream : invalid seek to a negative or overflowing position",
+            )),
+        }
+    }
+}
@@ -0,0 +1,251 @@
+use super::{CompoundFile, PropertySet, PropertySetStream};
+use crate::date_time::{FileTime, FileTimeState};
+use crate::structs::PropVariant;
+use crate::ReaderError;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Read, Seek};
+
+/// Returns the string value of the property `id`.
+fn string_property(property_set: &PropertySet, id: u32) -> Option<String> {
+    property_set
+        .get(id)
+        .and_then(PropVariant::as_str)
+        .map(|value| value.to_string())
+}
+
+/// Returns the integer value of the property `id`.
+fn integer_property(property_set: &PropertySet, id: u32) -> Option<i64> {
+    match property_set.get(id)? {
+        PropVariant::I2(value) => Some(*value as i64),
+        PropVariant::I4(value) | PropVariant::Int(value) => Some(*value as i64),
+        PropVariant::UI2(value) => Some(*value as i64),
+        PropVariant::UI4(value) | PropVariant::UInt(value) => Some(*value as i64),
+        _ => None,
+    }
+}
+
+/// Returns the value of the `VT_FILETIME` property `id`, `None` if the timestamp is not set.
+fn file_time_property(property_set: &PropertySet, id: u32) -> Option<FileTime> {
+    match property_set.get(id)? {
+        PropVariant::FileTime(value) if value.state() != FileTimeState::NotSet => Some(*value),
+        _ => None,
+    }
+}
+
+/// Returns the value of the `VT_BOOL` property `id`.
+fn bool_property(property_set: &PropertySet, id: u32) -> Option<bool> {
+    match property_set.get(id)? {
+        PropVariant::Bool(value) => Some(*value),
+        _ => None,
+    }
+}
+
+/// The `\u{5}SummaryInformation` [property set stream](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleps) parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SummaryInformation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keywords: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comments: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_saved_by: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revision_number: Option<String>,
+    /// The total editing time in seconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_editing_time: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_printed: Option<FileTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created: Option<FileTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_saved: Option<FileTime>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub page_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub word_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub character_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub application_name: Option<String>,
+    /// `1` password protected, `2` read-only recommended, `4` read-only enforced, `8` locked for annotations.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security: Option<i64>,
+    pub property_set_stream: PropertySetStream,
+}
+
+impl SummaryInformation {
+    pub const STREAM_NAME: &'static str = "\u{5}SummaryInformation";
+    pub const FORMAT_ID: &'static str = "F29F85E0-4FF9-1068-AB91-08002B27B3D9";
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_property_set_stream(PropertySetStream::from_buffer(buf)?)
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        Self::from_property_set_stream(PropertySetStream::from_reader(r)?)
+    }
+
+    /// Read the `\u{5}SummaryInformation` stream of the compound file.
+    pub fn from_compound_file<R: Read + Seek>(
+        compound_file: &mut CompoundFile<R>,
+    ) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut compound_file.open_stream(Self::STREAM_NAME)?)
+    }
+
+    pub fn from_property_set_stream(
+        property_set_stream: PropertySetStream,
+    ) -> Result<Self, ReaderError> {
+        let property_set = property_set_stream
+            .property_set(Self::FORMAT_ID)
+            .ok_or_else(|| {
+                super::invalid_data(format!(
+                    "SummaryInformation : The property set '{}' does not exist",
+                    Self::FORMAT_ID
+                ))
+            })?;
+
+        Ok(Self {
+            title: string_property(property_set, 2),
+            subject: string_property(property_set, 3),
+            author: string_property(property_set, 4),
+            keywords: string_property(property_set, 5),
+            comments: string_property(property_set, 6),
+            template: string_property(property_set, 7),
+            last_saved_by: string_property(property_set, 8),
+            revision_number: string_property(property_set, 9),
+            total_editing_time: match property_set.get(10) {
+                Some(PropVariant::FileTime(duration)) => Some(duration.to_u64() / 10_000_000),
+                _ => None,
+            },
+            last_printed: file_time_property(property_set, 11),
+            created: file_time_property(property_set, 12),
+            last_saved: file_time_property(property_set, 13),
+            page_count: integer_property(property_set, 14),
+            word_count: integer_property(property_set, 15),
+            character_count: integer_property(property_set, 16),
+            application_name: string_property(property_set, 18),
+            security: integer_property(property_set, 19),
+            property_set_stream,
+        })
+    }
+}
+
+/// The `\u{5}DocumentSummaryInformation` [property set stream](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleps) parser.
+/// The second property set contains the user defined properties.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DocumentSummaryInformation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub presentation_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub byte_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub paragraph_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slide_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hidden_slide_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub multimedia_clip_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub manager: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub links_dirty: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_version: Option<String>,
+    /// The user defined properties by name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub user_defined_properties: BTreeMap<String, PropVariant>,
+    pub property_set_stream: PropertySetStream,
+}
+
+impl DocumentSummaryInformation {
+    pub const STREAM_NAME: &'static str = "\u{5}DocumentSummaryInformation";
+    pub const FORMAT_ID: &'static str = "D5CDD502-2E9C-101B-9397-08002B2CF9AE";
+    pub const USER_DEFINED_FORMAT_ID: &'static str = "D5CDD505-2E9C-101B-9397-08002B2CF9AE";
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_property_set_stream(PropertySetStream::from_buffer(buf)?)
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        Self::from_property_set_stream(PropertySetStream::from_reader(r)?)
+    }
+
+    /// Read the `\u{5}DocumentSummaryInformation` stream of the compound file.
+    pub fn from_compound_file<R: Read + Seek>(
+        compound_file: &mut CompoundFile<R>,
+    ) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut compound_file.open_stream(Self::STREAM_NAME)?)
+    }
+
+    pub fn from_property_set_stream(
+        property_set_stream: PropertySetStream,
+    ) -> Result<Self, ReaderError> {
+        let mut user_defined_properties = BTreeMap::new();
+        if let Some(user_defined) = property_set_stream.property_set(Self::USER_DEFINED_FORMAT_ID) {
+            for property in &user_defined.properties {
+                let name = match &property.name {
+                    Some(name) => name.clone(),
+                    None => format!("{:#X}", property.id),
+                };
+                user_defined_properties.insert(name, property.value.clone());
+            }
+        }
+        let property_set = property_set_stream
+            .property_set(Self::FORMAT_ID)
+            .ok_or_else(|| {
+                super::invalid_data(format!(
+                    "DocumentSummaryInformation : The property set '{}' does not exist",
+                    Self::FORMAT_ID
+                ))
+            })?;
+
+        Ok(Self {
+            category: string_property(property_set, 2),
+            presentation_format: string_property(property_set, 3),
+            byte_count: integer_property(property_set, 4),
+            line_count: integer_property(property_set, 5),
+            paragraph_count: integer_property(property_set, 6),
+            slide_count: integer_property(property_set, 7),
+            note_count: integer_property(property_set, 8),
+            hidden_slide_count: integer_property(property_set, 9),
+            multimedia_clip_count: integer_property(property_set, 10),
+            scale: bool_property(property_set, 11),
+            manager: string_property(property_set, 14),
+            company: string_property(property_set, 15),
+            links_dirty: bool_property(property_set, 16),
+            content_type: string_property(property_set, 26),
+            content_status: string_property(property_set, 27),
+            language: string_property(property_set, 28),
+            document_version: string_property(property_set, 29),
+            user_defined_properties,
+            property_set_stream,
+        })
+    }
+}
@@ -0,0 +1,213 @@
+use super::timestamp_format::{
+    deserialize_timestamp, serialize_timestamp, DeserializeTimestamp, SerializeTimestamp,
+    TimestampParts, TimestampValue,
+};
+use super::win_timestamp::{RawTimestamp, TimestampPrecision, WinTimestamp};
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::io::{Cursor, Read};
+
+const TICKS_PER_SECOND: u64 = 10_000_000;
+const TICKS_MASK: u64 = 0x3FFF_FFFF_FFFF_FFFF;
+/// `DateTime.MaxValue.Ticks`, 9999-12-31 23:59:59.9999999.
+const MAX_TICKS: u64 = 3_155_378_975_999_999_999;
+/// Local times are stored as UTC ticks that wrap around this value when they are negative.
+const TICKS_CEILING: u64 = 0x4000_0000_0000_0000;
+const TICKS_PER_DAY: u64 = 864_000_000_000;
+
+/// 0001-01-01, tick `0` of .NET `DateTime` values.
+fn dot_net_epoch() -> NaiveDateTime {
+    // Unwraping here is safe because the date is always valid.
+    NaiveDate::from_ymd_opt(1, 1, 1)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+/// The [DateTimeKind](https://learn.microsoft.com/en-us/dotnet/api/system.datetimekind) stored in the top 2 bits of `DateTime.ToBinary()`.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum DateTimeKind {
+    Unspecified,
+    Utc,
+    Local,
+    /// A local time in the hour repeated at the end of daylight saving time.
+    LocalAmbiguousDst,
+}
+
+impl From<u64> for DateTimeKind {
+    fn from(num: u64) -> Self {
+        match num >> 62 {
+            0 => DateTimeKind::Unspecified,
+            1 => DateTimeKind::Utc,
+            2 => DateTimeKind::Local,
+            _ => DateTimeKind::LocalAmbiguousDst,
+        }
+    }
+}
+
+/// .NET [DateTime.ToBinary()](https://learn.microsoft.com/en-us/dotnet/api/system.datetime.tobinary) value parser.
+/// The low 62 bits are the number of 100-nanosecond ticks since 0001-01-01 and the top 2 bits are the `DateTimeKind`.
+/// Local times are stored as UTC ticks, unspecified times are treated as UTC. Formatted like `FileTime`, values after
+/// `9999-12-31` are formatted as the raw number.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::{DateTimeKind, DotNetTicks};
+///
+/// let ticks = DotNetTicks::new(0x48D8DFE04B2F8900);
+/// assert_eq!(ticks.kind(), DateTimeKind::Utc);
+/// assert_eq!(ticks.to_string(), "2021-03-05T14:09:30Z");
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct DotNetTicks(u64);
+
+impl DotNetTicks {
+    /// Create `DotNetTicks` from the `DateTime.ToBinary()` value.
+    pub fn new(value: u64) -> Self {
+        Self(value)
+    }
+
+    /// Create `DotNetTicks` from `DateTime.Ticks` and the kind.
+    pub fn from_ticks(ticks: u64, kind: DateTimeKind) -> Self {
+        let kind = match kind {
+            DateTimeKind::Unspecified => 0,
+            DateTimeKind::Utc => 1,
+            DateTimeKind::Local => 2,
+            DateTimeKind::LocalAmbiguousDst => 3,
+        };
+        Self((ticks & TICKS_MASK) | (kind << 62))
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        Ok(Self(r.read_u64::<LittleEndian>()?))
+    }
+
+    /// Returns the raw `DateTime.ToBinary()` value.
+    pub fn to_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the value in its binary (little endian) form.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    pub fn kind(&self) -> DateTimeKind {
+        DateTimeKind::from(self.0)
+    }
+
+    /// Returns the number of UTC ticks (unspecified times are returned as they are). Local times
+    /// just after 0001-01-01 can be before 0001-01-01 in UTC and have negative ticks.
+    pub fn ticks(&self) -> i64 {
+        let ticks = self.0 & TICKS_MASK;
+        match self.kind() {
+            // Negative UTC ticks of local times wrap around the ceiling
+            DateTimeKind::Local | DateTimeKind::LocalAmbiguousDst
+                if ticks > TICKS_CEILING - TICKS_PER_DAY =>
+            {
+                ticks as i64 - TICKS_CEILING as i64
+            }
+            _ => ticks as i64,
+        }
+    }
+
+    /// Returns the timestamp as UTC, `None` if the ticks are out of range.
+    pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        let ticks = self.ticks();
+        if ticks > MAX_TICKS as i64 {
+            return None;
+        }
+        let date_time = dot_net_epoch()
+            + Duration::seconds(ticks.div_euclid(TICKS_PER_SECOND as i64))
+            + Duration::nanoseconds(ticks.rem_euclid(TICKS_PER_SECOND as i64) * 100);
+        Some(DateTime::from_naive_utc_and_offset(date_time, Utc))
+    }
+}
+
+impl Display for DotNetTicks {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.to_datetime() {
+            Some(date_time) => write!(f, "{}", date_time.format("%Y-%m-%dT%H:%M:%SZ")),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl WinTimestamp for DotNetTicks {
+    fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        DotNetTicks::to_datetime(self)
+    }
+
+    fn raw_timestamp(&self) -> RawTimestamp {
+        RawTimestamp::U64(self.0)
+    }
+
+    fn precision(&self) -> TimestampPrecision {
+        TimestampPrecision::HundredNanoseconds
+    }
+}
+
+impl SerializeTimestamp for DotNetTicks {}
+
+impl DeserializeTimestamp for DotNetTicks {
+    fn from_raw(raw: TimestampValue) -> Option<Self> {
+        match raw {
+            TimestampValue::Unsigned(raw) => Some(DotNetTicks(raw)),
+            _ => None,
+        }
+    }
+
+    /// Formatted timestamps are `DateTimeKind::Utc`, values that are not valid are formatted as the raw number.
+    fn from_formatted(formatted: &str) -> Option<Self> {
+        match TimestampParts::parse(formatted) {
+            Some(parts) => Self::from_utc(parts.to_datetime()?),
+            None => formatted.parse().ok().map(DotNetTicks),
+        }
+    }
+
+    fn from_utc(date_time: DateTime<Utc>) -> Option<Self> {
+        Some(DotNetTicks::from(date_time))
+    }
+}
+
+impl Debug for DotNetTicks {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self)
+    }
+}
+
+impl Serialize for DotNetTicks {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_timestamp(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DotNetTicks {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_timestamp(deserializer)
+    }
+}
+
+impl From<DateTime<Utc>> for DotNetTicks {
+    /// Create a `DateTimeKind::Utc` value, dates before 0001-01-01 are clamped.
+    fn from(value: DateTime<Utc>) -> Self {
+        let duration = value.naive_utc() - dot_net_epoch();
+        let ticks = duration.num_seconds().max(0) as u64 * TICKS_PER_SECOND
+            + (duration.subsec_nanos().max(0) / 100) as u64;
+        Self::from_ticks(ticks, DateTimeKind::Utc)
+    }
+}
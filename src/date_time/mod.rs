@@ -0,0 +1,560 @@
+//! Contains structs to parse common windows date and time structs.
+mod dot_net_ticks;
+mod ole_date;
+mod system_time;
+mod time_zone;
+mod timestamp_format;
+mod win_timestamp;
+
+pub use dot_net_ticks::{DateTimeKind, DotNetTicks};
+pub use ole_date::OleDate;
+pub use system_time::{DayOfWeekInMonth, SystemTime};
+pub use time_zone::{
+    DynamicTimeZoneInformation, TimeInterpretation, TimeZoneInformation, TimeZoneRule, TimeZoneRules,
+};
+pub use timestamp_format::{
+    timestamp_format, with_input_timestamp_format, with_timestamp_format, TimestampFormat, WithTimestampFormat,
+};
+pub use win_timestamp::{RawTimestamp, TimestampPrecision, WinTimestamp};
+
+use crate::ReaderError;
+use timestamp_format::{
+    deserialize_timestamp, serialize_timestamp, DeserializeTimestamp, SerializeTimestamp,
+    TimestampParts, TimestampValue,
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::io::{Error, ErrorKind, Result};
+
+/// [DosDateTime](https://docs.microsoft.com/en-us/windows/win32/api/winbase/nf-winbase-filetimetodosdatetime#parameters) struct parser.
+/// The timestamp is interpreted as UTC unless another `TimeInterpretation` is set, the formatted timestamp ends with `Z` for UTC
+/// or with the offset from UTC for local times (e.g. `2021-03-05T14:09:30+03:00`). Invalid fields (e.g. month `0`) are kept and
+/// formatted as they are, use `DosDateTime::is_valid` to check them.
+///
+/// FAT and exFAT directory entries store the time and the date as separate words and can add a 10 ms increment (the FAT
+/// "create time fine" byte), exFAT entries also store the offset from UTC, see `DosDateTime::from_exfat`.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::{DosDateTime, TimeInterpretation};
+///
+/// let dos_date_time = DosDateTime::new(2021, 3, 5, 14, 9, 30);
+/// assert_eq!(dos_date_time.to_string(), "2021-03-05T14:09:30Z");
+/// let local = dos_date_time.with_interpretation(TimeInterpretation::FixedOffset(180));
+/// assert_eq!(local.to_string(), "2021-03-05T14:09:30+03:00");
+/// assert_eq!(local.to_datetime().unwrap().to_string(), "2021-03-05 11:09:30 UTC");
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct DosDateTime {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minutes: u8,
+    seconds: u8,
+    /// The 10 ms increment (0 to 199) added to the seconds.
+    hundredths: Option<u8>,
+    /// The UTC offset field of exFAT directory entries, kept for the raw value.
+    exfat_utc_offset: Option<u8>,
+    interpretation: TimeInterpretation,
+}
+
+impl DosDateTime {
+    /// Create `DosDateTime` instence from components.
+    pub fn new(year: u16, month: u8, day: u8, hour: u8, minutes: u8, seconds: u8) -> DosDateTime {
+        DosDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minutes,
+            seconds,
+            hundredths: None,
+            exfat_utc_offset: None,
+            interpretation: TimeInterpretation::Utc,
+        }
+    }
+    /// Create DosDateTime struct from `u32`.
+    pub fn from_u32(num: u32) -> Result<Self> {
+        let day = (num & 31) as u8;
+        let month = ((num >> 5) & 15) as u8;
+        let year = ((num >> 9) & 127) as u16 + 1980;
+        let seconds = (((num >> 16) & 31) * 2) as u8;
+        let minutes = ((num >> 21) & 63) as u8;
+        let hour = ((num >> 27) & 31) as u8;
+
+        Ok(DosDateTime::new(year, month, day, hour, minutes, seconds))
+    }
+
+    /// Create DosDateTime struct from the separate date and time words of FAT directory entries.
+    pub fn from_date_time(date: u16, time: u16) -> Self {
+        // Unwraping here is safe because `from_u32` never fails.
+        Self::from_u32((time as u32) << 16 | date as u32).unwrap()
+    }
+
+    /// Create DosDateTime struct from the fields of exFAT directory entries, the `timestamp` (the time in the low word and
+    /// the date in the high word), the 10 ms increment and the UTC offset. The UTC offset is used if its valid bit is set,
+    /// see `TimeInterpretation::from_exfat_utc_offset`, otherwise the time is local with an unknown offset
+    /// (`TimeInterpretation::UnknownLocal`). The raw value keeps the three fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use winparsingtools::date_time::DosDateTime;
+    ///
+    /// let dos_date_time = DosDateTime::from_exfat(0x50C699F7, 150, 0x8C);
+    /// assert_eq!(dos_date_time.to_string(), "2020-06-06T19:15:47+03:00");
+    /// assert_eq!(dos_date_time.to_datetime().unwrap().to_string(), "2020-06-06 16:15:47.500 UTC");
+    /// assert_eq!(DosDateTime::from_exfat(0x50C699F7, 150, 0x0C).to_string(), "2020-06-06T19:15:47");
+    /// ```
+    pub fn from_exfat(timestamp: u32, increment_10ms: u8, utc_offset: u8) -> Self {
+        let mut dos_date_time =
+            Self::from_date_time((timestamp >> 16) as u16, timestamp as u16).with_hundredths(increment_10ms);
+        dos_date_time.exfat_utc_offset = Some(utc_offset);
+        dos_date_time.with_interpretation(
+            TimeInterpretation::from_exfat_utc_offset(utc_offset).unwrap_or(TimeInterpretation::UnknownLocal),
+        )
+    }
+
+    /// Set the 10 ms increment (0 to 199), the FAT "create time fine" byte or the exFAT `10msIncrement` field.
+    pub fn with_hundredths(mut self, hundredths: u8) -> Self {
+        self.hundredths = Some(hundredths);
+        self
+    }
+
+    /// Create DosDateTime from a time with second and nanosecond components, odd seconds and fractions of a second are
+    /// stored as the 10 ms increment.
+    fn from_components(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minutes: u8,
+        seconds: u8,
+        nanosecond: u32,
+    ) -> Option<Self> {
+        let hundredths = (seconds % 2) as u32 * 100 + nanosecond / 10_000_000;
+        let dos_date_time = DosDateTime::new(year, month, day, hour, minutes, seconds - seconds % 2);
+        match hundredths {
+            0 => Some(dos_date_time),
+            _ => Some(dos_date_time.with_hundredths(u8::try_from(hundredths).ok()?)),
+        }
+    }
+
+    /// Returns the packed `u32` form, the date in the low word and the time in the high word.
+    pub fn to_u32(&self) -> u32 {
+        let date = (self.year.saturating_sub(1980) as u32 & 127) << 9
+            | (self.month as u32 & 15) << 5
+            | (self.day as u32 & 31);
+        let time = (self.hour as u32 & 31) << 11
+            | (self.minutes as u32 & 63) << 5
+            | ((self.seconds as u32 / 2) & 31);
+        time << 16 | date
+    }
+
+    /// Set how the timestamp is interpreted.
+    pub fn with_interpretation(mut self, interpretation: TimeInterpretation) -> Self {
+        self.interpretation = interpretation;
+        self
+    }
+
+    pub fn interpretation(&self) -> &TimeInterpretation {
+        &self.interpretation
+    }
+
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    pub fn minutes(&self) -> u8 {
+        self.minutes
+    }
+
+    /// Returns the seconds, always even for values read from the packed form.
+    pub fn seconds(&self) -> u8 {
+        self.seconds
+    }
+
+    /// Returns the 10 ms increment, `None` if it is not stored.
+    pub fn hundredths(&self) -> Option<u8> {
+        self.hundredths
+    }
+
+    /// Returns the date and time as they are stored, `None` if a field is out of range.
+    pub fn to_naive_datetime(&self) -> Option<NaiveDateTime> {
+        let date_time = NaiveDate::from_ymd_opt(self.year as i32, self.month as u32, self.day as u32)?
+            .and_hms_opt(self.hour as u32, self.minutes as u32, self.seconds as u32)?;
+        match self.hundredths {
+            Some(hundredths) if hundredths > 199 => None,
+            Some(hundredths) => Some(date_time + Duration::milliseconds(hundredths as i64 * 10)),
+            None => Some(date_time),
+        }
+    }
+
+    /// Returns `true` if every field is in range.
+    pub fn is_valid(&self) -> bool {
+        self.to_naive_datetime().is_some()
+    }
+
+    /// Returns the timestamp as UTC using the interpretation, `None` if a field is out of range.
+    pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        self.to_naive_datetime()
+            .map(|date_time| self.interpretation.to_utc(&date_time))
+    }
+}
+
+impl Default for DosDateTime {
+    fn default() -> Self {
+        DosDateTime::new(1980, 1, 1, 0, 0, 0)
+    }
+}
+
+impl Display for DosDateTime {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.to_naive_datetime() {
+            Some(date_time) => write!(
+                f,
+                "{}{}",
+                date_time.format("%Y-%m-%dT%H:%M:%S"),
+                self.interpretation.suffix(Some(&date_time))
+            ),
+            None => write!(
+                f,
+                "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}",
+                self.year,
+                self.month,
+                self.day,
+                self.hour,
+                self.minutes,
+                self.seconds,
+                self.interpretation.suffix(None)
+            ),
+        }
+    }
+}
+
+impl WinTimestamp for DosDateTime {
+    fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        DosDateTime::to_datetime(self)
+    }
+
+    /// The packed `u32`, with the 10 ms increment and the exFAT UTC offset if they are stored.
+    fn raw_timestamp(&self) -> RawTimestamp {
+        match (self.hundredths, self.exfat_utc_offset) {
+            (hundredths, Some(utc_offset)) => RawTimestamp::ExFat {
+                timestamp: self.to_u32().rotate_left(16),
+                increment_10ms: hundredths.unwrap_or(0),
+                utc_offset,
+            },
+            (Some(increment_10ms), None) => RawTimestamp::Fat {
+                date_time: self.to_u32(),
+                increment_10ms,
+            },
+            (None, None) => RawTimestamp::U32(self.to_u32()),
+        }
+    }
+
+    fn precision(&self) -> TimestampPrecision {
+        match self.hundredths {
+            Some(_) => TimestampPrecision::TenMilliseconds,
+            None => TimestampPrecision::TwoSeconds,
+        }
+    }
+}
+
+impl SerializeTimestamp for DosDateTime {
+    fn rfc3339_nanos(&self) -> Option<String> {
+        self.to_naive_datetime().map(|date_time| {
+            format!(
+                "{}{}",
+                date_time.format("%Y-%m-%dT%H:%M:%S%.9f"),
+                self.interpretation.suffix(Some(&date_time))
+            )
+        })
+    }
+
+    fn local_interpretation(&self) -> Option<&TimeInterpretation> {
+        match self.interpretation {
+            TimeInterpretation::Utc => None,
+            ref interpretation => Some(interpretation),
+        }
+    }
+}
+
+impl DeserializeTimestamp for DosDateTime {
+    fn from_raw(raw: TimestampValue) -> Option<Self> {
+        match raw {
+            TimestampValue::Unsigned(raw) => DosDateTime::from_u32(u32::try_from(raw).ok()?).ok(),
+            TimestampValue::Fat { date_time, increment_10ms } => {
+                Some(DosDateTime::from_u32(date_time).ok()?.with_hundredths(increment_10ms))
+            }
+            TimestampValue::ExFat { timestamp, increment_10ms, utc_offset } => {
+                Some(DosDateTime::from_exfat(timestamp, increment_10ms, utc_offset))
+            }
+            _ => None,
+        }
+    }
+
+    /// The offset is kept as `TimeInterpretation::FixedOffset` and timestamps without an offset are read as
+    /// `TimeInterpretation::UnknownLocal`, invalid fields are kept as they are.
+    fn from_formatted(formatted: &str) -> Option<Self> {
+        let parts = TimestampParts::parse(formatted)?;
+        let dos_date_time = DosDateTime::from_components(
+            u16::try_from(parts.year).ok()?,
+            u8::try_from(parts.month).ok()?,
+            u8::try_from(parts.day).ok()?,
+            u8::try_from(parts.hour).ok()?,
+            u8::try_from(parts.minute).ok()?,
+            u8::try_from(parts.second).ok()?,
+            parts.nanosecond,
+        )?;
+        Some(match (parts.offset, parts.unknown_local) {
+            (Some(offset), _) => dos_date_time.with_interpretation(TimeInterpretation::FixedOffset(offset)),
+            (None, true) => dos_date_time.with_interpretation(TimeInterpretation::UnknownLocal),
+            (None, false) => dos_date_time,
+        })
+    }
+
+    fn from_utc(date_time: DateTime<Utc>) -> Option<Self> {
+        DosDateTime::from_components(
+            u16::try_from(date_time.year()).ok()?,
+            date_time.month() as u8,
+            date_time.day() as u8,
+            date_time.hour() as u8,
+            date_time.minute() as u8,
+            date_time.second() as u8,
+            date_time.nanosecond(),
+        )
+    }
+
+    fn with_local_interpretation(self, interpretation: TimeInterpretation) -> Option<Self> {
+        Some(self.with_interpretation(interpretation))
+    }
+}
+
+impl Debug for DosDateTime {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self)
+    }
+}
+
+impl Serialize for DosDateTime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_timestamp(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for DosDateTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_timestamp(deserializer)
+    }
+}
+
+/// The state of a `FileTime`, `0` and `0x7FFFFFFFFFFFFFFF` are used as "not set" and "never" (e.g. an account that never expires).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum FileTimeState {
+    NotSet,
+    Never,
+    Set,
+}
+
+/// [FILETIME](https://docs.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-filetime) struct parser.
+/// The raw value is kept with its 100-nanosecond precision. Formatted as `%Y-%m-%dT%H:%M:%SZ`, the alternate
+/// form (`{:#}`) prints the 7 fractional digits (e.g. `2020-12-05T12:19:48.1399495Z`).
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::{FileTime, FileTimeState};
+///
+/// let file_time = FileTime::try_new(132516443881399495).unwrap();
+/// assert_eq!(format!("{:#}", file_time), "2020-12-05T12:19:48.1399495Z");
+/// assert_eq!(FileTime::new(0).state(), FileTimeState::NotSet);
+/// assert!(FileTime::try_new(0x8000000000000000).is_err());
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct FileTime(u64);
+
+impl FileTime {
+    /// The "never" sentinel value.
+    pub const NEVER: u64 = 0x7FFF_FFFF_FFFF_FFFF;
+
+    /// Create FileTime struct from `u64`.
+    pub fn new(timestamp: u64) -> Self {
+        FileTime(timestamp)
+    }
+
+    /// Create FileTime struct from `u64`, values above `FileTime::NEVER` are not valid FILETIMEs.
+    pub fn try_new(timestamp: u64) -> std::result::Result<Self, ReaderError> {
+        match timestamp > Self::NEVER {
+            true => Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!("FileTime : Invalid timestamp '{:#X}'", timestamp),
+            ))),
+            false => Ok(FileTime(timestamp)),
+        }
+    }
+
+    /// Returns the number of 100-nanosecond intervals since 1601-01-01.
+    pub fn to_u64(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the FILETIME in its binary (little endian) form.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    pub fn state(&self) -> FileTimeState {
+        match self.0 {
+            0 => FileTimeState::NotSet,
+            Self::NEVER => FileTimeState::Never,
+            _ => FileTimeState::Set,
+        }
+    }
+
+    /// Returns the timestamp as UTC.
+    pub fn to_datetime(&self) -> DateTime<Utc> {
+        // Unwraping here is safe because every `u64` value is in the range of chrono (before the year 60,100).
+        let date_time = NaiveDate::from_ymd_opt(1601, 1, 1)
+            .unwrap()
+            .and_hms_nano_opt(0, 0, 0, 0)
+            .unwrap()
+            .checked_add_signed(Duration::seconds((self.0 / 10_000_000) as i64))
+            .unwrap()
+            .checked_add_signed(Duration::nanoseconds((self.0 % 10_000_000 * 100) as i64))
+            .unwrap();
+        DateTime::from_naive_utc_and_offset(date_time, Utc)
+    }
+}
+
+impl Display for FileTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let date_time = self.to_datetime();
+        match f.alternate() {
+            true => write!(
+                f,
+                "{}.{:07}Z",
+                date_time.format("%Y-%m-%dT%H:%M:%S"),
+                self.0 % 10_000_000
+            ),
+            false => write!(f, "{}", date_time.format("%Y-%m-%dT%H:%M:%SZ")),
+        }
+    }
+}
+
+impl WinTimestamp for FileTime {
+    /// Returns `None` for values above `FileTime::NEVER`.
+    fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        match self.0 > Self::NEVER {
+            true => None,
+            false => Some(FileTime::to_datetime(self)),
+        }
+    }
+
+    fn raw_timestamp(&self) -> RawTimestamp {
+        RawTimestamp::U64(self.0)
+    }
+
+    fn precision(&self) -> TimestampPrecision {
+        TimestampPrecision::HundredNanoseconds
+    }
+}
+
+impl SerializeTimestamp for FileTime {}
+
+impl DeserializeTimestamp for FileTime {
+    fn from_raw(raw: TimestampValue) -> Option<Self> {
+        match raw {
+            TimestampValue::Unsigned(raw) => Some(FileTime(raw)),
+            _ => None,
+        }
+    }
+
+    /// Timestamps formatted without the fractions of a second in the last second before `FileTime::NEVER` are read as `NEVER`.
+    fn from_formatted(formatted: &str) -> Option<Self> {
+        let file_time = Self::from_utc(TimestampParts::parse(formatted)?.to_datetime()?)?;
+        match file_time.0 == Self::NEVER - Self::NEVER % 10_000_000 {
+            true => Some(FileTime(Self::NEVER)),
+            false => Some(file_time),
+        }
+    }
+
+    fn from_utc(date_time: DateTime<Utc>) -> Option<Self> {
+        Some(FileTime::from(date_time))
+    }
+}
+
+impl Debug for FileTime {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self)
+    }
+}
+
+impl Serialize for FileTime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_timestamp(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for FileTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_timestamp(deserializer)
+    }
+}
+
+impl From<DateTime<Utc>> for FileTime {
+    /// Dates before 1601-01-01 are clamped to `0`, the nanoseconds are truncated to 100-nanosecond intervals.
+    fn from(value: DateTime<Utc>) -> Self {
+        // Unwraping here is safe because the timestamp is always valid.
+        let duration = value.naive_utc()
+            - NaiveDate::from_ymd_opt(1601, 1, 1).unwrap().and_hms_nano_opt(0, 0, 0, 0).unwrap();
+        match duration.num_seconds() {
+            seconds if seconds < 0 => FileTime(0),
+            seconds => FileTime(
+                (seconds as u64)
+                    .saturating_mul(10_000_000)
+                    .saturating_add(duration.subsec_nanos() as u64 / 100),
+            ),
+        }
+    }
+}
+
+impl From<FileTime> for DateTime<Utc> {
+    fn from(value: FileTime) -> Self {
+        value.to_datetime()
+    }
+}
+
+impl From<&FileTime> for DateTime<Utc> {
+    fn from(value: &FileTime) -> Self {
+        value.to_datetime()
+    }
+}
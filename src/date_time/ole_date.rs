@@ -0,0 +1,171 @@
+use super::timestamp_format::{
+    deserialize_timestamp, serialize_timestamp, DeserializeTimestamp, SerializeTimestamp,
+    TimestampParts, TimestampValue,
+};
+use super::win_timestamp::{RawTimestamp, TimestampPrecision, WinTimestamp};
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::io::{Cursor, Read};
+
+const MILLISECONDS_PER_DAY: f64 = 86_400_000.0;
+
+/// 1899-12-30, day `0` of OLE Automation dates.
+fn ole_epoch() -> NaiveDateTime {
+    // Unwraping here is safe because the date is always valid.
+    NaiveDate::from_ymd_opt(1899, 12, 30)
+        .unwrap()
+        .and_hms_opt(0, 0, 0)
+        .unwrap()
+}
+
+/// [OLE Automation date](https://learn.microsoft.com/en-us/dotnet/api/system.datetime.tooadate) (`VT_DATE`) parser.
+/// The integer part is the number of days since 1899-12-30 and the fractional part is the time of day. For negative values the
+/// fractional part is still added to the day, so `-1.25` is 1899-12-29 06:00:00 and `-0.5` is the same as `0.5`.
+/// The time of day is rounded to milliseconds. Formatted like `FileTime`, values outside the range `0100-01-01` to `9999-12-31`
+/// are formatted as the raw number.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::OleDate;
+///
+/// assert_eq!(OleDate::new(44260.5).to_string(), "2021-03-05T12:00:00Z");
+/// assert_eq!(OleDate::new(-1.25).to_string(), "1899-12-29T06:00:00Z");
+/// ```
+#[derive(Clone, Copy, PartialEq)]
+pub struct OleDate(f64);
+
+impl OleDate {
+    /// The smallest valid value, 0100-01-01.
+    pub const MIN: f64 = -657_434.0;
+    /// The biggest valid value, 9999-12-31 23:59:59.999.
+    pub const MAX: f64 = 2_958_465.999_999_99;
+
+    pub fn new(value: f64) -> Self {
+        Self(value)
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        Ok(Self(r.read_f64::<LittleEndian>()?))
+    }
+
+    /// Returns the raw value.
+    pub fn to_f64(&self) -> f64 {
+        self.0
+    }
+
+    /// Returns the OLE date in its binary (little endian) form.
+    pub fn to_bytes(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Returns `true` if the value is in the supported range.
+    pub fn is_valid(&self) -> bool {
+        (Self::MIN..=Self::MAX).contains(&self.0)
+    }
+
+    /// Returns the timestamp as UTC, `None` if the value is not valid.
+    pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        if !self.is_valid() {
+            return None;
+        }
+        let days = self.0.trunc();
+        let milliseconds = ((self.0 - days).abs() * MILLISECONDS_PER_DAY).round() as i64;
+        let date_time =
+            ole_epoch() + Duration::days(days as i64) + Duration::milliseconds(milliseconds);
+        Some(DateTime::from_naive_utc_and_offset(date_time, Utc))
+    }
+}
+
+impl Display for OleDate {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.to_datetime() {
+            Some(date_time) => write!(f, "{}", date_time.format("%Y-%m-%dT%H:%M:%SZ")),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl WinTimestamp for OleDate {
+    fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        OleDate::to_datetime(self)
+    }
+
+    fn raw_timestamp(&self) -> RawTimestamp {
+        RawTimestamp::F64(self.0)
+    }
+
+    fn precision(&self) -> TimestampPrecision {
+        TimestampPrecision::Milliseconds
+    }
+}
+
+impl SerializeTimestamp for OleDate {}
+
+impl DeserializeTimestamp for OleDate {
+    fn from_raw(raw: TimestampValue) -> Option<Self> {
+        match raw {
+            TimestampValue::Float(raw) => Some(OleDate(raw)),
+            TimestampValue::Signed(raw) => Some(OleDate(raw as f64)),
+            TimestampValue::Unsigned(raw) => Some(OleDate(raw as f64)),
+            _ => None,
+        }
+    }
+
+    /// Values that are not valid are formatted as the raw number.
+    fn from_formatted(formatted: &str) -> Option<Self> {
+        match TimestampParts::parse(formatted) {
+            Some(parts) => Self::from_utc(parts.to_datetime()?),
+            None => formatted.parse().ok().map(OleDate),
+        }
+    }
+
+    fn from_utc(date_time: DateTime<Utc>) -> Option<Self> {
+        Some(OleDate::from(date_time))
+    }
+}
+
+impl Debug for OleDate {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self)
+    }
+}
+
+impl Serialize for OleDate {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_timestamp(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for OleDate {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_timestamp(deserializer)
+    }
+}
+
+impl From<DateTime<Utc>> for OleDate {
+    fn from(value: DateTime<Utc>) -> Self {
+        let milliseconds = (value.naive_utc() - ole_epoch()).num_milliseconds();
+        let days = milliseconds.div_euclid(MILLISECONDS_PER_DAY as i64);
+        let time =
+            milliseconds.rem_euclid(MILLISECONDS_PER_DAY as i64) as f64 / MILLISECONDS_PER_DAY;
+        match days < 0 {
+            // The time of day is subtracted from negative days
+            true => Self(days as f64 - time),
+            false => Self(days as f64 + time),
+        }
+    }
+}
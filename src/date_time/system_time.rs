@@ -0,0 +1,399 @@
+use super::timestamp_format::{
+    deserialize_timestamp, serialize_timestamp, DeserializeTimestamp, SerializeTimestamp,
+    TimestampParts, TimestampValue,
+};
+use super::win_timestamp::{RawTimestamp, TimestampPrecision, WinTimestamp};
+use crate::utils::hex_to_bytes;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveDateTime, Timelike, Utc, Weekday};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::convert::TryFrom;
+use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
+use std::io::{Cursor, Error, ErrorKind, Read};
+
+const DAY_NAMES: [&str; 7] = [
+    "Sunday",
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+];
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+/// The "day-of-week in month" form of a `SYSTEMTIME` used by the time zone transition dates (`wYear` is `0`),
+/// e.g. the last Sunday of March at 02:00:00.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayOfWeekInMonth {
+    /// 1 to 12.
+    pub month: u16,
+    /// 0 (Sunday) to 6 (Saturday).
+    pub day_of_week: u16,
+    /// The occurrence of the day in the month, 1 to 5 where 5 is the last occurrence.
+    pub occurrence: u16,
+    pub hour: u16,
+    pub minute: u16,
+    pub second: u16,
+    pub milliseconds: u16,
+}
+
+impl DayOfWeekInMonth {
+    /// Returns the date and time of the rule in `year`, `None` if the rule is not valid.
+    pub fn date_in_year(&self, year: i32) -> Option<NaiveDateTime> {
+        if !(1..=5).contains(&self.occurrence) {
+            return None;
+        }
+        let weekday = *[
+            Weekday::Sun,
+            Weekday::Mon,
+            Weekday::Tue,
+            Weekday::Wed,
+            Weekday::Thu,
+            Weekday::Fri,
+            Weekday::Sat,
+        ]
+        .get(self.day_of_week as usize)?;
+        let first = NaiveDate::from_ymd_opt(year, self.month as u32, 1)?;
+        let first_occurrence = first
+            + Duration::days(
+                (7 + weekday.num_days_from_sunday() as i64
+                    - first.weekday().num_days_from_sunday() as i64)
+                    % 7,
+            );
+        let mut date = first_occurrence + Duration::weeks(self.occurrence as i64 - 1);
+        // The fifth occurrence means the last occurrence in the month
+        while date.month() != first.month() {
+            date -= Duration::weeks(1);
+        }
+        date.and_hms_milli_opt(
+            self.hour as u32,
+            self.minute as u32,
+            self.second as u32,
+            self.milliseconds as u32,
+        )
+    }
+}
+
+impl Display for DayOfWeekInMonth {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let occurrence = match self.occurrence {
+            1 => "First",
+            2 => "Second",
+            3 => "Third",
+            4 => "Fourth",
+            _ => "Last",
+        };
+        write!(
+            f,
+            "{} {} of {} at {:02}:{:02}:{:02}",
+            occurrence,
+            DAY_NAMES.get(self.day_of_week as usize).unwrap_or(&"?"),
+            MONTH_NAMES
+                .get((self.month as usize).wrapping_sub(1))
+                .unwrap_or(&"?"),
+            self.hour,
+            self.minute,
+            self.second
+        )
+    }
+}
+
+/// [SYSTEMTIME](https://learn.microsoft.com/en-us/windows/win32/api/minwinbase/ns-minwinbase-systemtime) struct parser.
+/// The fields are kept as they were read, use `SystemTime::validate` to check them. Valid timestamps are formatted like `FileTime`,
+/// the "day-of-week in month" form is formatted as a rule (e.g. `Last Sunday of March at 02:00:00`) and other values
+/// are formatted from the raw fields (e.g. `0000-00-00T00:00:00Z`).
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::SystemTime;
+///
+/// let system_time = SystemTime::new(2021, 3, 5, 14, 9, 30, 0);
+/// assert_eq!(system_time.to_string(), "2021-03-05T14:09:30Z");
+/// ```
+#[derive(Clone, PartialEq)]
+pub struct SystemTime {
+    pub year: u16,
+    pub month: u16,
+    /// 0 (Sunday) to 6 (Saturday).
+    pub day_of_week: u16,
+    pub day: u16,
+    pub hour: u16,
+    pub minute: u16,
+    pub second: u16,
+    pub milliseconds: u16,
+}
+
+impl SystemTime {
+    /// Create a `SystemTime` from the date and time components, the day of the week is calculated from the date.
+    pub fn new(
+        year: u16,
+        month: u16,
+        day: u16,
+        hour: u16,
+        minute: u16,
+        second: u16,
+        milliseconds: u16,
+    ) -> Self {
+        let day_of_week = NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+            .map(|date| date.weekday().num_days_from_sunday() as u16)
+            .unwrap_or(0);
+        Self {
+            year,
+            month,
+            day_of_week,
+            day,
+            hour,
+            minute,
+            second,
+            milliseconds,
+        }
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        Ok(Self {
+            year: r.read_u16::<LittleEndian>()?,
+            month: r.read_u16::<LittleEndian>()?,
+            day_of_week: r.read_u16::<LittleEndian>()?,
+            day: r.read_u16::<LittleEndian>()?,
+            hour: r.read_u16::<LittleEndian>()?,
+            minute: r.read_u16::<LittleEndian>()?,
+            second: r.read_u16::<LittleEndian>()?,
+            milliseconds: r.read_u16::<LittleEndian>()?,
+        })
+    }
+
+    /// Returns the `SYSTEMTIME` in its binary (little endian) form.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        for (i, field) in [
+            self.year,
+            self.month,
+            self.day_of_week,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            self.milliseconds,
+        ]
+        .iter()
+        .enumerate()
+        {
+            bytes[i * 2..i * 2 + 2].copy_from_slice(&field.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Returns `true` if every field is `0`, used for timestamps that are not set.
+    pub fn is_empty(&self) -> bool {
+        self.to_bytes() == [0; 16]
+    }
+
+    /// Returns `true` if the value is in the "day-of-week in month" form (`wYear` is `0` and `wMonth` is set).
+    pub fn is_day_of_week_in_month(&self) -> bool {
+        self.year == 0 && self.month != 0
+    }
+
+    /// Returns the "day-of-week in month" rule if the value is in that form.
+    pub fn day_of_week_in_month(&self) -> Option<DayOfWeekInMonth> {
+        match self.is_day_of_week_in_month() {
+            true => Some(DayOfWeekInMonth {
+                month: self.month,
+                day_of_week: self.day_of_week,
+                occurrence: self.day,
+                hour: self.hour,
+                minute: self.minute,
+                second: self.second,
+                milliseconds: self.milliseconds,
+            }),
+            false => None,
+        }
+    }
+
+    /// Check every field of an absolute timestamp, the error names the first invalid field.
+    pub fn validate(&self) -> Result<(), ReaderError> {
+        let days_in_month = match self.month {
+            2 => {
+                let year = self.year as i32;
+                match (year % 4 == 0 && year % 100 != 0) || year % 400 == 0 {
+                    true => 29,
+                    false => 28,
+                }
+            }
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        let checks = [
+            ("year", self.year, (1601..=30827).contains(&self.year)),
+            ("month", self.month, (1..=12).contains(&self.month)),
+            ("day_of_week", self.day_of_week, self.day_of_week <= 6),
+            ("day", self.day, (1..=days_in_month).contains(&self.day)),
+            ("hour", self.hour, self.hour <= 23),
+            ("minute", self.minute, self.minute <= 59),
+            ("second", self.second, self.second <= 59),
+            ("milliseconds", self.milliseconds, self.milliseconds <= 999),
+        ];
+        match checks.iter().find(|(_, _, valid)| !valid) {
+            Some((field, value, _)) => Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!("SystemTime : Invalid {} '{}'", field, value),
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns `true` if the value is a valid absolute timestamp.
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Returns the timestamp as UTC, `None` if the value is not a valid absolute timestamp.
+    pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        self.validate().ok()?;
+        let date = NaiveDate::from_ymd_opt(self.year as i32, self.month as u32, self.day as u32)?;
+        let time = date.and_hms_milli_opt(
+            self.hour as u32,
+            self.minute as u32,
+            self.second as u32,
+            self.milliseconds as u32,
+        )?;
+        Some(DateTime::from_naive_utc_and_offset(time, Utc))
+    }
+}
+
+impl Display for SystemTime {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        if let Some(date_time) = self.to_datetime() {
+            return write!(f, "{}", date_time.format("%Y-%m-%dT%H:%M:%SZ"));
+        }
+        if let Some(rule) = self.day_of_week_in_month() {
+            return write!(f, "{}", rule);
+        }
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+impl WinTimestamp for SystemTime {
+    fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        SystemTime::to_datetime(self)
+    }
+
+    fn raw_timestamp(&self) -> RawTimestamp {
+        RawTimestamp::Bytes(self.to_bytes().to_vec())
+    }
+
+    fn precision(&self) -> TimestampPrecision {
+        TimestampPrecision::Milliseconds
+    }
+}
+
+impl SerializeTimestamp for SystemTime {}
+
+impl DeserializeTimestamp for SystemTime {
+    /// The raw value is the hex string of the `SYSTEMTIME` bytes.
+    fn from_raw(raw: TimestampValue) -> Option<Self> {
+        match raw {
+            TimestampValue::String(raw) => SystemTime::from_buffer(&hex_to_bytes(&raw)?).ok(),
+            _ => None,
+        }
+    }
+
+    fn from_formatted(formatted: &str) -> Option<Self> {
+        if let Some(parts) = TimestampParts::parse(formatted) {
+            return Some(SystemTime::new(
+                u16::try_from(parts.year).ok()?,
+                u16::try_from(parts.month).ok()?,
+                u16::try_from(parts.day).ok()?,
+                u16::try_from(parts.hour).ok()?,
+                u16::try_from(parts.minute).ok()?,
+                u16::try_from(parts.second).ok()?,
+                (parts.nanosecond / 1_000_000) as u16,
+            ));
+        }
+        // The "day-of-week in month" form, e.g. `Last Sunday of March at 02:00:00`
+        let words = formatted.split(' ').collect::<Vec<&str>>();
+        if words.len() != 6 || words[2] != "of" || words[4] != "at" {
+            return Self::from_raw(TimestampValue::String(formatted.to_string()));
+        }
+        let occurrence = ["First", "Second", "Third", "Fourth", "Last"]
+            .iter()
+            .position(|occurrence| *occurrence == words[0])?;
+        let mut time = words[5].splitn(3, ':');
+        Some(SystemTime {
+            year: 0,
+            month: MONTH_NAMES.iter().position(|month| *month == words[3])? as u16 + 1,
+            day_of_week: DAY_NAMES.iter().position(|day| *day == words[1])? as u16,
+            day: occurrence as u16 + 1,
+            hour: time.next()?.parse().ok()?,
+            minute: time.next()?.parse().ok()?,
+            second: time.next()?.parse().ok()?,
+            milliseconds: 0,
+        })
+    }
+
+    fn from_utc(date_time: DateTime<Utc>) -> Option<Self> {
+        Some(SystemTime::from(date_time))
+    }
+}
+
+impl Debug for SystemTime {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        write!(f, "{}", self)
+    }
+}
+
+impl Serialize for SystemTime {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serialize_timestamp(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SystemTime {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserialize_timestamp(deserializer)
+    }
+}
+
+impl From<DateTime<Utc>> for SystemTime {
+    fn from(value: DateTime<Utc>) -> Self {
+        Self {
+            year: value.year() as u16,
+            month: value.month() as u16,
+            day_of_week: value.weekday().num_days_from_sunday() as u16,
+            day: value.day() as u16,
+            hour: value.hour() as u16,
+            minute: value.minute() as u16,
+            second: value.second() as u16,
+            milliseconds: (value.nanosecond() / 1_000_000).min(999) as u16,
+        }
+    }
+}
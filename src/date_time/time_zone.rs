@@ -0,0 +1,387 @@
+use super::SystemTime;
+use crate::utils::read_utf16_string;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use chrono::{DateTime, Datelike, Duration, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::{Cursor, Read};
+
+/// Read a fixed size `WCHAR` array.
+fn read_name<R: Read>(r: &mut R, len: usize) -> Result<String, ReaderError> {
+    read_utf16_string(r, Some(len))
+}
+
+/// Returns the name as a fixed size `WCHAR` array, long names are truncated.
+fn encode_name(name: &str, len: usize) -> Vec<u8> {
+    let mut bytes = name
+        .encode_utf16()
+        .take(len - 1)
+        .flat_map(|c| c.to_le_bytes().to_vec())
+        .collect::<Vec<u8>>();
+    bytes.resize(len * 2, 0);
+    bytes
+}
+
+/// The standard and daylight saving time rule of a time zone, the `REG_TZI_FORMAT` binary value (`TZI`) of the keys under
+/// `HKLM\SOFTWARE\Microsoft\Windows NT\CurrentVersion\Time Zones` and of their `Dynamic DST` keys.
+/// The biases are in minutes and `UTC = local time + bias`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeZoneRule {
+    pub bias: i32,
+    pub standard_bias: i32,
+    pub daylight_bias: i32,
+    /// The transition from daylight saving time to standard time, in local daylight saving time.
+    pub standard_date: SystemTime,
+    /// The transition from standard time to daylight saving time, in local standard time. The time zone does not use daylight saving time if `month` is `0`.
+    pub daylight_date: SystemTime,
+}
+
+impl TimeZoneRule {
+    /// Create a rule for a time zone that does not use daylight saving time.
+    pub fn fixed(bias: i32) -> Self {
+        let empty = SystemTime::new(0, 0, 0, 0, 0, 0, 0);
+        Self {
+            bias,
+            standard_bias: 0,
+            daylight_bias: 0,
+            standard_date: empty.clone(),
+            daylight_date: empty,
+        }
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        Ok(Self {
+            bias: r.read_i32::<LittleEndian>()?,
+            standard_bias: r.read_i32::<LittleEndian>()?,
+            daylight_bias: r.read_i32::<LittleEndian>()?,
+            standard_date: SystemTime::from_reader(r)?,
+            daylight_date: SystemTime::from_reader(r)?,
+        })
+    }
+
+    /// Returns the rule in its `REG_TZI_FORMAT` binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&self.bias.to_le_bytes());
+        bytes.extend_from_slice(&self.standard_bias.to_le_bytes());
+        bytes.extend_from_slice(&self.daylight_bias.to_le_bytes());
+        bytes.extend_from_slice(&self.standard_date.to_bytes());
+        bytes.extend_from_slice(&self.daylight_date.to_bytes());
+        bytes
+    }
+
+    /// Returns the offset from UTC in minutes (positive east of UTC) during standard time.
+    pub fn standard_offset(&self) -> i32 {
+        -(self.bias + self.standard_bias)
+    }
+
+    /// Returns `true` if the time zone uses daylight saving time.
+    pub fn has_daylight_time(&self) -> bool {
+        self.daylight_date.month != 0 && self.standard_date.month != 0
+    }
+
+    /// Returns the date and time of the transition `date` in `year`, `None` if the transition does not happen in `year`.
+    fn transition_in_year(date: &SystemTime, year: i32) -> Option<NaiveDateTime> {
+        match date.day_of_week_in_month() {
+            Some(rule) => rule.date_in_year(year),
+            None => date
+                .to_datetime()
+                .map(|date_time| date_time.naive_utc())
+                .filter(|date_time| date_time.year() == year),
+        }
+    }
+
+    /// Returns `true` if the local time `local` is in daylight saving time.
+    pub fn is_daylight_time(&self, local: &NaiveDateTime) -> bool {
+        if !self.has_daylight_time() {
+            return false;
+        }
+        let start = Self::transition_in_year(&self.daylight_date, local.year());
+        let end = Self::transition_in_year(&self.standard_date, local.year());
+        match (start, end) {
+            (Some(start), Some(end)) if start < end => *local >= start && *local < end,
+            // Daylight saving time spans the end of the year in the southern hemisphere
+            (Some(start), Some(end)) => *local >= start || *local < end,
+            _ => false,
+        }
+    }
+
+    /// Returns the offset from UTC in minutes (positive east of UTC) at the local time `local`.
+    pub fn utc_offset(&self, local: &NaiveDateTime) -> i32 {
+        match self.is_daylight_time(local) {
+            true => -(self.bias + self.daylight_bias),
+            false => self.standard_offset(),
+        }
+    }
+
+    /// Convert the local time `local` to UTC.
+    pub fn local_to_utc(&self, local: &NaiveDateTime) -> DateTime<Utc> {
+        DateTime::from_naive_utc_and_offset(
+            *local - Duration::minutes(self.utc_offset(local) as i64),
+            Utc,
+        )
+    }
+}
+
+/// The rules of a time zone, the rule of the time zone and the per-year rules of its `Dynamic DST` key.
+/// Years before the first per-year rule use the first rule and years after the last per-year rule use the last rule.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::{TimeZoneRule, TimeZoneRules};
+/// use chrono::NaiveDate;
+///
+/// let rules = TimeZoneRules::new(TimeZoneRule::fixed(-180)).with_dynamic_rule(2016, TimeZoneRule::fixed(-120));
+/// let local = NaiveDate::from_ymd_opt(2010, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap();
+/// assert_eq!(rules.local_to_utc(&local).to_string(), "2010-01-01 10:00:00 UTC");
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeZoneRules {
+    pub rule: TimeZoneRule,
+    /// The per-year rules by year.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dynamic_rules: BTreeMap<i32, TimeZoneRule>,
+}
+
+impl TimeZoneRules {
+    pub fn new(rule: TimeZoneRule) -> Self {
+        Self {
+            rule,
+            dynamic_rules: BTreeMap::new(),
+        }
+    }
+
+    /// Add the rule of `year` from the `Dynamic DST` key.
+    pub fn with_dynamic_rule(mut self, year: i32, rule: TimeZoneRule) -> Self {
+        self.dynamic_rules.insert(year, rule);
+        self
+    }
+
+    /// Returns the rule used in `year`.
+    pub fn rule_for_year(&self, year: i32) -> &TimeZoneRule {
+        self.dynamic_rules
+            .range(..=year)
+            .next_back()
+            .or_else(|| self.dynamic_rules.iter().next())
+            .map(|(_, rule)| rule)
+            .unwrap_or(&self.rule)
+    }
+
+    /// Returns `true` if the local time `local` is in daylight saving time.
+    pub fn is_daylight_time(&self, local: &NaiveDateTime) -> bool {
+        self.rule_for_year(local.year()).is_daylight_time(local)
+    }
+
+    /// Returns the offset from UTC in minutes (positive east of UTC) at the local time `local`.
+    pub fn utc_offset(&self, local: &NaiveDateTime) -> i32 {
+        self.rule_for_year(local.year()).utc_offset(local)
+    }
+
+    /// Convert the local time `local` to UTC using the rule of its year.
+    pub fn local_to_utc(&self, local: &NaiveDateTime) -> DateTime<Utc> {
+        self.rule_for_year(local.year()).local_to_utc(local)
+    }
+}
+
+impl From<TimeZoneRule> for TimeZoneRules {
+    fn from(rule: TimeZoneRule) -> Self {
+        Self::new(rule)
+    }
+}
+
+/// [TIME_ZONE_INFORMATION](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/ns-timezoneapi-time_zone_information) struct parser.
+/// The same fields are stored as separate values under `HKLM\SYSTEM\CurrentControlSet\Control\TimeZoneInformation`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimeZoneInformation {
+    pub bias: i32,
+    pub standard_name: String,
+    pub standard_date: SystemTime,
+    pub standard_bias: i32,
+    pub daylight_name: String,
+    pub daylight_date: SystemTime,
+    pub daylight_bias: i32,
+}
+
+impl TimeZoneInformation {
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        Ok(Self {
+            bias: r.read_i32::<LittleEndian>()?,
+            standard_name: read_name(r, 32)?,
+            standard_date: SystemTime::from_reader(r)?,
+            standard_bias: r.read_i32::<LittleEndian>()?,
+            daylight_name: read_name(r, 32)?,
+            daylight_date: SystemTime::from_reader(r)?,
+            daylight_bias: r.read_i32::<LittleEndian>()?,
+        })
+    }
+
+    /// Returns the `TIME_ZONE_INFORMATION` in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&self.bias.to_le_bytes());
+        bytes.extend_from_slice(&encode_name(&self.standard_name, 32));
+        bytes.extend_from_slice(&self.standard_date.to_bytes());
+        bytes.extend_from_slice(&self.standard_bias.to_le_bytes());
+        bytes.extend_from_slice(&encode_name(&self.daylight_name, 32));
+        bytes.extend_from_slice(&self.daylight_date.to_bytes());
+        bytes.extend_from_slice(&self.daylight_bias.to_le_bytes());
+        bytes
+    }
+
+    /// Returns the standard and daylight saving time rule.
+    pub fn rule(&self) -> TimeZoneRule {
+        TimeZoneRule {
+            bias: self.bias,
+            standard_bias: self.standard_bias,
+            daylight_bias: self.daylight_bias,
+            standard_date: self.standard_date.clone(),
+            daylight_date: self.daylight_date.clone(),
+        }
+    }
+}
+
+/// [DYNAMIC_TIME_ZONE_INFORMATION](https://learn.microsoft.com/en-us/windows/win32/api/timezoneapi/ns-timezoneapi-dynamic_time_zone_information) struct parser.
+/// The time zone key name is the name of the key under `Time Zones` that has the `Dynamic DST` rules.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DynamicTimeZoneInformation {
+    #[serde(flatten)]
+    pub time_zone_information: TimeZoneInformation,
+    pub time_zone_key_name: String,
+    /// The per-year rules are not used if `true`.
+    pub dynamic_daylight_time_disabled: bool,
+}
+
+impl DynamicTimeZoneInformation {
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let time_zone_information = TimeZoneInformation::from_reader(r)?;
+        let time_zone_key_name = read_name(r, 128)?;
+        let dynamic_daylight_time_disabled = r.read_u8()? != 0;
+        // The struct is padded to 4 bytes
+        r.take(3).read_to_end(&mut vec![])?;
+        Ok(Self {
+            time_zone_information,
+            time_zone_key_name,
+            dynamic_daylight_time_disabled,
+        })
+    }
+
+    /// Returns the `DYNAMIC_TIME_ZONE_INFORMATION` in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.time_zone_information.to_bytes();
+        bytes.extend_from_slice(&encode_name(&self.time_zone_key_name, 128));
+        bytes.extend_from_slice(&[self.dynamic_daylight_time_disabled as u8, 0, 0, 0]);
+        bytes
+    }
+
+    /// Returns the time zone rules with the per-year rules `dynamic_rules` read from the `Dynamic DST` key
+    /// of `time_zone_key_name`, the per-year rules are ignored if dynamic daylight saving time is disabled.
+    pub fn rules(&self, dynamic_rules: BTreeMap<i32, TimeZoneRule>) -> TimeZoneRules {
+        let mut rules = TimeZoneRules::new(self.time_zone_information.rule());
+        if !self.dynamic_daylight_time_disabled {
+            rules.dynamic_rules = dynamic_rules;
+        }
+        rules
+    }
+}
+
+/// How a timestamp stored without a time zone (e.g. `DosDateTime`) is interpreted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub enum TimeInterpretation {
+    /// The timestamp is in UTC.
+    #[default]
+    Utc,
+    /// The timestamp is in local time with a fixed offset from UTC in minutes (positive east of UTC).
+    FixedOffset(i32),
+    /// The timestamp is in local time with an unknown offset (e.g. exFAT entries without a valid UTC offset), it is formatted
+    /// without `Z` or an offset and converted to UTC as if the offset was `0`.
+    UnknownLocal,
+    /// The timestamp is in the local time of the time zone rule.
+    TimeZone(TimeZoneRule),
+    /// The timestamp is in the local time of the time zone, using the per-year rules of its year.
+    TimeZoneRules(TimeZoneRules),
+}
+
+impl From<TimeZoneRule> for TimeInterpretation {
+    fn from(rule: TimeZoneRule) -> Self {
+        TimeInterpretation::TimeZone(rule)
+    }
+}
+
+impl From<TimeZoneRules> for TimeInterpretation {
+    fn from(rules: TimeZoneRules) -> Self {
+        TimeInterpretation::TimeZoneRules(rules)
+    }
+}
+
+impl TimeInterpretation {
+    /// Decode the UTC offset field of exFAT directory entries, the offset is a signed 7-bit number of 15 minute intervals
+    /// and the top bit is set if the offset is valid. Returns `None` if the offset is not valid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use winparsingtools::date_time::TimeInterpretation;
+    ///
+    /// assert_eq!(TimeInterpretation::from_exfat_utc_offset(0x8C), Some(TimeInterpretation::FixedOffset(180)));
+    /// assert_eq!(TimeInterpretation::from_exfat_utc_offset(0xEC), Some(TimeInterpretation::FixedOffset(-300)));
+    /// assert_eq!(TimeInterpretation::from_exfat_utc_offset(0x0C), None);
+    /// ```
+    pub fn from_exfat_utc_offset(utc_offset: u8) -> Option<Self> {
+        match utc_offset & 0x80 {
+            0 => None,
+            // Sign extend the low 7 bits
+            _ => Some(TimeInterpretation::FixedOffset(((utc_offset << 1) as i8 >> 1) as i32 * 15)),
+        }
+    }
+
+    /// Returns the offset from UTC in minutes at the local time `local`, `None` for UTC and unknown offsets.
+    pub fn utc_offset(&self, local: &NaiveDateTime) -> Option<i32> {
+        match self {
+            TimeInterpretation::Utc | TimeInterpretation::UnknownLocal => None,
+            TimeInterpretation::FixedOffset(offset) => Some(*offset),
+            TimeInterpretation::TimeZone(rule) => Some(rule.utc_offset(local)),
+            TimeInterpretation::TimeZoneRules(rules) => Some(rules.utc_offset(local)),
+        }
+    }
+
+    /// Convert the local time `local` to UTC.
+    pub fn to_utc(&self, local: &NaiveDateTime) -> DateTime<Utc> {
+        let offset = self.utc_offset(local).unwrap_or(0);
+        DateTime::from_naive_utc_and_offset(*local - Duration::minutes(offset as i64), Utc)
+    }
+
+    /// Returns the suffix of the formatted timestamps, `Z` for UTC or the offset (e.g. `+03:00`).
+    /// Time zone rules use the standard time offset if the local time is not known.
+    pub(crate) fn suffix(&self, local: Option<&NaiveDateTime>) -> String {
+        let offset = match (self, local) {
+            (TimeInterpretation::UnknownLocal, _) => return String::new(),
+            (TimeInterpretation::TimeZone(rule), None) => Some(rule.standard_offset()),
+            (TimeInterpretation::TimeZoneRules(rules), None) => Some(rules.rule.standard_offset()),
+            (_, Some(local)) => self.utc_offset(local),
+            (_, None) => self.utc_offset(&NaiveDateTime::default()),
+        };
+        match offset {
+            None => "Z".to_string(),
+            Some(offset) => format!(
+                "{}{:02}:{:02}",
+                if offset < 0 { '-' } else { '+' },
+                offset.abs() / 60,
+                offset.abs() % 60
+            ),
+        }
+    }
+}
@@ -0,0 +1,331 @@
+use super::win_timestamp::WinTimestamp;
+use super::TimeInterpretation;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::de::Error as _;
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::cell::Cell;
+
+/// How the time types (e.g. `FileTime` and `DosDateTime`) are serialized. Deserializing accepts every format, integers are
+/// read as raw values unless the input format is set to `TimestampFormat::EpochMillis` with `with_input_timestamp_format`.
+///
+/// Only `Raw` and `RawAndFormatted` round-trip exactly (e.g. the 10 ms increment and the exFAT UTC offset of a `DosDateTime`).
+/// The other formats, including the default `Rfc3339`, are not lossless, deserializing gives back only what they print
+/// (e.g. `Rfc3339` drops the fractions of a second).
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Default)]
+pub enum TimestampFormat {
+    /// The formatted timestamp with second precision (e.g. `2020-12-05T12:19:48Z`), the fractions of a second are lost.
+    #[default]
+    Rfc3339,
+    /// The formatted timestamp with nanosecond precision (e.g. `2020-12-05T12:19:48.139949500Z`).
+    Rfc3339Nanos,
+    /// The number of milliseconds since 1970-01-01 in UTC.
+    EpochMillis,
+    /// The raw value as it is stored (e.g. the `u64` of a `FileTime`). Timestamps stored in local time (e.g. a `DosDateTime`
+    /// with a `TimeInterpretation` other than UTC) are serialized with their interpretation
+    /// (e.g. `{"raw":2583122118,"interpretation":{"FixedOffset":180}}`).
+    Raw,
+    /// The raw value and the formatted timestamp (e.g. `{"raw":132516443881399495,"formatted":"2020-12-05T12:19:48Z"}`),
+    /// the interpretation of timestamps stored in local time is added as with `TimestampFormat::Raw`.
+    RawAndFormatted,
+}
+
+thread_local! {
+    /// The timestamp format set with `with_timestamp_format` for the current thread.
+    static SCOPED_TIMESTAMP_FORMAT: Cell<Option<TimestampFormat>> = const { Cell::new(None) };
+    /// The timestamp format of the input set with `with_input_timestamp_format` for the current thread.
+    static INPUT_TIMESTAMP_FORMAT: Cell<Option<TimestampFormat>> = const { Cell::new(None) };
+}
+
+/// Returns the timestamp format used by the current thread, the default format outside of `with_timestamp_format`.
+pub fn timestamp_format() -> TimestampFormat {
+    SCOPED_TIMESTAMP_FORMAT.with(|scoped| scoped.get()).unwrap_or_default()
+}
+
+/// Restores the previous scoped timestamp format when dropped.
+struct ScopedTimestampFormat(Option<TimestampFormat>);
+
+impl Drop for ScopedTimestampFormat {
+    fn drop(&mut self) {
+        SCOPED_TIMESTAMP_FORMAT.with(|scoped| scoped.set(self.0));
+    }
+}
+
+/// Restores the previous input timestamp format when dropped.
+struct ScopedInputTimestampFormat(Option<TimestampFormat>);
+
+impl Drop for ScopedInputTimestampFormat {
+    fn drop(&mut self) {
+        INPUT_TIMESTAMP_FORMAT.with(|input| input.set(self.0));
+    }
+}
+
+/// Run `f` (e.g. a `serde_json::to_string` call) with the timestamp format `format` on the current thread.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::{with_timestamp_format, FileTime, TimestampFormat};
+///
+/// let file_time = FileTime::new(132516443881399495);
+/// let json = with_timestamp_format(TimestampFormat::EpochMillis, || serde_json::to_string(&file_time));
+/// assert_eq!(json.unwrap(), "1607170788139");
+/// ```
+pub fn with_timestamp_format<T, F: FnOnce() -> T>(format: TimestampFormat, f: F) -> T {
+    let _previous =
+        ScopedTimestampFormat(SCOPED_TIMESTAMP_FORMAT.with(|scoped| scoped.replace(Some(format))));
+    f()
+}
+
+/// Run `f` (e.g. a `serde_json::from_str` call) reading the timestamps as serialized with the format `format` on the
+/// current thread. Only `TimestampFormat::EpochMillis` changes how the input is read, integers are read as epoch milliseconds
+/// instead of raw values.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::{with_input_timestamp_format, FileTime, TimestampFormat};
+///
+/// let file_time: FileTime = with_input_timestamp_format(TimestampFormat::EpochMillis, || serde_json::from_str("1607170788139")).unwrap();
+/// assert_eq!(file_time.to_u64(), 132516443881390000);
+/// let file_time: FileTime = serde_json::from_str("132516443881399495").unwrap();
+/// assert_eq!(file_time.to_u64(), 132516443881399495);
+/// ```
+pub fn with_input_timestamp_format<T, F: FnOnce() -> T>(format: TimestampFormat, f: F) -> T {
+    let _previous =
+        ScopedInputTimestampFormat(INPUT_TIMESTAMP_FORMAT.with(|input| input.replace(Some(format))));
+    f()
+}
+
+/// Serialize the wrapped value with the timestamp format `format`.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::{FileTime, TimestampFormat, WithTimestampFormat};
+///
+/// let file_times = vec![FileTime::new(132516443881399495)];
+/// assert_eq!(
+///     serde_json::to_string(&WithTimestampFormat::new(&file_times, TimestampFormat::Rfc3339Nanos)).unwrap(),
+///     r#"["2020-12-05T12:19:48.139949500Z"]"#
+/// );
+/// ```
+pub struct WithTimestampFormat<'a, T: ?Sized> {
+    value: &'a T,
+    format: TimestampFormat,
+}
+
+impl<'a, T: ?Sized> WithTimestampFormat<'a, T> {
+    pub fn new(value: &'a T, format: TimestampFormat) -> Self {
+        Self { value, format }
+    }
+}
+
+impl<T: Serialize + ?Sized> Serialize for WithTimestampFormat<'_, T> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        with_timestamp_format(self.format, || self.value.serialize(serializer))
+    }
+}
+
+/// Implemented by the time types to be serialized with `TimestampFormat`, `Display` is used for `TimestampFormat::Rfc3339`.
+pub(crate) trait SerializeTimestamp: WinTimestamp {
+    /// Returns the formatted timestamp with nanosecond precision, `None` if the value is not valid.
+    fn rfc3339_nanos(&self) -> Option<String> {
+        self.to_datetime()
+            .map(|date_time| date_time.format("%Y-%m-%dT%H:%M:%S%.9fZ").to_string())
+    }
+
+    /// Returns how the raw value is interpreted if it is stored in local time, `None` for UTC.
+    fn local_interpretation(&self) -> Option<&TimeInterpretation> {
+        None
+    }
+}
+
+/// Serialize `value` with the current timestamp format, values that are not valid are formatted with `Display`
+/// when the format needs a valid timestamp.
+pub(crate) fn serialize_timestamp<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: SerializeTimestamp + ?Sized,
+    S: Serializer,
+{
+    match timestamp_format() {
+        TimestampFormat::Rfc3339 => serializer.serialize_str(&value.to_string()),
+        TimestampFormat::Rfc3339Nanos => match value.rfc3339_nanos() {
+            Some(formatted) => serializer.serialize_str(&formatted),
+            None => serializer.serialize_str(&value.to_string()),
+        },
+        TimestampFormat::EpochMillis => match value.to_datetime() {
+            Some(date_time) => serializer.serialize_i64(date_time.timestamp_millis()),
+            None => serializer.serialize_str(&value.to_string()),
+        },
+        TimestampFormat::Raw => match value.local_interpretation() {
+            Some(interpretation) => {
+                let mut s = serializer.serialize_struct("Timestamp", 2)?;
+                s.serialize_field("raw", &value.raw_timestamp())?;
+                s.serialize_field("interpretation", interpretation)?;
+                s.end()
+            }
+            None => value.raw_timestamp().serialize(serializer),
+        },
+        TimestampFormat::RawAndFormatted => {
+            let interpretation = value.local_interpretation();
+            let mut s = serializer.serialize_struct("Timestamp", 2 + interpretation.is_some() as usize)?;
+            s.serialize_field("raw", &value.raw_timestamp())?;
+            if let Some(interpretation) = interpretation {
+                s.serialize_field("interpretation", interpretation)?;
+            }
+            s.serialize_field("formatted", &value.to_string())?;
+            s.end()
+        }
+    }
+}
+
+/// A serialized timestamp value, the raw value or the formatted timestamp.
+#[derive(Deserialize)]
+#[serde(untagged)]
+pub(crate) enum TimestampValue {
+    Unsigned(u64),
+    Signed(i64),
+    Float(f64),
+    String(String),
+    /// `RawTimestamp::ExFat`.
+    ExFat { timestamp: u32, increment_10ms: u8, utc_offset: u8 },
+    /// `RawTimestamp::Fat`.
+    Fat { date_time: u32, increment_10ms: u8 },
+}
+
+/// A timestamp serialized with any `TimestampFormat`.
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SerializedTimestamp {
+    Value(TimestampValue),
+    /// The raw value of `Raw` and `RawAndFormatted`, the formatted timestamp is ignored.
+    RawAndFormatted {
+        raw: TimestampValue,
+        #[serde(default)]
+        interpretation: Option<TimeInterpretation>,
+    },
+}
+
+/// The components of a formatted timestamp (e.g. `2020-06-06T19:15:46.5+03:00`), the values are not validated.
+#[derive(Debug)]
+pub(crate) struct TimestampParts {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+    pub nanosecond: u32,
+    /// The offset from UTC in minutes, `None` for `Z` and timestamps without an offset.
+    pub offset: Option<i32>,
+    /// `true` if the timestamp has no `Z` or offset (a local time with an unknown offset).
+    pub unknown_local: bool,
+}
+
+impl TimestampParts {
+    /// Parse a timestamp formatted by the time types.
+    pub fn parse(timestamp: &str) -> Option<Self> {
+        let (sign, rest) = match timestamp.as_bytes().first()? {
+            b'+' => (1, &timestamp[1..]),
+            b'-' => (-1, &timestamp[1..]),
+            _ => (1, timestamp),
+        };
+        let (date, time) = rest.split_once(['T', ' '])?;
+        let mut date = date.splitn(3, '-');
+        let year = sign * date.next()?.parse::<i32>().ok()?;
+        let month = date.next()?.parse().ok()?;
+        let day = date.next()?.parse().ok()?;
+
+        let (time, offset, unknown_local) = match (time.strip_suffix('Z'), time.rfind(['+', '-'])) {
+            (Some(time), _) => (time, None, false),
+            (None, Some(index)) => {
+                let (hours, minutes) = time[index + 1..].split_once(':')?;
+                let offset = hours.parse::<i32>().ok()? * 60 + minutes.parse::<i32>().ok()?;
+                match &time[index..index + 1] {
+                    "-" => (&time[..index], Some(-offset), false),
+                    _ => (&time[..index], Some(offset), false),
+                }
+            }
+            (None, None) => (time, None, true),
+        };
+        let (time, fraction) = match time.split_once('.') {
+            Some((time, fraction)) => (time, fraction),
+            None => (time, ""),
+        };
+        if fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let nanosecond = format!("{:0<9}", fraction).parse().ok()?;
+        let mut time = time.splitn(3, ':');
+        Some(Self {
+            year,
+            month,
+            day,
+            hour: time.next()?.parse().ok()?,
+            minute: time.next()?.parse().ok()?,
+            second: time.next()?.parse().ok()?,
+            nanosecond,
+            offset,
+            unknown_local,
+        })
+    }
+
+    /// Returns the timestamp as UTC, `None` if the components are not valid. Timestamps without an offset are read as UTC.
+    pub fn to_datetime(&self) -> Option<DateTime<Utc>> {
+        let date_time = NaiveDate::from_ymd_opt(self.year, self.month, self.day)?.and_hms_nano_opt(
+            self.hour,
+            self.minute,
+            self.second,
+            self.nanosecond,
+        )? - chrono::Duration::minutes(self.offset.unwrap_or(0) as i64);
+        Some(DateTime::from_naive_utc_and_offset(date_time, Utc))
+    }
+}
+
+/// Implemented by the time types to be deserialized from any `TimestampFormat`.
+pub(crate) trait DeserializeTimestamp: Sized {
+    /// Create the value from the raw value (e.g. the `u64` of a `FileTime`).
+    fn from_raw(raw: TimestampValue) -> Option<Self>;
+
+    /// Create the value from the formatted timestamp.
+    fn from_formatted(formatted: &str) -> Option<Self>;
+
+    /// Create the value from a timestamp in UTC.
+    fn from_utc(date_time: DateTime<Utc>) -> Option<Self>;
+
+    /// Set the interpretation of a raw value stored in local time, `None` if the type is never stored in local time.
+    fn with_local_interpretation(self, _interpretation: TimeInterpretation) -> Option<Self> {
+        None
+    }
+}
+
+/// Deserialize a time type serialized with any `TimestampFormat`.
+pub(crate) fn deserialize_timestamp<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: DeserializeTimestamp,
+    D: Deserializer<'de>,
+{
+    let epoch_millis = INPUT_TIMESTAMP_FORMAT.with(|input| input.get()) == Some(TimestampFormat::EpochMillis);
+    let value = match SerializedTimestamp::deserialize(deserializer)? {
+        SerializedTimestamp::RawAndFormatted {
+            raw,
+            interpretation: Some(interpretation),
+        } => T::from_raw(raw).and_then(|value| value.with_local_interpretation(interpretation)),
+        SerializedTimestamp::RawAndFormatted { raw, .. } => T::from_raw(raw),
+        SerializedTimestamp::Value(TimestampValue::String(formatted)) => {
+            T::from_formatted(&formatted)
+        }
+        SerializedTimestamp::Value(TimestampValue::Signed(millis)) if epoch_millis => {
+            DateTime::from_timestamp_millis(millis).and_then(T::from_utc)
+        }
+        SerializedTimestamp::Value(TimestampValue::Unsigned(millis)) if epoch_millis => {
+            DateTime::from_timestamp_millis(millis as i64).and_then(T::from_utc)
+        }
+        SerializedTimestamp::Value(raw) => T::from_raw(raw),
+    };
+    value.ok_or_else(|| D::Error::custom("invalid timestamp"))
+}
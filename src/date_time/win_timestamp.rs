@@ -0,0 +1,121 @@
+use crate::utils::bytes_to_hex;
+use chrono::{DateTime, Duration, Utc};
+use serde::ser::SerializeStruct;
+use serde::{Serialize, Serializer};
+use std::cmp::Ordering;
+use std::fmt::Display;
+
+/// The raw value of a time type as it is stored.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RawTimestamp {
+    U32(u32),
+    U64(u64),
+    F64(f64),
+    /// Structs (e.g. `SystemTime`) are serialized as a hex string.
+    Bytes(Vec<u8>),
+    /// A `DosDateTime` with the FAT "create time fine" byte, the packed `u32` (the date in the low word) and the 10 ms
+    /// increment. Serialized as an object (e.g. `{"date_time":2583122118,"increment_10ms":100}`).
+    Fat { date_time: u32, increment_10ms: u8 },
+    /// The fields of exFAT directory entries as they are stored, the timestamp (the time in the low word), the 10 ms
+    /// increment and the UTC offset. Serialized as an object (e.g. `{"timestamp":1355192823,"increment_10ms":150,"utc_offset":140}`).
+    ExFat { timestamp: u32, increment_10ms: u8, utc_offset: u8 },
+}
+
+impl Serialize for RawTimestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RawTimestamp::U32(raw) => serializer.serialize_u32(*raw),
+            RawTimestamp::U64(raw) => serializer.serialize_u64(*raw),
+            RawTimestamp::F64(raw) => serializer.serialize_f64(*raw),
+            RawTimestamp::Bytes(raw) => serializer.serialize_str(&bytes_to_hex(raw)),
+            RawTimestamp::Fat { date_time, increment_10ms } => {
+                let mut s = serializer.serialize_struct("Fat", 2)?;
+                s.serialize_field("date_time", date_time)?;
+                s.serialize_field("increment_10ms", increment_10ms)?;
+                s.end()
+            }
+            RawTimestamp::ExFat { timestamp, increment_10ms, utc_offset } => {
+                let mut s = serializer.serialize_struct("ExFat", 3)?;
+                s.serialize_field("timestamp", timestamp)?;
+                s.serialize_field("increment_10ms", increment_10ms)?;
+                s.serialize_field("utc_offset", utc_offset)?;
+                s.end()
+            }
+        }
+    }
+}
+
+/// The smallest time difference a time type can store.
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimestampPrecision {
+    /// `FileTime` and `DotNetTicks`.
+    HundredNanoseconds,
+    /// `SystemTime` and `OleDate`.
+    Milliseconds,
+    /// `DosDateTime` with the 10 ms increment of FAT and exFAT directory entries.
+    TenMilliseconds,
+    /// `DosDateTime` without the 10 ms increment.
+    TwoSeconds,
+}
+
+impl TimestampPrecision {
+    pub fn to_duration(&self) -> Duration {
+        match self {
+            TimestampPrecision::HundredNanoseconds => Duration::nanoseconds(100),
+            TimestampPrecision::Milliseconds => Duration::milliseconds(1),
+            TimestampPrecision::TenMilliseconds => Duration::milliseconds(10),
+            TimestampPrecision::TwoSeconds => Duration::seconds(2),
+        }
+    }
+}
+
+/// Implemented by every time type (`FileTime`, `DosDateTime`, `SystemTime`, `OleDate` and `DotNetTicks`).
+/// Trait objects are compared by their UTC timestamp, so timestamps from different sources can be sorted together.
+/// Invalid timestamps are equal to each other and sort before the valid ones.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::date_time::{DosDateTime, FileTime, OleDate, WinTimestamp};
+///
+/// let mut timeline: Vec<Box<dyn WinTimestamp>> = vec![
+///     Box::new(FileTime::new(132516443881399495)),
+///     Box::new(DosDateTime::new(2020, 6, 6, 19, 15, 46)),
+///     Box::new(OleDate::new(44260.5)),
+/// ];
+/// timeline.sort();
+/// assert_eq!(timeline[0].to_string(), "2020-06-06T19:15:46Z");
+/// assert_eq!(timeline[2].to_string(), "2021-03-05T12:00:00Z");
+/// ```
+pub trait WinTimestamp: Display {
+    /// Returns the timestamp as UTC, `None` if the value is not valid.
+    fn to_datetime(&self) -> Option<DateTime<Utc>>;
+
+    /// Returns the raw value as it is stored.
+    fn raw_timestamp(&self) -> RawTimestamp;
+
+    fn precision(&self) -> TimestampPrecision;
+}
+
+impl PartialEq for dyn WinTimestamp + '_ {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_datetime() == other.to_datetime()
+    }
+}
+
+impl Eq for dyn WinTimestamp + '_ {}
+
+impl PartialOrd for dyn WinTimestamp + '_ {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for dyn WinTimestamp + '_ {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_datetime().cmp(&other.to_datetime())
+    }
+}
@@ -1,10 +1,10 @@
 use std::io::{Result, Cursor, Read};
 use std::fmt::{Formatter, Display, Result as FmtResult};
 use byteorder::{ReadBytesExt, LittleEndian};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// `FileReference` struct parser.`FileReference` is a struct that contains the file `mft_entry` and `sequence_number`.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FileReference {
     mft_entry: u64,
     sequence_number: u16
@@ -35,7 +35,7 @@ impl FileReference{
 }
 
 /// [File system attributes flags](https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-fscc/ca28ec38-f155-4768-81d6-4bfeb8586fc9) parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum FileAttributesFlags{
     READONLY,
     HIDDEN,
@@ -0,0 +1,115 @@
+//! Jump list files are named after the AppID of the application (e.g. `1b4dd67f29cb1962.automaticDestinations-ms`).
+//! The AppID is the CRC64 of the AppUserModelID of the application, or of the executable path if the application does not set one.
+
+/// Known AppIDs and their application names, sorted by AppID.
+const KNOWN_APP_IDS: &[(&str, &str)] = &[
+    ("007579c6d1089a8e", "Remote Desktop Connection"),
+    ("012dc1ea8e34b5a6", "Paint"),
+    ("0a61657a5e5dfbdc", "Notepad"),
+    ("0faef7def55a1d4b", "VLC media player (64-bit)"),
+    ("116351484fbe207a", "Google Chrome"),
+    ("16f2f0042ddbe0e8", "Windows Terminal"),
+    ("1b4dd67f29cb1962", "Windows Explorer (Pinned and Recent)"),
+    ("1bc392b8e104a00e", "Remote Desktop Connection"),
+    ("28c8b86deab549a1", "Internet Explorer"),
+    ("3353b940c074fd0c", "Snipping Tool"),
+    ("469e4a7982cea4d4", "WordPad"),
+    ("4f1b0725a5cedd54", "Windows Media Player (64-bit)"),
+    ("590aee7bdd69b59b", "Windows PowerShell"),
+    ("5bb830f67194431a", "7-Zip File Manager"),
+    ("5c05855efcd9a868", "Windows Media Player (32-bit)"),
+    ("5c450709f7ae4396", "Mozilla Firefox (32-bit)"),
+    ("5d696d521de238c3", "Google Chrome"),
+    ("6728dd69a3088f97", "Windows Command Processor"),
+    ("6758a3a018631c8d", "Mozilla Firefox (64-bit)"),
+    ("6bb98fb8cdc26d69", "Calculator"),
+    ("6d2bac8f1edf6668", "Microsoft Outlook 2013 and later"),
+    ("766c6474ef2adc83", "Registry Editor"),
+    ("7e4dca80246863e3", "Control Panel"),
+    ("918e0ecb43d17e23", "Notepad (32-bit)"),
+    ("9b9cdc69c1c24e2b", "Notepad (64-bit)"),
+    ("9d1f905ce5044aee", "Microsoft Edge (Legacy)"),
+    ("9d78513a8998829c", "Run Dialog"),
+    ("9fda41b86ddcf1db", "VLC media player (32-bit)"),
+    ("a52b0784bd667468", "Microsoft Photos"),
+    ("a7bd71699cd38d1c", "Microsoft Word 2010 (32-bit)"),
+    ("b8ab77100df80ab2", "Microsoft Excel 2013 and later"),
+    ("b98a17056fbc5cdc", "Google Chrome"),
+    ("baacb5294867b833", "Notepad++ (64-bit)"),
+    ("ccba5a5986c77e43", "Microsoft Edge"),
+    ("d00655d2aa12ff6d", "Microsoft PowerPoint 2013 and later"),
+    ("e70d383b15687e37", "Notepad++ (32-bit)"),
+    ("f01b4d95cf55d32a", "Windows Explorer"),
+    ("fb3b0dbfee58fac8", "Microsoft Word 2013 and later"),
+];
+
+/// The [KNOWNFOLDERID](https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid) that replaces the default folder locations
+/// (without the drive letter) in executable paths before computing the AppID. The folders are ordered from the most specific.
+const APP_ID_KNOWN_FOLDERS: &[(&str, &str)] = &[
+    (
+        "\\WINDOWS\\SYSTEM32",
+        "{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}",
+    ),
+    (
+        "\\WINDOWS\\SYSWOW64",
+        "{D65231B0-B2F1-4857-A4CE-A8E7C6EA7D27}",
+    ),
+    ("\\WINDOWS", "{F38BF404-1D43-42F2-9305-67DE0B28FC23}"),
+    (
+        "\\PROGRAM FILES (X86)",
+        "{7C5A40EF-A0FB-4BFC-874A-C0F2E0B9FA8E}",
+    ),
+    ("\\PROGRAM FILES", "{6D809377-6AF0-444B-8957-A3773F02200E}"),
+];
+
+/// The reflected polynomial of the CRC64 used to compute AppIDs.
+const APP_ID_CRC64_POLYNOMIAL: u64 = 0x92C64265D32139A4;
+
+/// Returns the name of the application with the AppID `app_id` (e.g. `1b4dd67f29cb1962`), the AppID is not case sensitive.
+pub fn app_id_name(app_id: &str) -> Option<&'static str> {
+    let app_id = app_id.to_lowercase();
+    KNOWN_APP_IDS
+        .binary_search_by(|(known_app_id, _)| known_app_id.cmp(&app_id.as_str()))
+        .ok()
+        .map(|index| KNOWN_APP_IDS[index].1)
+}
+
+/// Compute the AppID of an AppUserModelID (e.g. `Microsoft.Windows.Explorer`) or of a path that starts with a
+/// KNOWNFOLDERID (e.g. `{1AC14E77-02E7-4E5D-B744-2EB1AE5198B7}\notepad.exe`). The CRC64 is calculated over the
+/// upper case UTF-16LE string.
+pub fn app_id(app_user_model_id: &str) -> String {
+    let mut crc = u64::MAX;
+    for byte in app_user_model_id
+        .to_uppercase()
+        .encode_utf16()
+        .flat_map(|c| c.to_le_bytes())
+    {
+        crc ^= byte as u64;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ APP_ID_CRC64_POLYNOMIAL,
+                _ => crc >> 1,
+            };
+        }
+    }
+    format!("{:016x}", crc)
+}
+
+/// Compute the AppID of an executable path (e.g. `C:\Windows\System32\notepad.exe`). The default locations of the
+/// Windows, System and Program Files folders are replaced with their KNOWNFOLDERID before calculating the CRC64, see `app_id`.
+/// Paths on a 64-bit Windows are assumed, `Program Files` is `ProgramFilesX64`.
+pub fn app_id_from_path(path: &str) -> String {
+    let upper_path = path.to_uppercase();
+    let path_without_drive = match upper_path.get(1..2) {
+        Some(":") => &upper_path[2..],
+        _ => &upper_path[..],
+    };
+    for (folder, known_folder_id) in APP_ID_KNOWN_FOLDERS {
+        if let Some(rest) = path_without_drive.strip_prefix(folder) {
+            if rest.is_empty() || rest.starts_with('\\') {
+                return app_id(&format!("{}{}", known_folder_id, rest));
+            }
+        }
+    }
+    app_id(path)
+}
@@ -0,0 +1,78 @@
+use super::{app_id_name, DestList, DestListEntry};
+use crate::cfb::CompoundFile;
+use crate::structs::shell_link::ShellLink;
+use crate::ReaderError;
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufReader, Read, Seek};
+
+/// A DestList entry with the LNK stream it points to.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomaticDestinationsEntry {
+    #[serde(flatten)]
+    pub dest_list_entry: DestListEntry,
+    /// `None` if the LNK stream does not exist or could not be parsed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<ShellLink>,
+}
+
+/// [AutomaticDestinations](https://github.com/libyal/dtformats/blob/main/documentation/Jump%20lists%20format.asciidoc)
+/// jump list (`*.automaticDestinations-ms`) parser. The file is a compound file with a `DestList` stream and a LNK stream for every entry.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AutomaticDestinations {
+    /// The AppID from the file name (e.g. `1b4dd67f29cb1962`), set by `AutomaticDestinations::from_path`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    pub version: u32,
+    pub last_entry_id: u32,
+    pub revision: u32,
+    pub entries: Vec<AutomaticDestinationsEntry>,
+}
+
+impl AutomaticDestinations {
+    pub fn from_path(path: &str) -> Result<Self, ReaderError> {
+        let mut automatic_destinations = Self::from_reader(&mut BufReader::new(File::open(path)?))?;
+        let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        automatic_destinations.app_id = file_name
+            .split('.')
+            .next()
+            .filter(|app_id| app_id.len() == 16)
+            .map(|app_id| app_id.to_lowercase());
+        Ok(automatic_destinations)
+    }
+
+    /// Returns the name of the application from the AppID, see `app_id_name`.
+    pub fn application_name(&self) -> Option<&'static str> {
+        self.app_id.as_deref().and_then(app_id_name)
+    }
+
+    pub fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self, ReaderError> {
+        Self::from_compound_file(&mut CompoundFile::from_reader(r)?)
+    }
+
+    pub fn from_compound_file<R: Read + Seek>(
+        compound_file: &mut CompoundFile<R>,
+    ) -> Result<Self, ReaderError> {
+        let dest_list = DestList::from_reader(&mut compound_file.open_stream("DestList")?)?;
+
+        let mut entries = vec![];
+        for dest_list_entry in dest_list.entries {
+            let link = compound_file
+                .open_stream(&dest_list_entry.stream_name())
+                .and_then(|mut stream| ShellLink::from_reader(&mut stream))
+                .ok();
+            entries.push(AutomaticDestinationsEntry {
+                dest_list_entry,
+                link,
+            });
+        }
+
+        Ok(Self {
+            app_id: None,
+            version: dest_list.version,
+            last_entry_id: dest_list.last_entry_id,
+            revision: dest_list.revision,
+            entries,
+        })
+    }
+}
@@ -0,0 +1,254 @@
+use super::app_id_name;
+use crate::structs::shell_link::ShellLink;
+use crate::utils::read_utf16_string;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{Cursor, Error, ErrorKind, Read};
+
+/// The CLSID of a shell link (`00021401-0000-0000-C000-000000000046`) followed by the LNK header size,
+/// every entry starts with the CLSID followed by the LNK data.
+const ENTRY_SIGNATURE: [u8; 20] = [
+    0x01, 0x14, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC0, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x46,
+    0x4C, 0x00, 0x00, 0x00,
+];
+
+/// Find the offset of the next entry or category footer starting from `offset`.
+fn find_next(buf: &[u8], offset: usize) -> Option<usize> {
+    let footer = CustomDestinations::FOOTER_SIGNATURE.to_le_bytes();
+    (offset..buf.len())
+        .find(|&i| buf[i..].starts_with(&ENTRY_SIGNATURE) || buf[i..].starts_with(&footer))
+}
+
+/// The type of a CustomDestinations category.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub enum CategoryType {
+    /// A category with a name defined by the application.
+    Custom,
+    /// A category defined by Windows (frequent or recent).
+    Known,
+    /// The application tasks.
+    Tasks,
+    UNKNOWN(u32),
+}
+
+impl From<u32> for CategoryType {
+    fn from(num: u32) -> Self {
+        match num {
+            0 => CategoryType::Custom,
+            1 => CategoryType::Known,
+            2 => CategoryType::Tasks,
+            other => CategoryType::UNKNOWN(other),
+        }
+    }
+}
+
+/// The Windows defined categories.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub enum KnownCategory {
+    Frequent,
+    Recent,
+    UNKNOWN(u32),
+}
+
+impl From<u32> for KnownCategory {
+    fn from(num: u32) -> Self {
+        match num {
+            1 => KnownCategory::Frequent,
+            2 => KnownCategory::Recent,
+            other => KnownCategory::UNKNOWN(other),
+        }
+    }
+}
+
+/// A LNK entry of a CustomDestinations category.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomDestinationsEntry {
+    /// The offset of the entry from the start of the file.
+    pub offset: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link: Option<ShellLink>,
+    /// The error returned while parsing a corrupt entry.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// A CustomDestinations category.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomDestinationsCategory {
+    pub category_type: CategoryType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub known_category: Option<KnownCategory>,
+    pub entries: Vec<CustomDestinationsEntry>,
+}
+
+/// [CustomDestinations](https://github.com/libyal/dtformats/blob/main/documentation/Jump%20lists%20format.asciidoc)
+/// jump list (`*.customDestinations-ms`) parser. The file contains categories of LNK entries, each category ends with the
+/// `0xBABFFBAB` footer. Corrupt entries are skipped by searching for the next entry or footer.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CustomDestinations {
+    /// The AppID from the file name (e.g. `1b4dd67f29cb1962`), set by `CustomDestinations::from_path`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub app_id: Option<String>,
+    pub version: u32,
+    pub categories: Vec<CustomDestinationsCategory>,
+    /// The error returned if the parsing stopped before the last category.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl CustomDestinations {
+    pub const FOOTER_SIGNATURE: u32 = 0xBABFFBAB;
+
+    pub fn from_path(path: &str) -> Result<Self, ReaderError> {
+        let mut custom_destinations = Self::from_reader(&mut File::open(path)?)?;
+        let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+        custom_destinations.app_id = file_name
+            .split('.')
+            .next()
+            .filter(|app_id| app_id.len() == 16)
+            .map(|app_id| app_id.to_lowercase());
+        Ok(custom_destinations)
+    }
+
+    /// Returns the name of the application from the AppID, see `app_id_name`.
+    pub fn application_name(&self) -> Option<&'static str> {
+        self.app_id.as_deref().and_then(app_id_name)
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        let mut r = Cursor::new(buf);
+        let version = r.read_u32::<LittleEndian>()?;
+        let number_of_categories = r.read_u32::<LittleEndian>()?;
+        r.read_u32::<LittleEndian>()?; // unknown
+
+        let mut categories = vec![];
+        let mut error = None;
+        for _ in 0..number_of_categories {
+            match Self::read_category(&mut r) {
+                Ok(category) => categories.push(category),
+                Err(e) => {
+                    error = Some(e.to_string());
+                    break;
+                }
+            }
+        }
+
+        Ok(Self {
+            app_id: None,
+            version,
+            categories,
+            error,
+        })
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let mut buf = vec![];
+        r.read_to_end(&mut buf)?;
+        Self::from_buffer(&buf)
+    }
+
+    fn read_category(r: &mut Cursor<&[u8]>) -> Result<CustomDestinationsCategory, ReaderError> {
+        let category_type = CategoryType::from(r.read_u32::<LittleEndian>()?);
+        let mut name = None;
+        let mut known_category = None;
+        let number_of_entries = match category_type {
+            CategoryType::Custom => {
+                let name_size = r.read_u16::<LittleEndian>()?;
+                name = Some(read_utf16_string(r, Some(name_size as usize))?);
+                r.read_u32::<LittleEndian>()?
+            }
+            CategoryType::Known => {
+                known_category = Some(KnownCategory::from(r.read_u32::<LittleEndian>()?));
+                0
+            }
+            CategoryType::Tasks => r.read_u32::<LittleEndian>()?,
+            CategoryType::UNKNOWN(category_type) => {
+                return Err(ReaderError::from(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "CustomDestinations : Unknown category type '{}' at offset '{}'",
+                        category_type,
+                        r.position() - 4
+                    ),
+                )))
+            }
+        };
+
+        let mut entries = vec![];
+        while entries.len() < number_of_entries as usize {
+            let offset = r.position();
+            // The number of entries can be larger than the entries in the file
+            if offset as usize >= r.get_ref().len() {
+                break;
+            }
+            if r.get_ref()[offset as usize..].starts_with(&Self::FOOTER_SIGNATURE.to_le_bytes()) {
+                break;
+            }
+            let result = match r.get_ref()[offset as usize..].starts_with(&ENTRY_SIGNATURE) {
+                true => {
+                    r.set_position(offset + 16);
+                    ShellLink::from_reader(r)
+                }
+                false => Err(ReaderError::from(Error::new(
+                    ErrorKind::InvalidData,
+                    format!(
+                        "CustomDestinations : Invalid entry signature at offset '{}'",
+                        offset
+                    ),
+                ))),
+            };
+            match result {
+                Ok(mut link) => {
+                    // The data after the terminal block belongs to the next entries
+                    let end = r.position() - link.extra_data.trailing_data.len() as u64;
+                    link.extra_data.trailing_data.clear();
+                    r.set_position(end);
+                    entries.push(CustomDestinationsEntry {
+                        offset,
+                        link: Some(link),
+                        error: None,
+                    });
+                }
+                Err(e) => {
+                    entries.push(CustomDestinationsEntry {
+                        offset,
+                        link: None,
+                        error: Some(e.to_string()),
+                    });
+                    match find_next(r.get_ref(), offset as usize + 1) {
+                        Some(next) => r.set_position(next as u64),
+                        None => {
+                            r.set_position(r.get_ref().len() as u64);
+                            break;
+                        }
+                    }
+                }
+            }
+        }
+
+        let footer_offset = r.position();
+        match r.read_u32::<LittleEndian>() {
+            Ok(Self::FOOTER_SIGNATURE) => {}
+            _ => {
+                // The category does not end with a footer, continue from the next entry or footer
+                let next =
+                    find_next(r.get_ref(), footer_offset as usize).unwrap_or(r.get_ref().len());
+                r.set_position(next as u64);
+                if r.get_ref()[next..].starts_with(&Self::FOOTER_SIGNATURE.to_le_bytes()) {
+                    r.set_position(next as u64 + 4);
+                }
+            }
+        }
+
+        Ok(CustomDestinationsCategory {
+            category_type,
+            name,
+            known_category,
+            entries,
+        })
+    }
+}
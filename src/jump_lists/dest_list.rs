@@ -0,0 +1,143 @@
+use crate::date_time::FileTime;
+use crate::structs::extra_data::{read_fixed_cp1252_string, uuid_mac_address, uuid_time};
+use crate::structs::Guid;
+use crate::utils::read_utf16_string;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Error, ErrorKind, Read};
+
+/// [DestList entry](https://github.com/libyal/dtformats/blob/main/documentation/Jump%20lists%20format.asciidoc) struct parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DestListEntry {
+    pub checksum: u64,
+    pub droid_volume_id: Guid,
+    pub droid_file_id: Guid,
+    pub birth_droid_volume_id: Guid,
+    pub birth_droid_file_id: Guid,
+    /// The MAC address stored in the file droid.
+    pub mac_address: String,
+    /// The timestamp stored in the file droid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub droid_file_time: Option<FileTime>,
+    pub netbios_name: String,
+    /// The entry ID is the name of the LNK stream in hexadecimal.
+    pub entry_id: u32,
+    pub last_access: FileTime,
+    pub pinned: bool,
+    /// The position of the entry in the pinned list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pin_order: Option<u32>,
+    pub access_count: u32,
+    pub path: String,
+}
+
+impl DestListEntry {
+    pub fn from_buffer(buf: &[u8], version: u32) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf), version)
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R, version: u32) -> Result<Self, ReaderError> {
+        let checksum = r.read_u64::<LittleEndian>()?;
+        let droid_volume_id = Guid::from_reader(r)?;
+        let droid_file_id = Guid::from_reader(r)?;
+        let birth_droid_volume_id = Guid::from_reader(r)?;
+        let birth_droid_file_id = Guid::from_reader(r)?;
+        let netbios_name = read_fixed_cp1252_string(r, 16)?;
+        let entry_id = r.read_u32::<LittleEndian>()?;
+        // Windows 7 and 8 store the access count as a float
+        let access_count_v1 = r.read_f32::<LittleEndian>()?;
+        let last_access = FileTime::new(r.read_u64::<LittleEndian>()?);
+        let pin_status = r.read_i32::<LittleEndian>()?;
+        let access_count = match version {
+            1 => access_count_v1 as u32,
+            _ => {
+                r.read_u32::<LittleEndian>()?; // unknown
+                let access_count = r.read_u32::<LittleEndian>()?;
+                r.read_u64::<LittleEndian>()?; // unknown
+                access_count
+            }
+        };
+        let path_size = r.read_u16::<LittleEndian>()?;
+        let path = read_utf16_string(r, Some(path_size as usize))?;
+        if version > 1 {
+            r.read_u32::<LittleEndian>()?; // unknown
+        }
+
+        Ok(Self {
+            checksum,
+            mac_address: uuid_mac_address(&droid_file_id),
+            droid_file_time: uuid_time(&droid_file_id),
+            droid_volume_id,
+            droid_file_id,
+            birth_droid_volume_id,
+            birth_droid_file_id,
+            netbios_name,
+            entry_id,
+            last_access,
+            pinned: pin_status >= 0,
+            pin_order: match pin_status {
+                pin_order if pin_order >= 0 => Some(pin_order as u32),
+                _ => None,
+            },
+            access_count,
+            path,
+        })
+    }
+
+    /// The name of the LNK stream of this entry in the AutomaticDestinations file.
+    pub fn stream_name(&self) -> String {
+        format!("{:x}", self.entry_id)
+    }
+}
+
+/// [DestList](https://github.com/libyal/dtformats/blob/main/documentation/Jump%20lists%20format.asciidoc) stream parser.
+/// Version 1 is used by Windows 7 and 8, versions 3 and 4 are used by Windows 10 and 11. Other versions are rejected.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DestList {
+    pub version: u32,
+    pub number_of_entries: u32,
+    pub number_of_pinned_entries: u32,
+    pub last_entry_id: u32,
+    pub revision: u32,
+    pub entries: Vec<DestListEntry>,
+}
+
+impl DestList {
+    pub const HEADER_SIZE: usize = 0x20;
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let version = r.read_u32::<LittleEndian>()?;
+        if ![1, 3, 4].contains(&version) {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!("DestList : Unsupported version '{}'", version),
+            )));
+        }
+        let number_of_entries = r.read_u32::<LittleEndian>()?;
+        let number_of_pinned_entries = r.read_u32::<LittleEndian>()?;
+        r.read_f32::<LittleEndian>()?; // unknown
+        let last_entry_id = r.read_u32::<LittleEndian>()?;
+        r.read_u32::<LittleEndian>()?; // unknown
+        let revision = r.read_u32::<LittleEndian>()?;
+        r.read_u32::<LittleEndian>()?; // unknown
+
+        let mut entries = vec![];
+        for _ in 0..number_of_entries {
+            entries.push(DestListEntry::from_reader(r, version)?);
+        }
+
+        Ok(Self {
+            version,
+            number_of_entries,
+            number_of_pinned_entries,
+            last_entry_id,
+            revision,
+            entries,
+        })
+    }
+}
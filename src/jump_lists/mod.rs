@@ -0,0 +1,14 @@
+//! This module contains the [Jump Lists](https://github.com/libyal/dtformats/blob/main/documentation/Jump%20lists%20format.asciidoc) parsers.
+#![cfg(feature = "jump_lists")]
+mod app_ids;
+mod automatic_destinations;
+mod custom_destinations;
+mod dest_list;
+
+pub use app_ids::{app_id, app_id_from_path, app_id_name};
+pub use automatic_destinations::{AutomaticDestinations, AutomaticDestinationsEntry};
+pub use custom_destinations::{
+    CategoryType, CustomDestinations, CustomDestinationsCategory, CustomDestinationsEntry,
+    KnownCategory,
+};
+pub use dest_list::{DestList, DestListEntry};
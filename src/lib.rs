@@ -1,8 +1,12 @@
 //! collection of structs and utilities for parsing windows binary formats.
+#[cfg(feature = "cfb")]
+pub mod cfb;
 #[cfg(feature = "date_time")]
 pub mod date_time;
 #[cfg(feature = "file_system")]
 pub mod file_system;
+#[cfg(feature = "jump_lists")]
+pub mod jump_lists;
 #[cfg(feature = "structs")]
 pub mod structs;
 pub mod traits;
@@ -0,0 +1,245 @@
+use super::read_block_header;
+use crate::utils::read_utf16_string;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Cursor, Read};
+
+/// [Console fill attributes flags](https://learn.microsoft.com/en-us/windows/console/console-screen-buffers#character-attributes) parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FillAttributesFlags {
+    FOREGROUND_BLUE,
+    FOREGROUND_GREEN,
+    FOREGROUND_RED,
+    FOREGROUND_INTENSITY,
+    BACKGROUND_BLUE,
+    BACKGROUND_GREEN,
+    BACKGROUND_RED,
+    BACKGROUND_INTENSITY,
+}
+
+impl FillAttributesFlags {
+    pub fn from_u16(flags: u16) -> Vec<FillAttributesFlags> {
+        let mut res = vec![];
+        if flags & 0x0001 != 0 { res.push(FillAttributesFlags::FOREGROUND_BLUE); }
+        if flags & 0x0002 != 0 { res.push(FillAttributesFlags::FOREGROUND_GREEN); }
+        if flags & 0x0004 != 0 { res.push(FillAttributesFlags::FOREGROUND_RED); }
+        if flags & 0x0008 != 0 { res.push(FillAttributesFlags::FOREGROUND_INTENSITY); }
+        if flags & 0x0010 != 0 { res.push(FillAttributesFlags::BACKGROUND_BLUE); }
+        if flags & 0x0020 != 0 { res.push(FillAttributesFlags::BACKGROUND_GREEN); }
+        if flags & 0x0040 != 0 { res.push(FillAttributesFlags::BACKGROUND_RED); }
+        if flags & 0x0080 != 0 { res.push(FillAttributesFlags::BACKGROUND_INTENSITY); }
+        res
+    }
+}
+
+impl Display for FillAttributesFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The font family stored in the high nibble of the low byte of `FontFamily`.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FontFamily {
+    FF_DONTCARE,
+    FF_ROMAN,
+    FF_SWISS,
+    FF_MODERN,
+    FF_SCRIPT,
+    FF_DECORATIVE,
+    UNKNOWN(u32),
+}
+
+impl From<u32> for FontFamily {
+    fn from(num: u32) -> Self {
+        match num & 0x00F0 {
+            0x0000 => FontFamily::FF_DONTCARE,
+            0x0010 => FontFamily::FF_ROMAN,
+            0x0020 => FontFamily::FF_SWISS,
+            0x0030 => FontFamily::FF_MODERN,
+            0x0040 => FontFamily::FF_SCRIPT,
+            0x0050 => FontFamily::FF_DECORATIVE,
+            other => FontFamily::UNKNOWN(other),
+        }
+    }
+}
+
+/// The font pitch flags stored in the low nibble of `FontFamily`.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum FontPitchFlags {
+    TMPF_FIXED_PITCH,
+    TMPF_VECTOR,
+    TMPF_TRUETYPE,
+    TMPF_DEVICE,
+}
+
+impl FontPitchFlags {
+    pub fn from_u32(flags: u32) -> Vec<FontPitchFlags> {
+        let mut res = vec![];
+        if flags & 0x0001 != 0 { res.push(FontPitchFlags::TMPF_FIXED_PITCH); }
+        if flags & 0x0002 != 0 { res.push(FontPitchFlags::TMPF_VECTOR); }
+        if flags & 0x0004 != 0 { res.push(FontPitchFlags::TMPF_TRUETYPE); }
+        if flags & 0x0008 != 0 { res.push(FontPitchFlags::TMPF_DEVICE); }
+        res
+    }
+}
+
+impl Display for FontPitchFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// [ConsoleDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the console window settings used when the link target is a console application.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsoleDataBlock {
+    #[serde(skip, default = "ConsoleDataBlock::default_size")]
+    pub size: u32,
+    #[serde(skip, default = "ConsoleDataBlock::default_signature")]
+    pub signature: u32,
+    pub fill_attributes: Vec<FillAttributesFlags>,
+    pub popup_fill_attributes: Vec<FillAttributesFlags>,
+    pub screen_buffer_size_x: i16,
+    pub screen_buffer_size_y: i16,
+    pub window_size_x: i16,
+    pub window_size_y: i16,
+    pub window_origin_x: i16,
+    pub window_origin_y: i16,
+    /// For raster fonts the width is in the low word and the height in the high word,
+    /// for vector fonts the low word is zero and the high word is the height.
+    pub font_size: u32,
+    pub font_family: FontFamily,
+    pub font_pitch: Vec<FontPitchFlags>,
+    pub font_weight: u32,
+    pub face_name: String,
+    pub cursor_size: u32,
+    pub full_screen: bool,
+    pub quick_edit: bool,
+    pub insert_mode: bool,
+    pub auto_position: bool,
+    pub history_buffer_size: u32,
+    pub number_of_history_buffers: u32,
+    pub history_no_dup: bool,
+    /// The 16 [COLORREF](https://learn.microsoft.com/en-us/windows/win32/gdi/colorref) values (`0x00BBGGRR`) of the console color table.
+    pub color_table: Vec<u32>,
+}
+
+impl ConsoleDataBlock {
+    pub const SIGNATURE: u32 = 0xA0000002;
+    pub const SIZE: u32 = 0xCC;
+
+    fn default_size() -> u32 {
+        Self::SIZE
+    }
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) =
+            read_block_header(r, "ConsoleDataBlock", Self::SIGNATURE, Some(Self::SIZE))?;
+        let fill_attributes = FillAttributesFlags::from_u16(r.read_u16::<LittleEndian>()?);
+        let popup_fill_attributes = FillAttributesFlags::from_u16(r.read_u16::<LittleEndian>()?);
+        let screen_buffer_size_x = r.read_i16::<LittleEndian>()?;
+        let screen_buffer_size_y = r.read_i16::<LittleEndian>()?;
+        let window_size_x = r.read_i16::<LittleEndian>()?;
+        let window_size_y = r.read_i16::<LittleEndian>()?;
+        let window_origin_x = r.read_i16::<LittleEndian>()?;
+        let window_origin_y = r.read_i16::<LittleEndian>()?;
+        r.read_u32::<LittleEndian>()?; // Unused1
+        r.read_u32::<LittleEndian>()?; // Unused2
+        let font_size = r.read_u32::<LittleEndian>()?;
+        let font_family_value = r.read_u32::<LittleEndian>()?;
+        let font_family = FontFamily::from(font_family_value);
+        let font_pitch = FontPitchFlags::from_u32(font_family_value);
+        let font_weight = r.read_u32::<LittleEndian>()?;
+        let face_name = read_utf16_string(r, Some(32))?;
+        let cursor_size = r.read_u32::<LittleEndian>()?;
+        let full_screen = r.read_u32::<LittleEndian>()? != 0;
+        let quick_edit = r.read_u32::<LittleEndian>()? != 0;
+        let insert_mode = r.read_u32::<LittleEndian>()? != 0;
+        let auto_position = r.read_u32::<LittleEndian>()? != 0;
+        let history_buffer_size = r.read_u32::<LittleEndian>()?;
+        let number_of_history_buffers = r.read_u32::<LittleEndian>()?;
+        let history_no_dup = r.read_u32::<LittleEndian>()? != 0;
+        let mut color_table = Vec::with_capacity(16);
+        for _ in 0..16 {
+            color_table.push(r.read_u32::<LittleEndian>()?);
+        }
+
+        Ok(Self {
+            size,
+            signature,
+            fill_attributes,
+            popup_fill_attributes,
+            screen_buffer_size_x,
+            screen_buffer_size_y,
+            window_size_x,
+            window_size_y,
+            window_origin_x,
+            window_origin_y,
+            font_size,
+            font_family,
+            font_pitch,
+            font_weight,
+            face_name,
+            cursor_size,
+            full_screen,
+            quick_edit,
+            insert_mode,
+            auto_position,
+            history_buffer_size,
+            number_of_history_buffers,
+            history_no_dup,
+            color_table,
+        })
+    }
+}
+
+/// [ConsoleFEDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the code page used to display text when the link target is a console application.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ConsoleFEDataBlock {
+    #[serde(skip, default = "ConsoleFEDataBlock::default_size")]
+    pub size: u32,
+    #[serde(skip, default = "ConsoleFEDataBlock::default_signature")]
+    pub signature: u32,
+    pub code_page: u32,
+}
+
+impl ConsoleFEDataBlock {
+    pub const SIGNATURE: u32 = 0xA0000004;
+    pub const SIZE: u32 = 0x0C;
+
+    fn default_size() -> u32 {
+        Self::SIZE
+    }
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) =
+            read_block_header(r, "ConsoleFEDataBlock", Self::SIGNATURE, Some(Self::SIZE))?;
+        let code_page = r.read_u32::<LittleEndian>()?;
+
+        Ok(Self {
+            size,
+            signature,
+            code_page,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use super::{preferred, read_ansi_unicode_pair, read_block_header, write_ansi_unicode_block};
+use crate::structs::Guid;
+use crate::ReaderError;
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+
+/// The characters used by the Windows Installer to compress GUIDs, the index of the character is its value.
+const BASE85_TABLE: &[u8; 85] =
+    b"!$%&'()*+,-.0123456789=?@ABCDEFGHIJKLMNOPQRSTUVWXYZ[]^_`abcdefghijklmnopqrstuvwxyz{}~";
+
+/// Decode a 20 characters compressed GUID. Every 5 characters represent a little endian `u32`,
+/// the first character is the least significant.
+fn decode_compressed_guid(s: &str) -> Option<Guid> {
+    let chars = s.as_bytes();
+    if chars.len() != 20 {
+        return None;
+    }
+    let mut guid_bytes = Vec::with_capacity(16);
+    for chunk in chars.chunks(5) {
+        let mut value: u64 = 0;
+        for c in chunk.iter().rev() {
+            let digit = BASE85_TABLE.iter().position(|t| t == c)? as u64;
+            value = value * 85 + digit;
+        }
+        if value > u32::MAX as u64 {
+            return None;
+        }
+        guid_bytes.extend_from_slice(&(value as u32).to_le_bytes());
+    }
+    Guid::from_buffer(&guid_bytes).ok()
+}
+
+/// Windows Installer (MSI) descriptor stored in the `DarwinDataBlock`. It is made of a compressed product code,
+/// an optional feature name and an optional compressed component code
+/// (`<product code><feature name>[>component code|<]`).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MsiDescriptor {
+    pub product_code: Guid,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub feature_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub component_code: Option<Guid>,
+}
+
+impl MsiDescriptor {
+    /// Decode the descriptor string, returns `None` if the string is not a valid descriptor.
+    pub fn from_descriptor(descriptor: &str) -> Option<Self> {
+        if !descriptor.is_ascii() || descriptor.len() < 20 {
+            return None;
+        }
+        let product_code = decode_compressed_guid(&descriptor[..20])?;
+        let rest = &descriptor[20..];
+        let (feature_name, component_code) = match rest.find(['>', '<']) {
+            Some(pos) if rest.as_bytes()[pos] == b'>' => {
+                (&rest[..pos], Some(decode_compressed_guid(&rest[pos + 1..])?))
+            }
+            Some(pos) => (&rest[..pos], None),
+            None => (rest, None),
+        };
+        let feature_name = match feature_name.is_empty() {
+            true => None,
+            false => Some(feature_name.to_string()),
+        };
+
+        Some(Self {
+            product_code,
+            feature_name,
+            component_code,
+        })
+    }
+}
+
+/// [DarwinDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the Windows Installer descriptor of an advertised shortcut.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DarwinDataBlock {
+    #[serde(skip, default = "DarwinDataBlock::default_size")]
+    pub size: u32,
+    #[serde(skip, default = "DarwinDataBlock::default_signature")]
+    pub signature: u32,
+    pub darwin_data_ansi: String,
+    pub darwin_data_unicode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub descriptor: Option<MsiDescriptor>,
+}
+
+impl DarwinDataBlock {
+    pub const SIGNATURE: u32 = 0xA0000006;
+    pub const SIZE: u32 = 0x314;
+
+    fn default_size() -> u32 {
+        Self::SIZE
+    }
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) =
+            read_block_header(r, "DarwinDataBlock", Self::SIGNATURE, Some(Self::SIZE))?;
+        let (darwin_data_ansi, darwin_data_unicode) = read_ansi_unicode_pair(r)?;
+        let descriptor =
+            MsiDescriptor::from_descriptor(preferred(&darwin_data_ansi, &darwin_data_unicode));
+
+        Ok(Self {
+            size,
+            signature,
+            darwin_data_ansi,
+            darwin_data_unicode,
+            descriptor,
+        })
+    }
+
+    /// Returns the Unicode descriptor if it is set, otherwise the ANSI descriptor.
+    pub fn darwin_data(&self) -> &str {
+        preferred(&self.darwin_data_ansi, &self.darwin_data_unicode)
+    }
+
+    /// Returns the block in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        write_ansi_unicode_block(
+            Self::SIGNATURE,
+            &self.darwin_data_ansi,
+            &self.darwin_data_unicode,
+        )
+    }
+}
@@ -0,0 +1,60 @@
+use super::{preferred, read_ansi_unicode_pair, read_block_header, write_ansi_unicode_block};
+use crate::ReaderError;
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+
+/// [IconEnvironmentDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the path to the icon location with environment variables (e.g. `%SystemRoot%\System32\shell32.dll`).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct IconEnvironmentDataBlock {
+    #[serde(skip, default = "IconEnvironmentDataBlock::default_size")]
+    pub size: u32,
+    #[serde(skip, default = "IconEnvironmentDataBlock::default_signature")]
+    pub signature: u32,
+    pub target_ansi: String,
+    pub target_unicode: String,
+}
+
+impl IconEnvironmentDataBlock {
+    pub const SIGNATURE: u32 = 0xA0000007;
+    pub const SIZE: u32 = 0x314;
+
+    fn default_size() -> u32 {
+        Self::SIZE
+    }
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) = read_block_header(
+            r,
+            "IconEnvironmentDataBlock",
+            Self::SIGNATURE,
+            Some(Self::SIZE),
+        )?;
+        let (target_ansi, target_unicode) = read_ansi_unicode_pair(r)?;
+
+        Ok(Self {
+            size,
+            signature,
+            target_ansi,
+            target_unicode,
+        })
+    }
+
+    /// Returns the Unicode icon path if it is set, otherwise the ANSI icon path.
+    pub fn target(&self) -> &str {
+        preferred(&self.target_ansi, &self.target_unicode)
+    }
+
+    /// Returns the block in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        write_ansi_unicode_block(Self::SIGNATURE, &self.target_ansi, &self.target_unicode)
+    }
+}
@@ -0,0 +1,128 @@
+use super::{read_block_header, resolve_folder_path};
+use crate::structs::shell_items::IDList;
+use crate::structs::Guid;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+
+/// Common [KNOWNFOLDERID](https://learn.microsoft.com/en-us/windows/win32/shell/knownfolderid) values and their names.
+const KNOWN_FOLDERS: &[(&str, &str)] = &[
+    ("0139D44E-6AFE-49F2-8690-3DAFCAE6FFB8", "CommonPrograms"),
+    ("0762D272-C50A-4BB0-A382-697DCD729B80", "UserProfiles"),
+    ("0AC0837C-BBF8-452A-850D-79D08E667CA7", "ComputerFolder"),
+    ("18989B1D-99B5-455B-841C-AB7C74E4DDFC", "Videos"),
+    ("1777F761-68AD-4D8A-87BD-30B759FA33DD", "Favorites"),
+    ("1AC14E77-02E7-4E5D-B744-2EB1AE5198B7", "System"),
+    ("1B3EA5DC-B587-4786-B4EF-BD1DC332AEAE", "Libraries"),
+    ("31C0DD25-9439-4F12-BF41-7FF4EDA38722", "Objects3D"),
+    ("33E28130-4E1E-4676-835A-98395C3BC3BB", "Pictures"),
+    ("374DE290-123F-4565-9164-39C4925E467B", "Downloads"),
+    ("3EB685DB-65F9-4CF6-A03A-E3EF65729F3D", "RoamingAppData"),
+    ("4BD8D571-6D19-48D3-BE97-422220080E43", "Music"),
+    ("4C5C32FF-BB9D-43B0-B5B4-2D72E54EAAA4", "SavedGames"),
+    ("52A4F021-7B75-48A9-9F6B-4B87A210BC8F", "QuickLaunch"),
+    ("56784854-C6CB-462B-8169-88E350ACB882", "Contacts"),
+    ("5CD7AEE2-2219-4A67-B85D-6C9CE15660CB", "UserProgramFiles"),
+    ("5E6C858F-0E22-4760-9AFE-EA3317B67173", "Profile"),
+    ("625B53C3-AB48-4EC1-BA1F-A1EF4146FC19", "StartMenu"),
+    ("62AB5D82-FDC1-4DC3-A9DD-070D1D495D97", "ProgramData"),
+    ("724EF170-A42D-4FEF-9F26-B60E846FBA4F", "AdminTools"),
+    ("7B0DB17D-9CD2-4A93-9733-46CC89022E7C", "DocumentsLibrary"),
+    ("7C5A40EF-A0FB-4BFC-874A-C0F2E0B9FA8E", "ProgramFilesX86"),
+    ("82A5EA35-D9CD-47C5-9629-E15D2F714E6E", "CommonStartup"),
+    ("82A74AEB-AEB4-465C-A014-D097EE346D63", "ControlPanelFolder"),
+    ("8983036C-27C0-404B-8F08-102D10DCFD74", "SendTo"),
+    ("905E63B6-C1BF-494E-B29C-65B732D3D21A", "ProgramFiles"),
+    ("A4115719-D62E-491D-AA7C-E74B8BE3B067", "CommonStartMenu"),
+    ("A520A1A4-1780-4FF6-BD18-167343C5AF16", "LocalAppDataLow"),
+    ("A52BBA46-E9E1-435F-B3D9-28DAA648C0F6", "OneDrive"),
+    ("A63293E8-664E-48DB-A079-DF759E0509F7", "Templates"),
+    ("A77F5D77-2E2B-44C3-A6A2-ABA601054A51", "Programs"),
+    ("AB5FB87B-7CE2-4F83-915D-550846C9537B", "CameraRoll"),
+    ("AE50C081-EBD2-438A-8655-8A092E34987A", "Recent"),
+    ("B4BFCC3A-DB2C-424C-B029-7FE99A87C641", "Desktop"),
+    ("B7534046-3ECB-4C18-BE4E-64CD4CB7D6AC", "RecycleBinFolder"),
+    ("B7BEDE81-DF94-4682-A7D8-57A52620B86F", "Screenshots"),
+    ("B97D20BB-F46A-4C97-BA10-5E3608430854", "Startup"),
+    ("BFB9D5E0-C6A9-404C-B2B2-AE6DB6AF4968", "Links"),
+    ("C4AA340D-F20F-4863-AFEF-F87EF2E6BA25", "PublicDesktop"),
+    ("D0384E7D-BAC3-4797-8F14-CBA229B392B5", "CommonAdminTools"),
+    ("D20BEEC4-5CA8-4905-AE3B-BF251EA09B53", "NetworkFolder"),
+    ("D65231B0-B2F1-4857-A4CE-A8E7C6EA7D27", "SystemX86"),
+    ("DFDF76A2-C82A-4D63-906A-5644AC457385", "Public"),
+    ("ED4824AF-DCE4-45A8-81E2-FC7965083634", "PublicDocuments"),
+    ("F1B32785-6FBA-4FCF-9D55-7B8E7F157091", "LocalAppData"),
+    ("F38BF404-1D43-42F2-9305-67DE0B28FC23", "Windows"),
+    ("F7F1ED05-9F6D-47A2-AAAE-29D317C6F066", "ProgramFilesCommon"),
+    ("FD228CB7-AE11-4AE3-864C-16F3910AB8FE", "Fonts"),
+    ("FDD39AD0-238F-46AF-ADB4-6C85480369C7", "Documents"),
+];
+
+/// [KnownFolderDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the known folder and the offset of its first child in the link target IDList.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct KnownFolderDataBlock {
+    #[serde(skip, default = "KnownFolderDataBlock::default_size")]
+    pub size: u32,
+    #[serde(skip, default = "KnownFolderDataBlock::default_signature")]
+    pub signature: u32,
+    pub known_folder_id: Guid,
+    pub offset: u32,
+}
+
+impl KnownFolderDataBlock {
+    pub const SIGNATURE: u32 = 0xA000000B;
+    pub const SIZE: u32 = 0x1C;
+
+    fn default_size() -> u32 {
+        Self::SIZE
+    }
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) =
+            read_block_header(r, "KnownFolderDataBlock", Self::SIGNATURE, Some(Self::SIZE))?;
+        let known_folder_id = Guid::from_reader(r)?;
+        let offset = r.read_u32::<LittleEndian>()?;
+
+        Ok(Self {
+            size,
+            signature,
+            known_folder_id,
+            offset,
+        })
+    }
+
+    /// Returns the block in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&Self::SIZE.to_le_bytes());
+        bytes.extend_from_slice(&Self::SIGNATURE.to_le_bytes());
+        bytes.extend_from_slice(&self.known_folder_id.to_bytes());
+        bytes.extend_from_slice(&self.offset.to_le_bytes());
+        bytes
+    }
+
+    /// Returns the name of the known folder, or the GUID if the folder is unknown.
+    pub fn folder_name(&self) -> String {
+        let guid = self.known_folder_id.to_string();
+        match KNOWN_FOLDERS.iter().find(|(id, _)| *id == guid) {
+            Some((_, name)) => name.to_string(),
+            None => guid,
+        }
+    }
+
+    /// Returns the path of the link target relative to the known folder (e.g. `Documents\report.docx`).
+    /// `id_list` is the link target IDList.
+    pub fn resolve_path(&self, id_list: &IDList) -> Option<String> {
+        resolve_folder_path(&self.folder_name(), id_list, self.offset)
+    }
+}
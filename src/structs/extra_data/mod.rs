@@ -0,0 +1,298 @@
+//! This module contains the [ExtraData](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) blocks parsers found at the end of LNK files.
+#![allow(non_camel_case_types)]
+#![allow(clippy::upper_case_acronyms)]
+#![cfg(feature = "lnk")]
+mod console;
+mod darwin;
+mod environment_variable;
+mod icon_environment;
+mod known_folder;
+mod property_store;
+mod shim;
+mod special_folder;
+mod tracker;
+mod vista_and_above_id_list;
+
+pub use console::{
+    ConsoleDataBlock, ConsoleFEDataBlock, FillAttributesFlags, FontFamily, FontPitchFlags,
+};
+pub use darwin::{DarwinDataBlock, MsiDescriptor};
+pub use environment_variable::EnvironmentVariableDataBlock;
+pub use icon_environment::IconEnvironmentDataBlock;
+pub use known_folder::KnownFolderDataBlock;
+pub use property_store::PropertyStoreDataBlock;
+pub use shim::ShimDataBlock;
+pub use special_folder::{SpecialFolderDataBlock, CSIDL};
+pub use tracker::TrackerDataBlock;
+#[cfg(feature = "jump_lists")]
+pub(crate) use tracker::{uuid_mac_address, uuid_time};
+pub use vista_and_above_id_list::VistaAndAboveIDListDataBlock;
+
+use crate::structs::shell_items::IDList;
+use crate::utils::{
+    deserialize_hex, encode_cp1252, read_cp1252_string, read_utf16_string, serialize_hex,
+};
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Error, ErrorKind, Read};
+
+/// Extra data block with an unknown signature, or a known signature that failed to parse.
+/// The raw bytes of the block (without the size and signature fields) are kept.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct UnknownDataBlock {
+    pub size: u32,
+    pub signature: u32,
+    #[serde(serialize_with = "serialize_hex", deserialize_with = "deserialize_hex")]
+    pub data: Vec<u8>,
+}
+
+impl UnknownDataBlock {
+    /// Returns the block in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&(self.data.len() as u32 + 8).to_le_bytes());
+        bytes.extend_from_slice(&self.signature.to_le_bytes());
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
+}
+
+/// The implemented types for extra data blocks.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExtraDataTypes {
+    EnvironmentVariable(EnvironmentVariableDataBlock),
+    Console(ConsoleDataBlock),
+    Tracker(TrackerDataBlock),
+    ConsoleFE(ConsoleFEDataBlock),
+    SpecialFolder(SpecialFolderDataBlock),
+    Darwin(DarwinDataBlock),
+    IconEnvironment(IconEnvironmentDataBlock),
+    Shim(ShimDataBlock),
+    PropertyStore(PropertyStoreDataBlock),
+    KnownFolder(KnownFolderDataBlock),
+    VistaAndAboveIDList(VistaAndAboveIDListDataBlock),
+    Unknown(UnknownDataBlock),
+}
+
+impl ExtraDataTypes {
+    /// Parse a single extra data block, `buf` contains the whole block including the size and signature fields.
+    /// Blocks with an unknown signature or that fail to parse are returned as `ExtraDataTypes::Unknown`.
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        let mut r = Cursor::new(buf);
+        let size = r.read_u32::<LittleEndian>()?;
+        let signature = r.read_u32::<LittleEndian>()?;
+        let block = match signature {
+            EnvironmentVariableDataBlock::SIGNATURE => {
+                EnvironmentVariableDataBlock::from_buffer(buf).map(Self::EnvironmentVariable)
+            }
+            ConsoleDataBlock::SIGNATURE => ConsoleDataBlock::from_buffer(buf).map(Self::Console),
+            TrackerDataBlock::SIGNATURE => TrackerDataBlock::from_buffer(buf).map(Self::Tracker),
+            ConsoleFEDataBlock::SIGNATURE => {
+                ConsoleFEDataBlock::from_buffer(buf).map(Self::ConsoleFE)
+            }
+            SpecialFolderDataBlock::SIGNATURE => {
+                SpecialFolderDataBlock::from_buffer(buf).map(Self::SpecialFolder)
+            }
+            DarwinDataBlock::SIGNATURE => DarwinDataBlock::from_buffer(buf).map(Self::Darwin),
+            IconEnvironmentDataBlock::SIGNATURE => {
+                IconEnvironmentDataBlock::from_buffer(buf).map(Self::IconEnvironment)
+            }
+            ShimDataBlock::SIGNATURE => ShimDataBlock::from_buffer(buf).map(Self::Shim),
+            PropertyStoreDataBlock::SIGNATURE => {
+                PropertyStoreDataBlock::from_buffer(buf).map(Self::PropertyStore)
+            }
+            KnownFolderDataBlock::SIGNATURE => {
+                KnownFolderDataBlock::from_buffer(buf).map(Self::KnownFolder)
+            }
+            VistaAndAboveIDListDataBlock::SIGNATURE => {
+                VistaAndAboveIDListDataBlock::from_buffer(buf).map(Self::VistaAndAboveIDList)
+            }
+            _ => Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                "unknown extra data block signature",
+            ))),
+        };
+
+        Ok(block.unwrap_or_else(|_| {
+            Self::Unknown(UnknownDataBlock {
+                size,
+                signature,
+                data: buf[8..].to_vec(),
+            })
+        }))
+    }
+
+    /// Returns the signature of the block, the signature of the known block types is always their `SIGNATURE`.
+    pub fn signature(&self) -> u32 {
+        match self {
+            Self::EnvironmentVariable(_) => EnvironmentVariableDataBlock::SIGNATURE,
+            Self::Console(_) => ConsoleDataBlock::SIGNATURE,
+            Self::Tracker(_) => TrackerDataBlock::SIGNATURE,
+            Self::ConsoleFE(_) => ConsoleFEDataBlock::SIGNATURE,
+            Self::SpecialFolder(_) => SpecialFolderDataBlock::SIGNATURE,
+            Self::Darwin(_) => DarwinDataBlock::SIGNATURE,
+            Self::IconEnvironment(_) => IconEnvironmentDataBlock::SIGNATURE,
+            Self::Shim(_) => ShimDataBlock::SIGNATURE,
+            Self::PropertyStore(_) => PropertyStoreDataBlock::SIGNATURE,
+            Self::KnownFolder(_) => KnownFolderDataBlock::SIGNATURE,
+            Self::VistaAndAboveIDList(_) => VistaAndAboveIDListDataBlock::SIGNATURE,
+            Self::Unknown(block) => block.signature,
+        }
+    }
+}
+
+/// [ExtraData](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Reads extra data blocks until the terminal block (a size less than 4) or the end of the data.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ExtraData {
+    pub blocks: Vec<ExtraDataTypes>,
+    /// The value of the terminal block, `None` if the data ended before the terminal block.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub terminal_block: Option<u32>,
+    /// Bytes found after the terminal block or after a truncated block.
+    #[serde(
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        serialize_with = "serialize_hex",
+        deserialize_with = "deserialize_hex"
+    )]
+    pub trailing_data: Vec<u8>,
+}
+
+impl ExtraData {
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let mut blocks = vec![];
+        let mut terminal_block = None;
+        let mut trailing_data = vec![];
+        loop {
+            let mut size_bytes = vec![];
+            r.by_ref().take(4).read_to_end(&mut size_bytes)?;
+            if size_bytes.len() < 4 {
+                trailing_data = size_bytes;
+                break;
+            }
+            let size =
+                u32::from_le_bytes([size_bytes[0], size_bytes[1], size_bytes[2], size_bytes[3]]);
+            if size < 4 {
+                terminal_block = Some(size);
+                r.read_to_end(&mut trailing_data)?;
+                break;
+            }
+
+            let mut block_data = size_bytes;
+            r.by_ref()
+                .take(size as u64 - 4)
+                .read_to_end(&mut block_data)?;
+            if block_data.len() < size as usize || size < 8 {
+                trailing_data = block_data;
+                r.read_to_end(&mut trailing_data)?;
+                break;
+            }
+            blocks.push(ExtraDataTypes::from_buffer(&block_data)?);
+        }
+
+        Ok(Self {
+            blocks,
+            terminal_block,
+            trailing_data,
+        })
+    }
+}
+
+/// Read the `BlockSize` and `BlockSignature` fields of an extra data block and make sure they match
+/// the expected values. `expected_size` is `None` for blocks with a variable size.
+fn read_block_header<R: Read>(
+    r: &mut R,
+    block_name: &str,
+    expected_signature: u32,
+    expected_size: Option<u32>,
+) -> Result<(u32, u32), ReaderError> {
+    let size = r.read_u32::<LittleEndian>()?;
+    let signature = r.read_u32::<LittleEndian>()?;
+    if signature != expected_signature {
+        return Err(ReaderError::from(Error::new(
+            ErrorKind::InvalidData,
+            format!(
+                "{} : expected signature '{:#010X}' found '{:#010X}'",
+                block_name, expected_signature, signature
+            ),
+        )));
+    }
+    if let Some(expected_size) = expected_size {
+        if size != expected_size {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "{} : expected size '{:#X}' found '{:#X}'",
+                    block_name, expected_size, size
+                ),
+            )));
+        }
+    }
+    Ok((size, signature))
+}
+
+/// Read a fixed size CP1252 string field, the string ends at the first NUL character.
+pub(crate) fn read_fixed_cp1252_string<R: Read>(
+    r: &mut R,
+    len: usize,
+) -> Result<String, ReaderError> {
+    let mut string = read_cp1252_string(r, Some(len))?;
+    if let Some(end) = string.find('\0') {
+        string.truncate(end);
+    }
+    Ok(string)
+}
+
+/// Read the fixed size 260 bytes ANSI string followed by the 520 bytes Unicode string
+/// used by the `EnvironmentVariable`, `IconEnvironment` and `Darwin` blocks.
+fn read_ansi_unicode_pair<R: Read>(r: &mut R) -> Result<(String, String), ReaderError> {
+    let ansi = read_fixed_cp1252_string(r, 260)?;
+    let unicode = read_utf16_string(r, Some(260))?;
+    Ok((ansi, unicode))
+}
+
+/// Write the header and the fixed size ANSI and Unicode strings of the `EnvironmentVariable`, `IconEnvironment`
+/// and `Darwin` blocks. The strings are truncated to fit in their fields.
+fn write_ansi_unicode_block(signature: u32, ansi: &str, unicode: &str) -> Vec<u8> {
+    let mut bytes = vec![];
+    bytes.extend_from_slice(&0x314u32.to_le_bytes());
+    bytes.extend_from_slice(&signature.to_le_bytes());
+    let mut ansi = encode_cp1252(ansi);
+    ansi.truncate(259);
+    ansi.resize(260, 0);
+    bytes.extend_from_slice(&ansi);
+    let mut unicode = unicode
+        .encode_utf16()
+        .take(259)
+        .flat_map(|c| c.to_le_bytes())
+        .collect::<Vec<u8>>();
+    unicode.resize(520, 0);
+    bytes.extend_from_slice(&unicode);
+    bytes
+}
+
+/// Returns the Unicode value if it is set, otherwise the ANSI value.
+fn preferred<'a>(ansi: &'a str, unicode: &'a str) -> &'a str {
+    if unicode.is_empty() {
+        ansi
+    } else {
+        unicode
+    }
+}
+
+/// Build the path of the link target relative to a known or special folder. `offset` is the offset of the
+/// first child of the folder in the link target IDList.
+fn resolve_folder_path(folder_name: &str, id_list: &IDList, offset: u32) -> Option<String> {
+    let (_, children) = id_list.split_at_offset(offset)?;
+    match children.is_empty() {
+        true => Some(folder_name.to_string()),
+        false => Some(format!("{}\\{}", folder_name, IDList::items_path(children))),
+    }
+}
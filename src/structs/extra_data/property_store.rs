@@ -0,0 +1,63 @@
+use super::read_block_header;
+use crate::structs::{PropertyStore, PropVariant};
+use crate::ReaderError;
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Error, ErrorKind, Read};
+
+/// [PropertyStoreDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the serialized property storages of the link target.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PropertyStoreDataBlock {
+    pub size: u32,
+    #[serde(skip, default = "PropertyStoreDataBlock::default_signature")]
+    pub signature: u32,
+    pub property_store: PropertyStore,
+}
+
+impl PropertyStoreDataBlock {
+    pub const SIGNATURE: u32 = 0xA0000009;
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) =
+            read_block_header(r, "PropertyStoreDataBlock", Self::SIGNATURE, None)?;
+        let store_size = size.saturating_sub(8) as u64;
+        let mut store_data = vec![];
+        r.take(store_size).read_to_end(&mut store_data)?;
+        if (store_data.len() as u64) < store_size {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::UnexpectedEof,
+                "PropertyStoreDataBlock : the block size is larger than the remaining data",
+            )));
+        }
+        let property_store = PropertyStore::from_buffer(&store_data)?;
+
+        Ok(Self {
+            size,
+            signature,
+            property_store,
+        })
+    }
+
+    /// Returns the block in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let store = self.property_store.to_bytes();
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&(store.len() as u32 + 8).to_le_bytes());
+        bytes.extend_from_slice(&Self::SIGNATURE.to_le_bytes());
+        bytes.extend_from_slice(&store);
+        bytes
+    }
+
+    /// Returns the value of the property with the format ID `format_id` and the integer ID `id`, see `PropertyStore::get`.
+    pub fn get(&self, format_id: &str, id: u32) -> Option<&PropVariant> {
+        self.property_store.get(format_id, id)
+    }
+}
@@ -0,0 +1,56 @@
+use super::read_block_header;
+use crate::utils::read_utf16_string;
+use crate::ReaderError;
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+
+/// [ShimDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the name of the shim layer applied to the link target.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShimDataBlock {
+    pub size: u32,
+    #[serde(skip, default = "ShimDataBlock::default_signature")]
+    pub signature: u32,
+    pub layer_name: String,
+}
+
+impl ShimDataBlock {
+    pub const SIGNATURE: u32 = 0xA0000008;
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) = read_block_header(r, "ShimDataBlock", Self::SIGNATURE, None)?;
+        let layer_name = read_utf16_string(r, Some((size.saturating_sub(8) / 2) as usize))?;
+
+        Ok(Self {
+            size,
+            signature,
+            layer_name,
+        })
+    }
+
+    /// Returns the block in its binary form, the layer name is padded to the minimum block size.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut layer_name = self
+            .layer_name
+            .encode_utf16()
+            .chain(std::iter::once(0))
+            .flat_map(|c| c.to_le_bytes())
+            .collect::<Vec<u8>>();
+        if layer_name.len() < 0x80 {
+            layer_name.resize(0x80, 0);
+        }
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&(layer_name.len() as u32 + 8).to_le_bytes());
+        bytes.extend_from_slice(&Self::SIGNATURE.to_le_bytes());
+        bytes.extend_from_slice(&layer_name);
+        bytes
+    }
+}
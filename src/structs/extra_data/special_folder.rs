@@ -0,0 +1,250 @@
+use super::{read_block_header, resolve_folder_path};
+use crate::structs::shell_items::IDList;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Cursor, Read};
+
+/// [CSIDL](https://learn.microsoft.com/en-us/windows/win32/shell/csidl) special folders identifiers.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum CSIDL {
+    DESKTOP,
+    INTERNET,
+    PROGRAMS,
+    CONTROLS,
+    PRINTERS,
+    PERSONAL,
+    FAVORITES,
+    STARTUP,
+    RECENT,
+    SENDTO,
+    BITBUCKET,
+    STARTMENU,
+    MYDOCUMENTS,
+    MYMUSIC,
+    MYVIDEO,
+    DESKTOPDIRECTORY,
+    DRIVES,
+    NETWORK,
+    NETHOOD,
+    FONTS,
+    TEMPLATES,
+    COMMON_STARTMENU,
+    COMMON_PROGRAMS,
+    COMMON_STARTUP,
+    COMMON_DESKTOPDIRECTORY,
+    APPDATA,
+    PRINTHOOD,
+    LOCAL_APPDATA,
+    ALTSTARTUP,
+    COMMON_ALTSTARTUP,
+    COMMON_FAVORITES,
+    INTERNET_CACHE,
+    COOKIES,
+    HISTORY,
+    COMMON_APPDATA,
+    WINDOWS,
+    SYSTEM,
+    PROGRAM_FILES,
+    MYPICTURES,
+    PROFILE,
+    SYSTEMX86,
+    PROGRAM_FILESX86,
+    PROGRAM_FILES_COMMON,
+    PROGRAM_FILES_COMMONX86,
+    COMMON_TEMPLATES,
+    COMMON_DOCUMENTS,
+    COMMON_ADMINTOOLS,
+    ADMINTOOLS,
+    CONNECTIONS,
+    COMMON_MUSIC,
+    COMMON_PICTURES,
+    COMMON_VIDEO,
+    RESOURCES,
+    RESOURCES_LOCALIZED,
+    COMMON_OEM_LINKS,
+    CDBURN_AREA,
+    COMPUTERSNEARME,
+    UNKNOWN(u32),
+}
+
+impl From<u32> for CSIDL {
+    fn from(num: u32) -> Self {
+        match num {
+            0x00 => CSIDL::DESKTOP,
+            0x01 => CSIDL::INTERNET,
+            0x02 => CSIDL::PROGRAMS,
+            0x03 => CSIDL::CONTROLS,
+            0x04 => CSIDL::PRINTERS,
+            0x05 => CSIDL::PERSONAL,
+            0x06 => CSIDL::FAVORITES,
+            0x07 => CSIDL::STARTUP,
+            0x08 => CSIDL::RECENT,
+            0x09 => CSIDL::SENDTO,
+            0x0A => CSIDL::BITBUCKET,
+            0x0B => CSIDL::STARTMENU,
+            0x0C => CSIDL::MYDOCUMENTS,
+            0x0D => CSIDL::MYMUSIC,
+            0x0E => CSIDL::MYVIDEO,
+            0x10 => CSIDL::DESKTOPDIRECTORY,
+            0x11 => CSIDL::DRIVES,
+            0x12 => CSIDL::NETWORK,
+            0x13 => CSIDL::NETHOOD,
+            0x14 => CSIDL::FONTS,
+            0x15 => CSIDL::TEMPLATES,
+            0x16 => CSIDL::COMMON_STARTMENU,
+            0x17 => CSIDL::COMMON_PROGRAMS,
+            0x18 => CSIDL::COMMON_STARTUP,
+            0x19 => CSIDL::COMMON_DESKTOPDIRECTORY,
+            0x1A => CSIDL::APPDATA,
+            0x1B => CSIDL::PRINTHOOD,
+            0x1C => CSIDL::LOCAL_APPDATA,
+            0x1D => CSIDL::ALTSTARTUP,
+            0x1E => CSIDL::COMMON_ALTSTARTUP,
+            0x1F => CSIDL::COMMON_FAVORITES,
+            0x20 => CSIDL::INTERNET_CACHE,
+            0x21 => CSIDL::COOKIES,
+            0x22 => CSIDL::HISTORY,
+            0x23 => CSIDL::COMMON_APPDATA,
+            0x24 => CSIDL::WINDOWS,
+            0x25 => CSIDL::SYSTEM,
+            0x26 => CSIDL::PROGRAM_FILES,
+            0x27 => CSIDL::MYPICTURES,
+            0x28 => CSIDL::PROFILE,
+            0x29 => CSIDL::SYSTEMX86,
+            0x2A => CSIDL::PROGRAM_FILESX86,
+            0x2B => CSIDL::PROGRAM_FILES_COMMON,
+            0x2C => CSIDL::PROGRAM_FILES_COMMONX86,
+            0x2D => CSIDL::COMMON_TEMPLATES,
+            0x2E => CSIDL::COMMON_DOCUMENTS,
+            0x2F => CSIDL::COMMON_ADMINTOOLS,
+            0x30 => CSIDL::ADMINTOOLS,
+            0x31 => CSIDL::CONNECTIONS,
+            0x35 => CSIDL::COMMON_MUSIC,
+            0x36 => CSIDL::COMMON_PICTURES,
+            0x37 => CSIDL::COMMON_VIDEO,
+            0x38 => CSIDL::RESOURCES,
+            0x39 => CSIDL::RESOURCES_LOCALIZED,
+            0x3A => CSIDL::COMMON_OEM_LINKS,
+            0x3B => CSIDL::CDBURN_AREA,
+            0x3D => CSIDL::COMPUTERSNEARME,
+            other => CSIDL::UNKNOWN(other),
+        }
+    }
+}
+
+impl Display for CSIDL {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let folder_name = match &self {
+            CSIDL::DESKTOP => "Desktop".to_string(),
+            CSIDL::INTERNET => "Internet".to_string(),
+            CSIDL::PROGRAMS => "Programs".to_string(),
+            CSIDL::CONTROLS => "Control Panel".to_string(),
+            CSIDL::PRINTERS => "Printers".to_string(),
+            CSIDL::PERSONAL => "Documents".to_string(),
+            CSIDL::FAVORITES => "Favorites".to_string(),
+            CSIDL::STARTUP => "Startup".to_string(),
+            CSIDL::RECENT => "Recent".to_string(),
+            CSIDL::SENDTO => "SendTo".to_string(),
+            CSIDL::BITBUCKET => "Recycle Bin".to_string(),
+            CSIDL::STARTMENU => "Start Menu".to_string(),
+            CSIDL::MYDOCUMENTS => "My Documents".to_string(),
+            CSIDL::MYMUSIC => "Music".to_string(),
+            CSIDL::MYVIDEO => "Videos".to_string(),
+            CSIDL::DESKTOPDIRECTORY => "Desktop".to_string(),
+            CSIDL::DRIVES => "My Computer".to_string(),
+            CSIDL::NETWORK => "Network".to_string(),
+            CSIDL::NETHOOD => "Network Shortcuts".to_string(),
+            CSIDL::FONTS => "Fonts".to_string(),
+            CSIDL::TEMPLATES => "Templates".to_string(),
+            CSIDL::COMMON_STARTMENU => "Common Start Menu".to_string(),
+            CSIDL::COMMON_PROGRAMS => "Common Programs".to_string(),
+            CSIDL::COMMON_STARTUP => "Common Startup".to_string(),
+            CSIDL::COMMON_DESKTOPDIRECTORY => "Public Desktop".to_string(),
+            CSIDL::APPDATA => "AppData\\Roaming".to_string(),
+            CSIDL::PRINTHOOD => "Printer Shortcuts".to_string(),
+            CSIDL::LOCAL_APPDATA => "AppData\\Local".to_string(),
+            CSIDL::ALTSTARTUP => "Alternate Startup".to_string(),
+            CSIDL::COMMON_ALTSTARTUP => "Common Alternate Startup".to_string(),
+            CSIDL::COMMON_FAVORITES => "Common Favorites".to_string(),
+            CSIDL::INTERNET_CACHE => "Temporary Internet Files".to_string(),
+            CSIDL::COOKIES => "Cookies".to_string(),
+            CSIDL::HISTORY => "History".to_string(),
+            CSIDL::COMMON_APPDATA => "ProgramData".to_string(),
+            CSIDL::WINDOWS => "Windows".to_string(),
+            CSIDL::SYSTEM => "System32".to_string(),
+            CSIDL::PROGRAM_FILES => "Program Files".to_string(),
+            CSIDL::MYPICTURES => "Pictures".to_string(),
+            CSIDL::PROFILE => "Profile".to_string(),
+            CSIDL::SYSTEMX86 => "SysWOW64".to_string(),
+            CSIDL::PROGRAM_FILESX86 => "Program Files (x86)".to_string(),
+            CSIDL::PROGRAM_FILES_COMMON => "Common Files".to_string(),
+            CSIDL::PROGRAM_FILES_COMMONX86 => "Common Files (x86)".to_string(),
+            CSIDL::COMMON_TEMPLATES => "Common Templates".to_string(),
+            CSIDL::COMMON_DOCUMENTS => "Public Documents".to_string(),
+            CSIDL::COMMON_ADMINTOOLS => "Common Administrative Tools".to_string(),
+            CSIDL::ADMINTOOLS => "Administrative Tools".to_string(),
+            CSIDL::CONNECTIONS => "Network Connections".to_string(),
+            CSIDL::COMMON_MUSIC => "Public Music".to_string(),
+            CSIDL::COMMON_PICTURES => "Public Pictures".to_string(),
+            CSIDL::COMMON_VIDEO => "Public Videos".to_string(),
+            CSIDL::RESOURCES => "Resources".to_string(),
+            CSIDL::RESOURCES_LOCALIZED => "Localized Resources".to_string(),
+            CSIDL::COMMON_OEM_LINKS => "OEM Links".to_string(),
+            CSIDL::CDBURN_AREA => "CD Burning".to_string(),
+            CSIDL::COMPUTERSNEARME => "Computers Near Me".to_string(),
+            CSIDL::UNKNOWN(other) => format!("CSIDL_{:#04X}", other),
+        };
+        write!(f, "{}", folder_name)
+    }
+}
+
+/// [SpecialFolderDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the special folder and the offset of its first child in the link target IDList.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SpecialFolderDataBlock {
+    #[serde(skip, default = "SpecialFolderDataBlock::default_size")]
+    pub size: u32,
+    #[serde(skip, default = "SpecialFolderDataBlock::default_signature")]
+    pub signature: u32,
+    pub special_folder_id: CSIDL,
+    pub offset: u32,
+}
+
+impl SpecialFolderDataBlock {
+    pub const SIGNATURE: u32 = 0xA0000005;
+    pub const SIZE: u32 = 0x10;
+
+    fn default_size() -> u32 {
+        Self::SIZE
+    }
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) =
+            read_block_header(r, "SpecialFolderDataBlock", Self::SIGNATURE, Some(Self::SIZE))?;
+        let special_folder_id = CSIDL::from(r.read_u32::<LittleEndian>()?);
+        let offset = r.read_u32::<LittleEndian>()?;
+
+        Ok(Self {
+            size,
+            signature,
+            special_folder_id,
+            offset,
+        })
+    }
+
+    /// Returns the path of the link target relative to the special folder (e.g. `Documents\report.docx`).
+    /// `id_list` is the link target IDList.
+    pub fn resolve_path(&self, id_list: &IDList) -> Option<String> {
+        resolve_folder_path(&self.special_folder_id.to_string(), id_list, self.offset)
+    }
+}
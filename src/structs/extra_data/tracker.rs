@@ -0,0 +1,121 @@
+use super::{read_block_header, read_fixed_cp1252_string};
+use crate::date_time::FileTime;
+use crate::structs::Guid;
+use crate::utils::encode_cp1252;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+
+/// The number of 100-nanosecond intervals between the UUID epoch (1582-10-15) and the FILETIME epoch (1601-01-01).
+const UUID_EPOCH_TO_FILETIME_EPOCH: u64 = 0x146BF33E42C000;
+
+/// Returns the MAC address stored in the node field of a version 1 UUID.
+pub(crate) fn uuid_mac_address(guid: &Guid) -> String {
+    guid.to_bytes()[10..]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<String>>()
+        .join(":")
+}
+
+/// Returns the timestamp stored in a version 1 UUID, `None` if the UUID is not version 1.
+pub(crate) fn uuid_time(guid: &Guid) -> Option<FileTime> {
+    let bytes = guid.to_bytes();
+    let time_low = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) as u64;
+    let time_mid = u16::from_le_bytes([bytes[4], bytes[5]]) as u64;
+    let time_hi_and_version = u16::from_le_bytes([bytes[6], bytes[7]]) as u64;
+    if time_hi_and_version >> 12 != 1 {
+        return None;
+    }
+    let timestamp = ((time_hi_and_version & 0x0FFF) << 48) | (time_mid << 32) | time_low;
+    timestamp
+        .checked_sub(UUID_EPOCH_TO_FILETIME_EPOCH)
+        .map(FileTime::new)
+}
+
+/// [TrackerDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains the data used by the Distributed Link Tracking service to resolve the link target.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TrackerDataBlock {
+    #[serde(skip, default = "TrackerDataBlock::default_size")]
+    pub size: u32,
+    #[serde(skip, default = "TrackerDataBlock::default_signature")]
+    pub signature: u32,
+    pub length: u32,
+    pub version: u32,
+    /// NetBIOS name of the machine where the link target was last known to reside.
+    pub machine_id: String,
+    pub droid_volume_id: Guid,
+    pub droid_file_id: Guid,
+    pub birth_droid_volume_id: Guid,
+    pub birth_droid_file_id: Guid,
+    /// MAC address from the file droid, the droid is a version 1 UUID.
+    pub mac_address: String,
+    /// Creation time of the file droid.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub droid_file_time: Option<FileTime>,
+}
+
+impl TrackerDataBlock {
+    pub const SIGNATURE: u32 = 0xA0000003;
+    pub const SIZE: u32 = 0x60;
+
+    fn default_size() -> u32 {
+        Self::SIZE
+    }
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) =
+            read_block_header(r, "TrackerDataBlock", Self::SIGNATURE, Some(Self::SIZE))?;
+        let length = r.read_u32::<LittleEndian>()?;
+        let version = r.read_u32::<LittleEndian>()?;
+        let machine_id = read_fixed_cp1252_string(r, 16)?;
+        let droid_volume_id = Guid::from_reader(r)?;
+        let droid_file_id = Guid::from_reader(r)?;
+        let birth_droid_volume_id = Guid::from_reader(r)?;
+        let birth_droid_file_id = Guid::from_reader(r)?;
+        let mac_address = uuid_mac_address(&droid_file_id);
+        let droid_file_time = uuid_time(&droid_file_id);
+
+        Ok(Self {
+            size,
+            signature,
+            length,
+            version,
+            machine_id,
+            droid_volume_id,
+            droid_file_id,
+            birth_droid_volume_id,
+            birth_droid_file_id,
+            mac_address,
+            droid_file_time,
+        })
+    }
+
+    /// Returns the block in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&Self::SIZE.to_le_bytes());
+        bytes.extend_from_slice(&Self::SIGNATURE.to_le_bytes());
+        bytes.extend_from_slice(&0x58u32.to_le_bytes());
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        let mut machine_id = encode_cp1252(&self.machine_id);
+        machine_id.truncate(15);
+        machine_id.resize(16, 0);
+        bytes.extend_from_slice(&machine_id);
+        bytes.extend_from_slice(&self.droid_volume_id.to_bytes());
+        bytes.extend_from_slice(&self.droid_file_id.to_bytes());
+        bytes.extend_from_slice(&self.birth_droid_volume_id.to_bytes());
+        bytes.extend_from_slice(&self.birth_droid_file_id.to_bytes());
+        bytes
+    }
+}
@@ -0,0 +1,48 @@
+use super::read_block_header;
+use crate::structs::shell_items::IDList;
+use crate::traits::Path;
+use crate::ReaderError;
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+
+/// [VistaAndAboveIDListDataBlock](https://github.com/libyal/liblnk/blob/main/documentation/Windows%20Shortcut%20File%20(LNK)%20format.asciidoc) struct parser.
+/// Contains an alternate IDList that is used instead of the link target IDList on Windows Vista and later.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VistaAndAboveIDListDataBlock {
+    pub size: u32,
+    #[serde(skip, default = "VistaAndAboveIDListDataBlock::default_signature")]
+    pub signature: u32,
+    pub id_list: IDList,
+}
+
+impl VistaAndAboveIDListDataBlock {
+    pub const SIGNATURE: u32 = 0xA000000C;
+
+    fn default_signature() -> u32 {
+        Self::SIGNATURE
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let (size, signature) =
+            read_block_header(r, "VistaAndAboveIDListDataBlock", Self::SIGNATURE, None)?;
+        let mut id_list_data = vec![0; size.saturating_sub(8) as usize];
+        r.read_exact(&mut id_list_data)?;
+        let id_list = IDList::from_buffer(&id_list_data)?;
+
+        Ok(Self {
+            size,
+            signature,
+            id_list,
+        })
+    }
+}
+
+impl Path for VistaAndAboveIDListDataBlock {
+    fn path(&self) -> Option<String> {
+        self.id_list.path()
+    }
+}
@@ -3,27 +3,21 @@ use crate::file_system::FileReference;
 use crate::utils::{read_utf16_string, read_utf8_string};
 use crate::ReaderError;
 use byteorder::{LittleEndian, ReadBytesExt};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::io::{Cursor, Read};
 
 /// [ExtraDataBlock (BEEF0004)](https://github.com/libyal/libfwsi/blob/main/documentation/Windows%20Shell%20Item%20format.asciidoc#extension_block_0xbeef0004) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ExtraDataBlock {
-    #[serde(skip_serializing)]
     pub size: u16,
-    #[serde(skip_serializing)]
     pub version: u16,
-    #[serde(skip_serializing)]
     pub signature: [u8; 4],
     pub ctime: DosDateTime,
     pub atime: DosDateTime,
-    #[serde(skip_serializing)]
     pub identifier: u16,
     pub file_ref: Option<FileReference>,
-    #[serde(skip_serializing)]
     pub long_str_size: u16,
     pub primary_name: String,
-    #[serde(skip_serializing)]
     pub version_offset: Option<u16>,
 }
 
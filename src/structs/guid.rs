@@ -1,11 +1,12 @@
-use std::io::{Cursor, Read, Result};
+use std::io::{Cursor, Error, ErrorKind, Read, Result};
+use std::str::FromStr;
 use byteorder::{LittleEndian, ReadBytesExt};
 use std::fmt::{Result as FmtResult, Display, Formatter};
-use serde::ser;
+use serde::{de, ser};
 
 /// [GUID](https://docs.microsoft.com/en-us/windows/win32/api/guiddef/ns-guiddef-guid) struct parser.
 /// From [omerbenamram](https://github.com/omerbenamram/winstructs/blob/master/src/guid.rs)
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub struct Guid {
     data1: u32,
     data2: u16,
@@ -45,6 +46,16 @@ impl Guid {
             data4
         })
     }
+
+    /// Returns the GUID in its binary (little endian) form.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[0..4].copy_from_slice(&self.data1.to_le_bytes());
+        bytes[4..6].copy_from_slice(&self.data2.to_le_bytes());
+        bytes[6..8].copy_from_slice(&self.data3.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.data4);
+        bytes
+    }
 }
 
 impl Display for Guid {
@@ -75,4 +86,42 @@ impl ser::Serialize for Guid {
     {
         serializer.serialize_str(&self.to_string())
     }
-}
\ No newline at end of file
+}
+
+/// Parse a GUID string, with or without braces (e.g. `{20D04FE0-3AEA-1069-A2D8-08002B30309D}`).
+impl FromStr for Guid {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::new(ErrorKind::InvalidData, format!("Guid : Invalid GUID '{}'", s));
+        let guid = s.trim_start_matches('{').trim_end_matches('}');
+        let parts = guid.split('-').collect::<Vec<&str>>();
+        if parts.len() != 5
+            || parts.iter().map(|part| part.len()).ne([8, 4, 4, 4, 12].iter().copied())
+            || !guid.chars().all(|c| c == '-' || c.is_ascii_hexdigit())
+        {
+            return Err(invalid());
+        }
+        let data4 = format!("{}{}", parts[3], parts[4]);
+        let mut data4_bytes = [0; 8];
+        for (i, byte) in data4_bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&data4[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(Guid {
+            data1: u32::from_str_radix(parts[0], 16).map_err(|_| invalid())?,
+            data2: u16::from_str_radix(parts[1], 16).map_err(|_| invalid())?,
+            data3: u16::from_str_radix(parts[2], 16).map_err(|_| invalid())?,
+            data4: data4_bytes,
+        })
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Guid {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let guid = String::deserialize(deserializer)?;
+        Guid::from_str(&guid).map_err(de::Error::custom)
+    }
+}
@@ -1,12 +1,30 @@
 //! Windows common structs module.
+#[cfg(feature = "lnk")]
+pub mod extra_data;
 #[cfg(feature = "shell_items")]
 mod extra_data_block;
 mod guid;
+#[cfg(feature = "property_store")]
+mod prop_variant;
+#[cfg(feature = "property_store")]
+mod property_keys;
+#[cfg(feature = "property_store")]
+mod property_store;
 #[cfg(feature = "shell_items")]
 pub mod shell_items;
+#[cfg(feature = "lnk")]
+pub mod shell_link;
 mod string_data;
 
 #[cfg(feature = "shell_items")]
 pub use extra_data_block::ExtraDataBlock;
 pub use guid::Guid;
+#[cfg(feature = "property_store")]
+pub use prop_variant::{ArrayDimension, Decimal, PropVariant, PropVariantArray, PropVariantVector};
+#[cfg(feature = "property_store")]
+pub use property_keys::{property_key_name, register_property_key, PropertyKey};
+#[cfg(feature = "property_store")]
+pub use property_store::{
+    PropertyIdentifier, PropertyStore, SerializedPropertyStorage, SerializedPropertyValue,
+};
 pub use string_data::StringData;
@@ -0,0 +1,681 @@
+use crate::date_time::{FileTime, OleDate};
+use crate::structs::Guid;
+use crate::utils::{
+    deserialize_hex, encode_code_page, read_code_page_string, serialize_hex, CP_WINUNICODE,
+};
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Cursor, Error, ErrorKind, Read};
+
+/// Read the zero padding after a field of `size` bytes to align it to a multiple of 4 bytes.
+/// Missing padding at the end of the data is ignored.
+fn read_padding<R: Read>(r: &mut R, size: usize) -> Result<(), ReaderError> {
+    r.take(((4 - size % 4) % 4) as u64)
+        .read_to_end(&mut vec![])?;
+    Ok(())
+}
+
+/// Append zero padding to align `bytes` to a multiple of 4 bytes.
+fn write_padding(bytes: &mut Vec<u8>) {
+    bytes.resize(bytes.len().div_ceil(4) * 4, 0);
+}
+
+/// Returns the size of the fixed size types, `None` for variable size types.
+fn scalar_size(vt: u16) -> Option<usize> {
+    match vt {
+        PropVariant::VT_I1 | PropVariant::VT_UI1 => Some(1),
+        PropVariant::VT_I2 | PropVariant::VT_UI2 | PropVariant::VT_BOOL => Some(2),
+        PropVariant::VT_I4
+        | PropVariant::VT_R4
+        | PropVariant::VT_ERROR
+        | PropVariant::VT_UI4
+        | PropVariant::VT_INT
+        | PropVariant::VT_UINT => Some(4),
+        PropVariant::VT_R8
+        | PropVariant::VT_CY
+        | PropVariant::VT_DATE
+        | PropVariant::VT_I8
+        | PropVariant::VT_UI8
+        | PropVariant::VT_FILETIME => Some(8),
+        PropVariant::VT_DECIMAL | PropVariant::VT_CLSID => Some(16),
+        _ => None,
+    }
+}
+
+/// [DECIMAL](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleps) struct parser.
+/// Serialized as a decimal string (e.g. `-123.45`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decimal {
+    /// The number of digits after the decimal point (0 to 28).
+    pub scale: u8,
+    /// `0x80` if the value is negative.
+    pub sign: u8,
+    pub hi32: u32,
+    pub lo64: u64,
+}
+
+impl Decimal {
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        r.read_u16::<LittleEndian>()?; // Reserved
+        let scale = r.read_u8()?;
+        let sign = r.read_u8()?;
+        let hi32 = r.read_u32::<LittleEndian>()?;
+        let lo64 = r.read_u64::<LittleEndian>()?;
+        Ok(Self {
+            scale,
+            sign,
+            hi32,
+            lo64,
+        })
+    }
+
+    /// Returns the decimal in its binary form.
+    pub fn to_bytes(&self) -> [u8; 16] {
+        let mut bytes = [0; 16];
+        bytes[2] = self.scale;
+        bytes[3] = self.sign;
+        bytes[4..8].copy_from_slice(&self.hi32.to_le_bytes());
+        bytes[8..16].copy_from_slice(&self.lo64.to_le_bytes());
+        bytes
+    }
+}
+
+impl Display for Decimal {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        let digits = (((self.hi32 as u128) << 64) | self.lo64 as u128).to_string();
+        let scale = self.scale as usize;
+        let digits = format!("{:0>width$}", digits, width = scale + 1);
+        let (integer, fraction) = digits.split_at(digits.len() - scale);
+        if self.sign & 0x80 != 0 {
+            write!(f, "-")?;
+        }
+        match fraction.is_empty() {
+            true => write!(f, "{}", integer),
+            false => write!(f, "{}.{}", integer, fraction),
+        }
+    }
+}
+
+impl Serialize for Decimal {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for Decimal {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let decimal = String::deserialize(deserializer)?;
+        let invalid = || D::Error::custom(format!("invalid decimal '{}'", decimal));
+        let (sign, digits) = match decimal.strip_prefix('-') {
+            Some(digits) => (0x80, digits),
+            None => (0, decimal.as_str()),
+        };
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if integer.is_empty() || fraction.len() > 28 {
+            return Err(invalid());
+        }
+        let value = format!("{}{}", integer, fraction)
+            .parse::<u128>()
+            .map_err(|_| invalid())?;
+        // The value is a 96-bit integer
+        if value >> 96 != 0 {
+            return Err(invalid());
+        }
+        Ok(Self {
+            scale: fraction.len() as u8,
+            sign,
+            hi32: (value >> 64) as u32,
+            lo64: value as u64,
+        })
+    }
+}
+
+/// A `VT_VECTOR` value, a one dimensional list of values of the same type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropVariantVector {
+    /// The type of the elements, `VT_VARIANT` if every element has its own type.
+    pub element_type: u16,
+    pub values: Vec<PropVariant>,
+}
+
+/// The size and the index of the first element of an array dimension.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct ArrayDimension {
+    pub size: u32,
+    pub index_offset: i32,
+}
+
+/// A `VT_ARRAY` value, a multi dimensional array of values of the same type.
+/// The values are stored in the order they were written (the first dimension changes fastest).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PropVariantArray {
+    /// The type of the elements, `VT_VARIANT` if every element has its own type.
+    pub element_type: u16,
+    pub dimensions: Vec<ArrayDimension>,
+    pub values: Vec<PropVariant>,
+}
+
+/// [TypedPropertyValue](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-oleps/f122b9d7-e5cf-4484-8466-83f6fd94b3cc) parser
+/// covering the MS-OLEPS property types. `VT_LPSTR`, `VT_BSTR` and the indirect property names are decoded using the code page
+/// of the property set, property stores always use `CP_WINUNICODE`. Unknown types are kept as raw bytes.
+///
+/// Values are serialized with their type next to the value (e.g. `{"vt":"UI4","value":5}`), so deserializing gives the same type.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::structs::PropVariant;
+///
+/// let value = PropVariant::from_buffer(&[0x02, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0x00, 0x00]).unwrap();
+/// assert!(matches!(value, PropVariant::I2(-1)));
+/// assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"vt":"I2","value":-1}"#);
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "vt", content = "value")]
+pub enum PropVariant {
+    Empty,
+    Null,
+    I2(i16),
+    I4(i32),
+    R4(f32),
+    R8(f64),
+    /// Currency, the value multiplied by 10,000.
+    Cy(i64),
+    /// OLE Automation date.
+    Date(OleDate),
+    BStr(String),
+    /// HRESULT error code.
+    Error(u32),
+    Bool(bool),
+    Decimal(Decimal),
+    I1(i8),
+    UI1(u8),
+    UI2(u16),
+    UI4(u32),
+    I8(i64),
+    UI8(u64),
+    Int(i32),
+    UInt(u32),
+    LPStr(String),
+    LPWStr(String),
+    FileTime(FileTime),
+    Blob(#[serde(serialize_with = "serialize_hex", deserialize_with = "deserialize_hex")] Vec<u8>),
+    /// The name of the stream that contains the value.
+    Stream(String),
+    /// The name of the storage that contains the value.
+    Storage(String),
+    StreamedObject(String),
+    StoredObject(String),
+    BlobObject(#[serde(serialize_with = "serialize_hex", deserialize_with = "deserialize_hex")] Vec<u8>),
+    ClipboardData {
+        format: i32,
+        #[serde(serialize_with = "serialize_hex", deserialize_with = "deserialize_hex")]
+        data: Vec<u8>,
+    },
+    Clsid(Guid),
+    VersionedStream {
+        version_guid: Guid,
+        stream_name: String,
+    },
+    Vector(PropVariantVector),
+    Array(PropVariantArray),
+    Unknown {
+        vt: u16,
+        #[serde(serialize_with = "serialize_hex", deserialize_with = "deserialize_hex")]
+        data: Vec<u8>,
+    },
+}
+
+impl PropVariant {
+    pub const VT_EMPTY: u16 = 0x0000;
+    pub const VT_NULL: u16 = 0x0001;
+    pub const VT_I2: u16 = 0x0002;
+    pub const VT_I4: u16 = 0x0003;
+    pub const VT_R4: u16 = 0x0004;
+    pub const VT_R8: u16 = 0x0005;
+    pub const VT_CY: u16 = 0x0006;
+    pub const VT_DATE: u16 = 0x0007;
+    pub const VT_BSTR: u16 = 0x0008;
+    pub const VT_ERROR: u16 = 0x000A;
+    pub const VT_BOOL: u16 = 0x000B;
+    pub const VT_VARIANT: u16 = 0x000C;
+    pub const VT_DECIMAL: u16 = 0x000E;
+    pub const VT_I1: u16 = 0x0010;
+    pub const VT_UI1: u16 = 0x0011;
+    pub const VT_UI2: u16 = 0x0012;
+    pub const VT_UI4: u16 = 0x0013;
+    pub const VT_I8: u16 = 0x0014;
+    pub const VT_UI8: u16 = 0x0015;
+    pub const VT_INT: u16 = 0x0016;
+    pub const VT_UINT: u16 = 0x0017;
+    pub const VT_LPSTR: u16 = 0x001E;
+    pub const VT_LPWSTR: u16 = 0x001F;
+    pub const VT_FILETIME: u16 = 0x0040;
+    pub const VT_BLOB: u16 = 0x0041;
+    pub const VT_STREAM: u16 = 0x0042;
+    pub const VT_STORAGE: u16 = 0x0043;
+    pub const VT_STREAMED_OBJECT: u16 = 0x0044;
+    pub const VT_STORED_OBJECT: u16 = 0x0045;
+    pub const VT_BLOB_OBJECT: u16 = 0x0046;
+    pub const VT_CF: u16 = 0x0047;
+    pub const VT_CLSID: u16 = 0x0048;
+    pub const VT_VERSIONED_STREAM: u16 = 0x0049;
+    pub const VT_VECTOR: u16 = 0x1000;
+    pub const VT_ARRAY: u16 = 0x2000;
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    /// Read a value with the `CP_WINUNICODE` code page.
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        Self::from_reader_with_code_page(r, CP_WINUNICODE)
+    }
+
+    /// Read the type, the padding and the value. `code_page` is used to decode `VT_LPSTR`, `VT_BSTR` and indirect property names.
+    pub fn from_reader_with_code_page<R: Read>(
+        r: &mut R,
+        code_page: u16,
+    ) -> Result<Self, ReaderError> {
+        let vt = r.read_u16::<LittleEndian>()?;
+        r.read_u16::<LittleEndian>()?; // Padding
+        Self::read_value(r, vt, code_page)
+    }
+
+    /// Read the value of the type `vt` including its padding.
+    pub fn read_value<R: Read>(r: &mut R, vt: u16, code_page: u16) -> Result<Self, ReaderError> {
+        match vt & 0xF000 {
+            Self::VT_VECTOR => {
+                let element_type = vt & 0x0FFF;
+                let count = r.read_u32::<LittleEndian>()?;
+                let values = Self::read_elements(r, element_type, count as usize, code_page)?;
+                Ok(PropVariant::Vector(PropVariantVector {
+                    element_type,
+                    values,
+                }))
+            }
+            Self::VT_ARRAY => {
+                let element_type = r.read_u32::<LittleEndian>()? as u16;
+                if element_type != vt & 0x0FFF {
+                    return Err(ReaderError::from(Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "PropVariant : array type '{:#06X}' does not match the value type '{:#06X}'",
+                            element_type, vt
+                        ),
+                    )));
+                }
+                let number_of_dimensions = r.read_u32::<LittleEndian>()?;
+                if !(1..=31).contains(&number_of_dimensions) {
+                    return Err(ReaderError::from(Error::new(
+                        ErrorKind::InvalidData,
+                        format!(
+                            "PropVariant : invalid number of array dimensions '{}'",
+                            number_of_dimensions
+                        ),
+                    )));
+                }
+                let mut dimensions = vec![];
+                let mut count: usize = 1;
+                for _ in 0..number_of_dimensions {
+                    let size = r.read_u32::<LittleEndian>()?;
+                    let index_offset = r.read_i32::<LittleEndian>()?;
+                    count = count.checked_mul(size as usize).ok_or_else(|| {
+                        ReaderError::from(Error::new(
+                            ErrorKind::InvalidData,
+                            "PropVariant : the array is too big",
+                        ))
+                    })?;
+                    dimensions.push(ArrayDimension { size, index_offset });
+                }
+                let values = Self::read_elements(r, element_type, count, code_page)?;
+                Ok(PropVariant::Array(PropVariantArray {
+                    element_type,
+                    dimensions,
+                    values,
+                }))
+            }
+            _ => {
+                let value = Self::read_scalar(r, vt, code_page)?;
+                if let Some(size) = scalar_size(vt) {
+                    read_padding(r, size)?;
+                }
+                Ok(value)
+            }
+        }
+    }
+
+    /// Read `count` vector or array elements followed by the padding.
+    /// Every element takes at least one byte, so reading stops with an error at the end of the data
+    /// instead of trusting `count`. `VT_VARIANT` elements can't be vectors or arrays.
+    fn read_elements<R: Read>(
+        r: &mut R,
+        element_type: u16,
+        count: usize,
+        code_page: u16,
+    ) -> Result<Vec<Self>, ReaderError> {
+        let invalid_type = |vt: u16| {
+            ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!("PropVariant : invalid vector or array type '{:#06X}'", vt),
+            ))
+        };
+        let mut values = vec![];
+        for _ in 0..count {
+            let value = match element_type {
+                Self::VT_VARIANT => {
+                    let vt = r.read_u16::<LittleEndian>()?;
+                    r.read_u16::<LittleEndian>()?; // Padding
+                    if vt & 0xF000 != 0 {
+                        return Err(invalid_type(vt));
+                    }
+                    Self::read_value(r, vt, code_page)?
+                }
+                Self::VT_EMPTY | Self::VT_NULL => return Err(invalid_type(element_type)),
+                _ => Self::read_scalar(r, element_type, code_page)?,
+            };
+            // The size of unknown types is not known, they can't be elements
+            if let PropVariant::Unknown { vt, .. } = value {
+                return Err(invalid_type(vt));
+            }
+            values.push(value);
+        }
+        if let Some(size) = scalar_size(element_type) {
+            read_padding(r, size * count)?;
+        }
+        Ok(values)
+    }
+
+    /// Read a `CodePageString`, `VT_BSTR` and `VT_LPSTR` strings in Unicode property sets are UTF-16 strings.
+    fn read_code_page_string<R: Read>(r: &mut R, code_page: u16) -> Result<String, ReaderError> {
+        let size = r.read_u32::<LittleEndian>()? as usize;
+        let string = read_code_page_string(r, size, code_page)?;
+        read_padding(r, size)?;
+        Ok(string)
+    }
+
+    /// Read a size prefixed byte array.
+    fn read_blob<R: Read>(r: &mut R) -> Result<Vec<u8>, ReaderError> {
+        let size = r.read_u32::<LittleEndian>()? as usize;
+        let mut data = vec![];
+        r.take(size as u64).read_to_end(&mut data)?;
+        if data.len() < size {
+            return Err(ReaderError::from(Error::from(ErrorKind::UnexpectedEof)));
+        }
+        read_padding(r, size)?;
+        Ok(data)
+    }
+
+    /// Read a value without the padding of fixed size types.
+    fn read_scalar<R: Read>(r: &mut R, vt: u16, code_page: u16) -> Result<Self, ReaderError> {
+        let value = match vt {
+            Self::VT_EMPTY => PropVariant::Empty,
+            Self::VT_NULL => PropVariant::Null,
+            Self::VT_I2 => PropVariant::I2(r.read_i16::<LittleEndian>()?),
+            Self::VT_I4 => PropVariant::I4(r.read_i32::<LittleEndian>()?),
+            Self::VT_R4 => PropVariant::R4(r.read_f32::<LittleEndian>()?),
+            Self::VT_R8 => PropVariant::R8(r.read_f64::<LittleEndian>()?),
+            Self::VT_CY => PropVariant::Cy(r.read_i64::<LittleEndian>()?),
+            Self::VT_DATE => PropVariant::Date(OleDate::from_reader(r)?),
+            Self::VT_BSTR => PropVariant::BStr(Self::read_code_page_string(r, code_page)?),
+            Self::VT_ERROR => PropVariant::Error(r.read_u32::<LittleEndian>()?),
+            Self::VT_BOOL => PropVariant::Bool(r.read_u16::<LittleEndian>()? != 0),
+            Self::VT_DECIMAL => PropVariant::Decimal(Decimal::from_reader(r)?),
+            Self::VT_I1 => PropVariant::I1(r.read_i8()?),
+            Self::VT_UI1 => PropVariant::UI1(r.read_u8()?),
+            Self::VT_UI2 => PropVariant::UI2(r.read_u16::<LittleEndian>()?),
+            Self::VT_UI4 => PropVariant::UI4(r.read_u32::<LittleEndian>()?),
+            Self::VT_I8 => PropVariant::I8(r.read_i64::<LittleEndian>()?),
+            Self::VT_UI8 => PropVariant::UI8(r.read_u64::<LittleEndian>()?),
+            Self::VT_INT => PropVariant::Int(r.read_i32::<LittleEndian>()?),
+            Self::VT_UINT => PropVariant::UInt(r.read_u32::<LittleEndian>()?),
+            Self::VT_LPSTR => PropVariant::LPStr(Self::read_code_page_string(r, code_page)?),
+            Self::VT_LPWSTR => {
+                let length = r.read_u32::<LittleEndian>()? as usize;
+                let size = length.checked_mul(2).ok_or_else(|| {
+                    ReaderError::from(Error::new(
+                        ErrorKind::InvalidData,
+                        "PropVariant : the string is too big",
+                    ))
+                })?;
+                let string = read_code_page_string(r, size, CP_WINUNICODE)?;
+                read_padding(r, size)?;
+                PropVariant::LPWStr(string)
+            }
+            Self::VT_FILETIME => {
+                PropVariant::FileTime(FileTime::new(r.read_u64::<LittleEndian>()?))
+            }
+            Self::VT_BLOB => PropVariant::Blob(Self::read_blob(r)?),
+            Self::VT_STREAM => PropVariant::Stream(Self::read_code_page_string(r, code_page)?),
+            Self::VT_STORAGE => PropVariant::Storage(Self::read_code_page_string(r, code_page)?),
+            Self::VT_STREAMED_OBJECT => {
+                PropVariant::StreamedObject(Self::read_code_page_string(r, code_page)?)
+            }
+            Self::VT_STORED_OBJECT => {
+                PropVariant::StoredObject(Self::read_code_page_string(r, code_page)?)
+            }
+            Self::VT_BLOB_OBJECT => PropVariant::BlobObject(Self::read_blob(r)?),
+            Self::VT_CF => {
+                let size = r.read_u32::<LittleEndian>()? as usize;
+                if size < 4 {
+                    return Err(ReaderError::from(Error::new(
+                        ErrorKind::InvalidData,
+                        format!("PropVariant : invalid clipboard data size '{}'", size),
+                    )));
+                }
+                let format = r.read_i32::<LittleEndian>()?;
+                let mut data = vec![];
+                r.take(size as u64 - 4).read_to_end(&mut data)?;
+                if data.len() < size - 4 {
+                    return Err(ReaderError::from(Error::from(ErrorKind::UnexpectedEof)));
+                }
+                read_padding(r, size)?;
+                PropVariant::ClipboardData { format, data }
+            }
+            Self::VT_CLSID => PropVariant::Clsid(Guid::from_reader(r)?),
+            Self::VT_VERSIONED_STREAM => PropVariant::VersionedStream {
+                version_guid: Guid::from_reader(r)?,
+                stream_name: Self::read_code_page_string(r, code_page)?,
+            },
+            _ => {
+                let mut data = vec![];
+                r.read_to_end(&mut data)?;
+                PropVariant::Unknown { vt, data }
+            }
+        };
+        Ok(value)
+    }
+
+    /// Returns the type of the value (e.g. `VT_LPWSTR` or `VT_VECTOR | VT_I4`).
+    pub fn vt(&self) -> u16 {
+        match self {
+            PropVariant::Empty => Self::VT_EMPTY,
+            PropVariant::Null => Self::VT_NULL,
+            PropVariant::I2(_) => Self::VT_I2,
+            PropVariant::I4(_) => Self::VT_I4,
+            PropVariant::R4(_) => Self::VT_R4,
+            PropVariant::R8(_) => Self::VT_R8,
+            PropVariant::Cy(_) => Self::VT_CY,
+            PropVariant::Date(_) => Self::VT_DATE,
+            PropVariant::BStr(_) => Self::VT_BSTR,
+            PropVariant::Error(_) => Self::VT_ERROR,
+            PropVariant::Bool(_) => Self::VT_BOOL,
+            PropVariant::Decimal(_) => Self::VT_DECIMAL,
+            PropVariant::I1(_) => Self::VT_I1,
+            PropVariant::UI1(_) => Self::VT_UI1,
+            PropVariant::UI2(_) => Self::VT_UI2,
+            PropVariant::UI4(_) => Self::VT_UI4,
+            PropVariant::I8(_) => Self::VT_I8,
+            PropVariant::UI8(_) => Self::VT_UI8,
+            PropVariant::Int(_) => Self::VT_INT,
+            PropVariant::UInt(_) => Self::VT_UINT,
+            PropVariant::LPStr(_) => Self::VT_LPSTR,
+            PropVariant::LPWStr(_) => Self::VT_LPWSTR,
+            PropVariant::FileTime(_) => Self::VT_FILETIME,
+            PropVariant::Blob(_) => Self::VT_BLOB,
+            PropVariant::Stream(_) => Self::VT_STREAM,
+            PropVariant::Storage(_) => Self::VT_STORAGE,
+            PropVariant::StreamedObject(_) => Self::VT_STREAMED_OBJECT,
+            PropVariant::StoredObject(_) => Self::VT_STORED_OBJECT,
+            PropVariant::BlobObject(_) => Self::VT_BLOB_OBJECT,
+            PropVariant::ClipboardData { .. } => Self::VT_CF,
+            PropVariant::Clsid(_) => Self::VT_CLSID,
+            PropVariant::VersionedStream { .. } => Self::VT_VERSIONED_STREAM,
+            PropVariant::Vector(vector) => Self::VT_VECTOR | vector.element_type,
+            PropVariant::Array(array) => Self::VT_ARRAY | array.element_type,
+            PropVariant::Unknown { vt, .. } => *vt,
+        }
+    }
+
+    /// Returns the string value of `VT_LPWSTR`, `VT_LPSTR` and `VT_BSTR` values.
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            PropVariant::LPWStr(s) | PropVariant::LPStr(s) | PropVariant::BStr(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns the value in its binary form with the `CP_WINUNICODE` code page.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.to_bytes_with_code_page(CP_WINUNICODE)
+    }
+
+    /// Returns the type, the padding and the value in its binary form, strings are encoded using `code_page`.
+    pub fn to_bytes_with_code_page(&self, code_page: u16) -> Vec<u8> {
+        let mut bytes = self.vt().to_le_bytes().to_vec();
+        bytes.extend_from_slice(&[0; 2]);
+        bytes.extend_from_slice(&self.value_to_bytes(code_page));
+        bytes
+    }
+
+    /// Returns the value without the type including its padding.
+    pub fn value_to_bytes(&self, code_page: u16) -> Vec<u8> {
+        let mut bytes = match self {
+            PropVariant::Vector(vector) => {
+                let mut bytes = (vector.values.len() as u32).to_le_bytes().to_vec();
+                bytes.extend(Self::elements_to_bytes(
+                    &vector.values,
+                    vector.element_type,
+                    code_page,
+                ));
+                bytes
+            }
+            PropVariant::Array(array) => {
+                let mut bytes = (array.element_type as u32).to_le_bytes().to_vec();
+                bytes.extend_from_slice(&(array.dimensions.len() as u32).to_le_bytes());
+                for dimension in &array.dimensions {
+                    bytes.extend_from_slice(&dimension.size.to_le_bytes());
+                    bytes.extend_from_slice(&dimension.index_offset.to_le_bytes());
+                }
+                bytes.extend(Self::elements_to_bytes(
+                    &array.values,
+                    array.element_type,
+                    code_page,
+                ));
+                bytes
+            }
+            _ => self.scalar_to_bytes(code_page),
+        };
+        write_padding(&mut bytes);
+        bytes
+    }
+
+    fn elements_to_bytes(values: &[Self], element_type: u16, code_page: u16) -> Vec<u8> {
+        values
+            .iter()
+            .flat_map(|value| match element_type {
+                Self::VT_VARIANT => value.to_bytes_with_code_page(code_page),
+                _ => value.scalar_to_bytes(code_page),
+            })
+            .collect()
+    }
+
+    /// Returns a `CodePageString` with a NUL terminator and padding.
+    fn code_page_string_to_bytes(string: &str, code_page: u16) -> Vec<u8> {
+        let mut characters = encode_code_page(string, code_page);
+        characters.extend_from_slice(match code_page {
+            1200 | 1201 => &[0, 0],
+            _ => &[0],
+        });
+        let mut bytes = (characters.len() as u32).to_le_bytes().to_vec();
+        bytes.extend(characters);
+        write_padding(&mut bytes);
+        bytes
+    }
+
+    fn blob_to_bytes(data: &[u8]) -> Vec<u8> {
+        let mut bytes = (data.len() as u32).to_le_bytes().to_vec();
+        bytes.extend_from_slice(data);
+        write_padding(&mut bytes);
+        bytes
+    }
+
+    /// Returns the value without the padding of fixed size types.
+    fn scalar_to_bytes(&self, code_page: u16) -> Vec<u8> {
+        match self {
+            PropVariant::Empty | PropVariant::Null => vec![],
+            PropVariant::I2(v) => v.to_le_bytes().to_vec(),
+            PropVariant::I4(v) | PropVariant::Int(v) => v.to_le_bytes().to_vec(),
+            PropVariant::R4(v) => v.to_le_bytes().to_vec(),
+            PropVariant::R8(v) => v.to_le_bytes().to_vec(),
+            PropVariant::Date(v) => v.to_bytes().to_vec(),
+            PropVariant::Cy(v) | PropVariant::I8(v) => v.to_le_bytes().to_vec(),
+            PropVariant::Error(v) | PropVariant::UI4(v) | PropVariant::UInt(v) => {
+                v.to_le_bytes().to_vec()
+            }
+            PropVariant::Bool(v) => (if *v { 0xFFFFu16 } else { 0 }).to_le_bytes().to_vec(),
+            PropVariant::Decimal(v) => v.to_bytes().to_vec(),
+            PropVariant::I1(v) => v.to_le_bytes().to_vec(),
+            PropVariant::UI1(v) => v.to_le_bytes().to_vec(),
+            PropVariant::UI2(v) => v.to_le_bytes().to_vec(),
+            PropVariant::UI8(v) => v.to_le_bytes().to_vec(),
+            PropVariant::BStr(v)
+            | PropVariant::LPStr(v)
+            | PropVariant::Stream(v)
+            | PropVariant::Storage(v)
+            | PropVariant::StreamedObject(v)
+            | PropVariant::StoredObject(v) => Self::code_page_string_to_bytes(v, code_page),
+            PropVariant::LPWStr(v) => {
+                let chars = v
+                    .encode_utf16()
+                    .chain(std::iter::once(0))
+                    .collect::<Vec<u16>>();
+                let mut bytes = (chars.len() as u32).to_le_bytes().to_vec();
+                chars
+                    .iter()
+                    .for_each(|c| bytes.extend_from_slice(&c.to_le_bytes()));
+                write_padding(&mut bytes);
+                bytes
+            }
+            PropVariant::FileTime(v) => v.to_bytes().to_vec(),
+            PropVariant::Blob(data) | PropVariant::BlobObject(data) => Self::blob_to_bytes(data),
+            PropVariant::ClipboardData { format, data } => {
+                let mut bytes = (data.len() as u32 + 4).to_le_bytes().to_vec();
+                bytes.extend_from_slice(&format.to_le_bytes());
+                bytes.extend_from_slice(data);
+                write_padding(&mut bytes);
+                bytes
+            }
+            PropVariant::Clsid(v) => v.to_bytes().to_vec(),
+            PropVariant::VersionedStream {
+                version_guid,
+                stream_name,
+            } => {
+                let mut bytes = version_guid.to_bytes().to_vec();
+                bytes.extend(Self::code_page_string_to_bytes(stream_name, code_page));
+                bytes
+            }
+            PropVariant::Vector(_) | PropVariant::Array(_) => self.value_to_bytes(code_page),
+            PropVariant::Unknown { data, .. } => data.clone(),
+        }
+    }
+}
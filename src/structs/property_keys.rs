@@ -0,0 +1,223 @@
+use crate::structs::Guid;
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::str::FromStr;
+use std::sync::RwLock;
+
+/// Canonical names of well-known property keys (format ID, property ID, name), sorted by format ID and property ID.
+/// From `propkey.h` in the Windows SDK.
+#[rustfmt::skip]
+const KNOWN_PROPERTY_KEYS: [(&str, u32, &str); 93] = [
+    ("0CEF7D53-FA64-11D1-A203-0000F81FEDEE", 3, "System.FileDescription"),
+    ("0CEF7D53-FA64-11D1-A203-0000F81FEDEE", 4, "System.FileVersion"),
+    ("14B81DA1-0135-4D31-96D9-6CBFC9671A99", 18258, "System.DateImported"),
+    ("14B81DA1-0135-4D31-96D9-6CBFC9671A99", 36867, "System.Photo.DateTaken"),
+    ("1E3EE840-BC2B-476C-8237-2ACD1A839B22", 3, "System.Kind"),
+    ("28636AA6-953D-11D2-B5D6-00C04FD918D0", 2, "System.DescriptionID"),
+    ("28636AA6-953D-11D2-B5D6-00C04FD918D0", 5, "System.ComputerName"),
+    ("28636AA6-953D-11D2-B5D6-00C04FD918D0", 6, "System.NamespaceCLSID"),
+    ("28636AA6-953D-11D2-B5D6-00C04FD918D0", 11, "System.ItemType"),
+    ("28636AA6-953D-11D2-B5D6-00C04FD918D0", 24, "System.ParsingName"),
+    ("28636AA6-953D-11D2-B5D6-00C04FD918D0", 25, "System.SFGAOFlags"),
+    ("28636AA6-953D-11D2-B5D6-00C04FD918D0", 30, "System.ParsingPath"),
+    ("41CF5AE0-F75A-4806-BD87-59C7D9248EB9", 100, "System.FileName"),
+    ("436F2667-14E2-4FEB-B30A-146C53B5B674", 100, "System.Link.Arguments"),
+    ("446D16B1-8DAD-4870-A748-402EA43D788C", 100, "System.ThumbnailCacheId"),
+    ("446D16B1-8DAD-4870-A748-402EA43D788C", 104, "System.VolumeId"),
+    ("49691C90-7E17-101A-A91C-08002B2ECDA9", 9, "System.ItemUrl"),
+    ("5CBF2787-48CF-4208-B90E-EE5E5D420294", 2, "System.Link.TargetUrl"),
+    ("5CBF2787-48CF-4208-B90E-EE5E5D420294", 21, "System.Link.Description"),
+    ("5CBF2787-48CF-4208-B90E-EE5E5D420294", 23, "System.Link.DateVisited"),
+    ("6444048F-4C8B-11D1-8B70-080036B11A03", 13, "System.Image.Dimensions"),
+    ("64440492-4C8B-11D1-8B70-080036B11A03", 9, "System.Rating"),
+    ("64440492-4C8B-11D1-8B70-080036B11A03", 11, "System.Copyright"),
+    ("6B8DA074-3B5C-43BC-886F-0A2CDCE00B6F", 100, "System.ItemName"),
+    ("7A7D76F4-B630-4BD7-95FF-37CC51A975C9", 2, "System.Link.TargetExtension"),
+    ("9B174B34-40FF-11D2-A27E-00C04FC30871", 4, "System.FileOwner"),
+    ("9B174B35-40FF-11D2-A27E-00C04FC30871", 2, "System.FreeSpace"),
+    ("9B174B35-40FF-11D2-A27E-00C04FC30871", 3, "System.Capacity"),
+    ("9B174B35-40FF-11D2-A27E-00C04FC30871", 4, "System.Volume.FileSystem"),
+    ("9B174B35-40FF-11D2-A27E-00C04FC30871", 10, "System.Volume.IsRoot"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 2, "System.AppUserModel.RelaunchCommand"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 3, "System.AppUserModel.RelaunchIconResource"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 4, "System.AppUserModel.RelaunchDisplayNameResource"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 5, "System.AppUserModel.ID"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 6, "System.AppUserModel.IsDestListSeparator"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 8, "System.AppUserModel.ExcludeFromShowInNewInstall"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 9, "System.AppUserModel.PreventPinning"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 11, "System.AppUserModel.IsDualMode"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 12, "System.AppUserModel.StartPinOption"),
+    ("9F4C2855-9F79-4B39-A8D0-E1D42DE1D5F3", 26, "System.AppUserModel.ToastActivatorCLSID"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 2, "System.ItemFolderNameDisplay"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 4, "System.ItemTypeText"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 10, "System.ItemNameDisplay"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 12, "System.Size"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 13, "System.FileAttributes"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 14, "System.DateModified"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 15, "System.DateCreated"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 16, "System.DateAccessed"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 19, "System.Search.Contents"),
+    ("B725F130-47EF-101A-A5F1-02608C9EEBAC", 21, "System.FileFRN"),
+    ("B9B4B3FC-2B51-4A42-B5D8-324146AFCF25", 2, "System.Link.TargetParsingPath"),
+    ("B9B4B3FC-2B51-4A42-B5D8-324146AFCF25", 3, "System.Link.Status"),
+    ("B9B4B3FC-2B51-4A42-B5D8-324146AFCF25", 5, "System.Link.Comment"),
+    ("B9B4B3FC-2B51-4A42-B5D8-324146AFCF25", 8, "System.Link.TargetSFGAOFlags"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 2, "System.Category"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 3, "System.Document.PresentationFormat"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 4, "System.Document.ByteCount"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 5, "System.Document.LineCount"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 6, "System.Document.ParagraphCount"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 7, "System.Document.SlideCount"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 8, "System.Document.NoteCount"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 9, "System.Document.HiddenSlideCount"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 10, "System.Document.MultimediaClipCount"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 14, "System.Document.Manager"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 15, "System.Company"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 26, "System.ContentType"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 27, "System.ContentStatus"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 28, "System.Language"),
+    ("D5CDD502-2E9C-101B-9397-08002B2CF9AE", 29, "System.Document.Version"),
+    ("DABD30ED-0043-4789-A7F8-D013A4736622", 100, "System.ItemFolderPathDisplayNarrow"),
+    ("E3E0584C-B788-4A5A-BB20-7F5A44C9ACDD", 6, "System.ItemFolderPathDisplay"),
+    ("E3E0584C-B788-4A5A-BB20-7F5A44C9ACDD", 7, "System.ItemPathDisplay"),
+    ("E4F10A3C-49E6-405D-8288-A23BD4EEAA6C", 100, "System.FileExtension"),
+    ("F04BEF95-C585-4197-A2B7-DF46FDC9EE6D", 100, "System.KindText"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 2, "System.Title"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 3, "System.Subject"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 4, "System.Author"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 5, "System.Keywords"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 6, "System.Comment"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 7, "System.Document.Template"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 8, "System.Document.LastAuthor"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 9, "System.Document.RevisionNumber"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 10, "System.Document.TotalEditingTime"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 11, "System.Document.DatePrinted"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 12, "System.Document.DateCreated"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 13, "System.Document.DateSaved"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 14, "System.Document.PageCount"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 15, "System.Document.WordCount"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 16, "System.Document.CharacterCount"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 17, "System.Thumbnail"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 18, "System.ApplicationName"),
+    ("F29F85E0-4FF9-1068-AB91-08002B27B3D9", 19, "System.Document.Security"),
+    ("F7DB74B4-4287-4103-AFBA-F1B13DCD75CF", 100, "System.ItemDate"),
+];
+
+/// Property keys registered with `register_property_key`.
+static USER_PROPERTY_KEYS: RwLock<BTreeMap<(String, u32), String>> = RwLock::new(BTreeMap::new());
+
+/// Normalize a format ID to the uppercase form without braces.
+fn normalize_format_id(format_id: &str) -> String {
+    format_id
+        .trim_start_matches('{')
+        .trim_end_matches('}')
+        .to_uppercase()
+}
+
+/// Returns the canonical name of the property key (e.g. `System.ItemNameDisplay`). Keys registered with
+/// `register_property_key` take precedence over the built-in keys. The format ID is case insensitive and can be enclosed in braces.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::structs::property_key_name;
+///
+/// assert_eq!(
+///     property_key_name("{B725F130-47EF-101A-A5F1-02608C9EEBAC}", 10).as_deref(),
+///     Some("System.ItemNameDisplay")
+/// );
+/// ```
+pub fn property_key_name(format_id: &str, id: u32) -> Option<String> {
+    let format_id = normalize_format_id(format_id);
+    if let Ok(user_keys) = USER_PROPERTY_KEYS.read() {
+        if let Some(name) = user_keys.get(&(format_id.clone(), id)) {
+            return Some(name.clone());
+        }
+    }
+    KNOWN_PROPERTY_KEYS
+        .binary_search_by(|(key_format_id, key_id, _)| {
+            (*key_format_id, *key_id).cmp(&(format_id.as_str(), id))
+        })
+        .ok()
+        .map(|index| KNOWN_PROPERTY_KEYS[index].2.to_string())
+}
+
+/// Add or replace the name of a property key, used for application specific properties.
+pub fn register_property_key(format_id: &str, id: u32, name: &str) {
+    if let Ok(mut user_keys) = USER_PROPERTY_KEYS.write() {
+        user_keys.insert((normalize_format_id(format_id), id), name.to_string());
+    }
+}
+
+/// [PROPERTYKEY](https://learn.microsoft.com/en-us/windows/win32/api/wtypes/ns-wtypes-propertykey) struct, a format ID and a property ID.
+/// Serialized as the canonical name if it is known (e.g. `System.DateModified`), otherwise as `{FMTID}/PID`.
+#[derive(Debug, Clone)]
+pub struct PropertyKey {
+    pub format_id: Guid,
+    pub id: u32,
+}
+
+impl PropertyKey {
+    pub fn new(format_id: Guid, id: u32) -> Self {
+        Self { format_id, id }
+    }
+
+    /// Returns the canonical name of the property key, see `property_key_name`.
+    pub fn name(&self) -> Option<String> {
+        property_key_name(&self.format_id.to_string(), self.id)
+    }
+}
+
+impl Display for PropertyKey {
+    fn fmt(&self, f: &mut Formatter) -> FmtResult {
+        match self.name() {
+            Some(name) => write!(f, "{}", name),
+            None => write!(f, "{{{}}}/{}", self.format_id, self.id),
+        }
+    }
+}
+
+impl Serialize for PropertyKey {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+/// Returns the property key (format ID, property ID) of a canonical name, the reverse of `property_key_name`.
+fn property_key_by_name(name: &str) -> Option<(String, u32)> {
+    if let Ok(user_keys) = USER_PROPERTY_KEYS.read() {
+        if let Some((key, _)) = user_keys.iter().find(|(_, key_name)| *key_name == name) {
+            return Some(key.clone());
+        }
+    }
+    KNOWN_PROPERTY_KEYS
+        .iter()
+        .find(|(_, _, key_name)| *key_name == name)
+        .map(|(format_id, id, _)| (format_id.to_string(), *id))
+}
+
+impl<'de> Deserialize<'de> for PropertyKey {
+    /// Deserialize a canonical name (e.g. `System.DateModified`) or `{FMTID}/PID`.
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let key = String::deserialize(deserializer)?;
+        let (format_id, id) = match key.rsplit_once('/') {
+            Some((format_id, id)) => (
+                format_id.to_string(),
+                id.parse().map_err(D::Error::custom)?,
+            ),
+            None => property_key_by_name(&key)
+                .ok_or_else(|| D::Error::custom(format!("unknown property key '{}'", key)))?,
+        };
+        Ok(Self {
+            format_id: Guid::from_str(&format_id).map_err(D::Error::custom)?,
+            id,
+        })
+    }
+}
@@ -0,0 +1,297 @@
+use crate::structs::{property_key_name, Guid, PropVariant};
+use crate::utils::read_utf16_string;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::io::{Cursor, Error, ErrorKind, Read};
+
+/// The format ID of property storages that use string names instead of integer IDs.
+const STRING_NAME_FORMAT_ID: &str = "D5CDD505-2E9C-101B-9397-08002B2CF9AE";
+/// `1SPS`
+const STORAGE_VERSION: u32 = 0x53505331;
+
+/// The identifier of a property, an integer ID or a string name.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum PropertyIdentifier {
+    Id(u32),
+    Name(String),
+}
+
+/// Serialized property value, a property identifier and its typed value.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SerializedPropertyValue {
+    pub identifier: PropertyIdentifier,
+    pub value: PropVariant,
+}
+
+/// [Serialized Property Storage](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-propstore/3453fb82-0e4f-4c2c-bc04-64b4bd2c51ec) parser.
+/// The values are serialized with the canonical name of their property key when it is known, see `property_key_name`.
+#[derive(Debug)]
+pub struct SerializedPropertyStorage {
+    pub size: u32,
+    pub version: u32,
+    pub format_id: Guid,
+    pub values: Vec<SerializedPropertyValue>,
+}
+
+impl SerializedPropertyStorage {
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let size = r.read_u32::<LittleEndian>()?;
+        let version = r.read_u32::<LittleEndian>()?;
+        if version != STORAGE_VERSION {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "SerializedPropertyStorage : expected version '{:#010X}' found '{:#010X}'",
+                    STORAGE_VERSION, version
+                ),
+            )));
+        }
+        let format_id = Guid::from_reader(r)?;
+        let is_string_name = format_id.to_string() == STRING_NAME_FORMAT_ID;
+        let mut values = vec![];
+        loop {
+            let value_size = r.read_u32::<LittleEndian>()?;
+            if value_size == 0 {
+                break;
+            }
+            let value_data_size = value_size.saturating_sub(4) as u64;
+            let mut value_data = vec![];
+            r.take(value_data_size).read_to_end(&mut value_data)?;
+            if (value_data.len() as u64) < value_data_size {
+                return Err(ReaderError::from(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "SerializedPropertyStorage : the value size is larger than the remaining data",
+                )));
+            }
+            let mut value_reader = Cursor::new(value_data);
+            let identifier = match is_string_name {
+                true => {
+                    let name_size = value_reader.read_u32::<LittleEndian>()?;
+                    value_reader.read_u8()?; // Reserved
+                    PropertyIdentifier::Name(read_utf16_string(
+                        &mut value_reader,
+                        Some(name_size as usize / 2),
+                    )?)
+                }
+                false => {
+                    let id = value_reader.read_u32::<LittleEndian>()?;
+                    value_reader.read_u8()?; // Reserved
+                    PropertyIdentifier::Id(id)
+                }
+            };
+            let value = PropVariant::from_reader(&mut value_reader)?;
+            values.push(SerializedPropertyValue { identifier, value });
+        }
+
+        Ok(Self {
+            size,
+            version,
+            format_id,
+            values,
+        })
+    }
+
+    /// Returns the property storage in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut values = vec![];
+        for value in &self.values {
+            let mut value_bytes = vec![];
+            match &value.identifier {
+                PropertyIdentifier::Id(id) => {
+                    value_bytes.extend_from_slice(&id.to_le_bytes());
+                    value_bytes.push(0);
+                }
+                PropertyIdentifier::Name(name) => {
+                    let name = name
+                        .encode_utf16()
+                        .chain(std::iter::once(0))
+                        .flat_map(|c| c.to_le_bytes())
+                        .collect::<Vec<u8>>();
+                    value_bytes.extend_from_slice(&(name.len() as u32).to_le_bytes());
+                    value_bytes.push(0);
+                    value_bytes.extend_from_slice(&name);
+                }
+            }
+            value_bytes.extend_from_slice(&value.value.to_bytes());
+            values.extend_from_slice(&(value_bytes.len() as u32 + 4).to_le_bytes());
+            values.extend_from_slice(&value_bytes);
+        }
+        values.extend_from_slice(&[0; 4]);
+
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&(values.len() as u32 + 24).to_le_bytes());
+        bytes.extend_from_slice(&STORAGE_VERSION.to_le_bytes());
+        bytes.extend_from_slice(&self.format_id.to_bytes());
+        bytes.extend_from_slice(&values);
+        bytes
+    }
+}
+
+/// A serialized property value with the canonical name of its property key.
+#[derive(Serialize)]
+struct NamedPropertyValue<'a> {
+    identifier: &'a PropertyIdentifier,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    value: &'a PropVariant,
+}
+
+impl Serialize for SerializedPropertyStorage {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let format_id = self.format_id.to_string();
+        let values = self
+            .values
+            .iter()
+            .map(|value| NamedPropertyValue {
+                identifier: &value.identifier,
+                name: match value.identifier {
+                    PropertyIdentifier::Id(id) => property_key_name(&format_id, id),
+                    PropertyIdentifier::Name(_) => None,
+                },
+                value: &value.value,
+            })
+            .collect::<Vec<NamedPropertyValue>>();
+        let mut state = serializer.serialize_struct("SerializedPropertyStorage", 2)?;
+        state.serialize_field("format_id", &self.format_id)?;
+        state.serialize_field("values", &values)?;
+        state.end()
+    }
+}
+
+/// The serialized form of `SerializedPropertyStorage`, the names of the values are ignored.
+#[derive(Deserialize)]
+struct DeserializedPropertyStorage {
+    format_id: Guid,
+    values: Vec<SerializedPropertyValue>,
+}
+
+impl<'de> Deserialize<'de> for SerializedPropertyStorage {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let storage = DeserializedPropertyStorage::deserialize(deserializer)?;
+        let mut storage = Self {
+            size: 0,
+            version: STORAGE_VERSION,
+            format_id: storage.format_id,
+            values: storage.values,
+        };
+        storage.size = storage.to_bytes().len() as u32;
+        Ok(storage)
+    }
+}
+
+/// [Serialized Property Store](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-propstore) parser.
+/// Property stores are found in LNK files, shell items extension blocks and the registry.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::structs::PropertyStore;
+///
+/// let property_store = PropertyStore::from_buffer(&[0, 0, 0, 0]).unwrap();
+/// assert_eq!(property_store.entries().count(), 0);
+/// ```
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct PropertyStore {
+    pub storages: Vec<SerializedPropertyStorage>,
+}
+
+impl PropertyStore {
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        let mut r = Cursor::new(buf);
+        let mut storages = vec![];
+        while (r.position() as usize) + 4 <= buf.len() {
+            let storage_size = r.read_u32::<LittleEndian>()?;
+            if storage_size == 0 {
+                break;
+            }
+            let start = r.position() as usize - 4;
+            let end = start + storage_size as usize;
+            if end > buf.len() {
+                return Err(ReaderError::from(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "PropertyStore : property storage is bigger than the property store",
+                )));
+            }
+            storages.push(SerializedPropertyStorage::from_buffer(&buf[start..end])?);
+            r.set_position(end as u64);
+        }
+        Ok(Self { storages })
+    }
+
+    /// Read the property storages until the terminal storage (a size of `0`).
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let mut storages = vec![];
+        loop {
+            let storage_size = r.read_u32::<LittleEndian>()?;
+            if storage_size == 0 {
+                break;
+            }
+            let mut storage_data = storage_size.to_le_bytes().to_vec();
+            r.take(storage_size.saturating_sub(4) as u64)
+                .read_to_end(&mut storage_data)?;
+            if (storage_data.len() as u64) < storage_size as u64 {
+                return Err(ReaderError::from(Error::new(
+                    ErrorKind::UnexpectedEof,
+                    "PropertyStore : property storage is bigger than the property store",
+                )));
+            }
+            storages.push(SerializedPropertyStorage::from_buffer(&storage_data)?);
+        }
+        Ok(Self { storages })
+    }
+
+    /// Returns the property store in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self
+            .storages
+            .iter()
+            .flat_map(|storage| storage.to_bytes())
+            .collect::<Vec<u8>>();
+        bytes.extend_from_slice(&[0; 4]);
+        bytes
+    }
+
+    /// Returns every property as (format ID, property identifier, value).
+    pub fn entries(&self) -> impl Iterator<Item = (&Guid, &PropertyIdentifier, &PropVariant)> {
+        self.storages.iter().flat_map(|storage| {
+            storage
+                .values
+                .iter()
+                .map(move |value| (&storage.format_id, &value.identifier, &value.value))
+        })
+    }
+
+    /// Returns the value of the property with the format ID `format_id` (e.g. `B9B4B3FC-2B51-4A42-B5D8-324146AFCF25`)
+    /// and the integer ID `id`.
+    pub fn get(&self, format_id: &str, id: u32) -> Option<&PropVariant> {
+        self.entries()
+            .find(|(entry_format_id, identifier, _)| {
+                matches!(identifier, PropertyIdentifier::Id(entry_id) if *entry_id == id)
+                    && entry_format_id.to_string().eq_ignore_ascii_case(format_id)
+            })
+            .map(|(_, _, value)| value)
+    }
+
+    /// Returns the value of the property with the string name `name`.
+    pub fn get_by_name(&self, name: &str) -> Option<&PropVariant> {
+        self.entries()
+            .find(|(_, identifier, _)| {
+                matches!(identifier, PropertyIdentifier::Name(entry_name) if entry_name == name)
+            })
+            .map(|(_, _, value)| value)
+    }
+}
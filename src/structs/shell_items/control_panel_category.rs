@@ -1,13 +1,13 @@
 use super::Name;
 use byteorder::ReadBytesExt;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::{
     convert::From,
     io::{Cursor, Read, Result, Seek, SeekFrom},
     fmt::{Display, Formatter, Result as FmtResult}
 };
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum ControlPanelCategory {
     AllControlPanelItems,
     AppearanceAndPersonalization,
@@ -66,7 +66,7 @@ impl Display for ControlPanelCategory {
 }
 
 /// [ControlPanelCategory](https://github.com/EricZimmerman/Lnk/tree/master/Lnk/ShellItems) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ControlPanelCategoryShellItem {
     pub cat_type: ControlPanelCategory,
 }
@@ -1,10 +1,10 @@
 use crate::structs::{ExtraDataBlock, Guid};
 use super::Name;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::io::{Cursor, Read, Result, Seek, SeekFrom};
 
 /// [ControlPanelItemShellItem](https://github.com/EricZimmerman/Lnk/tree/master/Lnk/ShellItems) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ControlPanelItemShellItem {
     pub guid: Guid,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -19,10 +19,7 @@ impl ControlPanelItemShellItem {
     pub fn from_reader<R: Read + Seek>(r: &mut R) -> Result<Self> {
         r.seek(SeekFrom::Current(12))?;
         let guid = Guid::from_reader(r)?;
-        let extention_block = match ExtraDataBlock::from_reader(r) {
-            Ok(eb) => eb,
-            Err(_) => None,
-        };
+        let extention_block = ExtraDataBlock::from_reader(r).unwrap_or_default();
 
         Ok(Self {
             guid,
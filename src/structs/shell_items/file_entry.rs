@@ -5,10 +5,10 @@ use crate::{utils, ReaderError};
 use crate::file_system::FileAttributesFlags;
 use crate::structs::ExtraDataBlock;
 use super::Name;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// [FileEntryShellItem](https://github.com/libyal/libfwsi/blob/main/documentation/Windows%20Shell%20Item%20format.asciidoc#file_entry_shell_item) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct FileEntryShellItem{
     pub is_file: bool,
     pub file_size: u32,
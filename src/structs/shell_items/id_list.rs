@@ -1,12 +1,16 @@
 use crate::ReaderError;
 use crate::structs::shell_items::{Name, ShellItem};
+use crate::structs::Guid;
 use crate::traits::Path;
 use byteorder::{LittleEndian, ReadBytesExt};
-use serde::Serialize;
-use std::io::{Cursor, Read, Seek, SeekFrom};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Error, ErrorKind, Read, Seek, SeekFrom};
+
+/// The last 8 bytes of the `My Computer` GUID (`20D04FE0-3AEA-1069-A2D8-08002B30309D`).
+const MY_COMPUTER_DATA4: [u8; 8] = [0xA2, 0xD8, 0x08, 0x00, 0x2B, 0x30, 0x30, 0x9D];
 
 /// [IDList](https://docs.microsoft.com/en-us/openspecs/windows_protocols/ms-shllink/470e62dc-6c62-49c4-b205-2c39780f10f7) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct IDList(Vec<ShellItem>);
 
 impl IDList {
@@ -29,22 +33,103 @@ impl IDList {
         Ok(Self(item_id_list))
     }
 
+    /// Build an IDList for a local path (e.g. `C:\Windows\notepad.exe`). The list starts with the `My Computer`
+    /// root shell item followed by the volume shell item and a file entry shell item for every path component.
+    /// The last component is treated as a file unless the path ends with `\`.
+    pub fn from_path(path: &str) -> Result<Self, ReaderError> {
+        let path_bytes = path.as_bytes();
+        if path_bytes.len() < 2 || !path_bytes[0].is_ascii_alphabetic() || path_bytes[1] != b':' {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidInput,
+                format!("IDList : '{}' is not a local path", path),
+            )));
+        }
+        let mut data = vec![];
+        // My Computer root shell item
+        data.extend_from_slice(&[0x14, 0x00, 0x1F, 0x50]);
+        data.extend_from_slice(&Guid::new(0x20D04FE0, 0x3AEA, 0x1069, MY_COMPUTER_DATA4).to_bytes());
+        // Volume shell item
+        let mut volume = vec![0x19, 0x00, 0x2F];
+        volume.extend_from_slice(&path_bytes[..2]);
+        volume.push(b'\\');
+        volume.resize(0x19, 0);
+        data.extend_from_slice(&volume);
+
+        let components = path[2..]
+            .split('\\')
+            .filter(|c| !c.is_empty())
+            .collect::<Vec<&str>>();
+        for (index, component) in components.iter().enumerate() {
+            let is_file = index == components.len() - 1 && !path.ends_with('\\');
+            let name = component
+                .encode_utf16()
+                .chain(std::iter::once(0))
+                .flat_map(|c| c.to_le_bytes())
+                .collect::<Vec<u8>>();
+            let size = 16 + name.len() as u16;
+            data.extend_from_slice(&size.to_le_bytes());
+            // File entry shell item with Unicode name
+            data.push(match is_file {
+                true => 0x36,
+                false => 0x35,
+            });
+            data.push(0);
+            data.extend_from_slice(&[0; 8]); // file size and modification time
+            data.extend_from_slice(&(if is_file { 0x20u16 } else { 0x10u16 }).to_le_bytes());
+            data.extend_from_slice(&name);
+            data.extend_from_slice(&[0, 0]); // no extension block
+        }
+        data.extend_from_slice(&[0, 0]);
+        Self::from_buffer(&data)
+    }
+
+    /// Returns the IDList in its binary form (the shell items followed by the terminal ID).
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self
+            .0
+            .iter()
+            .flat_map(|shell_item| shell_item.to_bytes())
+            .collect::<Vec<u8>>();
+        bytes.extend_from_slice(&[0, 0]);
+        bytes
+    }
+
     pub fn items<'a>(&'a self) -> std::slice::Iter<'a, ShellItem> {
         self.0.iter()
     }
+
+    /// Split the shell items at `offset`, the offset in bytes of a shell item from the start of the list
+    /// (e.g. the `Offset` field of `KnownFolderDataBlock` and `SpecialFolderDataBlock`).
+    /// Returns `None` if the offset is not the start of a shell item.
+    pub fn split_at_offset(&self, offset: u32) -> Option<(&[ShellItem], &[ShellItem])> {
+        let mut item_offset = 0u32;
+        for (index, shell_item) in self.0.iter().enumerate() {
+            if item_offset == offset {
+                return Some(self.0.split_at(index));
+            }
+            item_offset += shell_item.size as u32;
+        }
+        match item_offset == offset {
+            true => Some((&self.0[..], &[])),
+            false => None,
+        }
+    }
+
+    /// Build a path from a slice of shell items, see the implementation of the `Path` trait.
+    pub fn items_path(items: &[ShellItem]) -> String {
+        items
+            .iter()
+            .map(|shell_item| shell_item.name())
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<String>>()
+            .join("\\")
+            .replace("\\\\", "\\")
+    }
 }
 
 impl Path for IDList {
     fn path(&self) -> Option<String> {
-        Some(
-            self.0
-                .iter()
-                .map(|shell_item| shell_item.name())
-                .filter(|s| !s.is_empty())
-                .collect::<Vec<String>>()
-                .join("\\")
-                .replace("\\\\", "\\"),
-        )
+        Some(Self::items_path(&self.0))
     }
 }
 
@@ -1,5 +1,6 @@
 //! This module contains ShellItems structs parsers.
 #![allow(non_camel_case_types)]
+#![allow(clippy::upper_case_acronyms)]
 #![cfg(feature = "shell_items")]
 mod control_panel_category;
 mod control_panel_item;
@@ -22,12 +23,13 @@ use users_files_folder::UsersFilesFolderShellItem;
 use volume::VolumeShellItem;
 
 use byteorder::{LittleEndian, ReadBytesExt};
-use serde::{Serialize, Serializer};
+use serde::{de::Error as _, Deserialize, Deserializer, Serialize, Serializer};
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
     io::{Cursor, Read, Seek, SeekFrom},
 };
 
+use crate::utils::{deserialize_hex, hex_to_bytes, serialize_hex};
 use crate::ReaderError;
 
 #[derive(Debug)]
@@ -39,8 +41,7 @@ impl Display for UnimplementedShellItem {
             f,
             "{}",
             self.0
-                .to_owned()
-                .into_iter()
+                .iter()
                 .map(|b| format!("{:02X}", b))
                 .collect::<String>()
         )
@@ -56,8 +57,20 @@ impl Serialize for UnimplementedShellItem {
     }
 }
 
+impl<'de> Deserialize<'de> for UnimplementedShellItem {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let hex = String::deserialize(deserializer)?;
+        hex_to_bytes(&hex)
+            .map(UnimplementedShellItem)
+            .ok_or_else(|| D::Error::custom(format!("invalid hex string '{}'", hex)))
+    }
+}
+
 /// The implemented types for shell items.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ShellItemTypes {
     FileEntry(FileEntryShellItem),
@@ -72,16 +85,17 @@ pub enum ShellItemTypes {
 }
 
 /// ShellItem is struct that reads the struct bytes and decide which shellitem struct to use (FileEntryShellItem, VolumeShellItem, etc).
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ShellItem {
     /// Shell item size in bytes.
-    #[serde(skip_serializing)]
     pub size: u16,
     /// Shell item type (FileEntry, Volume, Root, etc)
-    #[serde(skip_serializing)]
     pub class_type: u8,
     /// The parsed shell item data
     pub shell_item_data: Option<ShellItemTypes>,
+    /// The raw shell item data (without the size field).
+    #[serde(serialize_with = "serialize_hex", deserialize_with = "deserialize_hex")]
+    pub data: Vec<u8>,
 }
 
 impl ShellItem {
@@ -109,7 +123,7 @@ impl ShellItem {
     ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use winparsingtools::structs::shell_items::ShellItem;
     /// use winparsingtools::ReaderError;
     /// use std::fs::File;
@@ -128,62 +142,50 @@ impl ShellItem {
         r.seek(SeekFrom::Current(-1))?;
         let mut shell_item_buf = vec![0; (size - 2) as usize];
         r.read_exact(&mut shell_item_buf)?;
-        let shell_item_data;
-
-        match class_type {
-            class_type if class_type & 0x70 == 0x10 => {
-                shell_item_data = Some(ShellItemTypes::Root(RootShellItem::from_buffer(
-                    &shell_item_buf,
-                )?))
-            }
-            class_type if class_type & 0x70 == 0x20 => {
-                shell_item_data = Some(ShellItemTypes::Volume(VolumeShellItem::from_buffer(
-                    &shell_item_buf,
-                )?))
-            }
-            class_type if class_type & 0x70 == 0x30 => {
-                shell_item_data = Some(ShellItemTypes::FileEntry(FileEntryShellItem::from_buffer(
-                    &shell_item_buf,
-                )?))
-            }
-            class_type if class_type & 0x70 == 0x40 => {
-                shell_item_data = Some(ShellItemTypes::NetworkLocation(
-                    NetworkLocationShellItem::from_buffer(&shell_item_buf)?,
-                ))
-            }
-            0x74 => {
-                shell_item_data = Some(ShellItemTypes::UsersFilesFolder(
-                    UsersFilesFolderShellItem::from_buffer(&shell_item_buf)?,
-                ))
-            }
-            0x61 => {
-                shell_item_data = Some(ShellItemTypes::URI(URIShellItem::from_buffer(
-                    &shell_item_buf,
-                )?))
-            }
-            0x01 => {
-                shell_item_data = Some(ShellItemTypes::ControlPanelCategory(
-                    ControlPanelCategoryShellItem::from_buffer(&shell_item_buf)?,
-                ))
-            }
-            0x71 => {
-                shell_item_data = Some(ShellItemTypes::ControlPanelItem(
-                    ControlPanelItemShellItem::from_buffer(&shell_item_buf)?,
-                ))
-            }
-            _ => {
-                shell_item_data = Some(ShellItemTypes::Unimplemented(UnimplementedShellItem(
-                    shell_item_buf.to_vec(),
-                )))
-            }
+        let shell_item_data = match class_type {
+            class_type if class_type & 0x70 == 0x10 => Some(ShellItemTypes::Root(
+                RootShellItem::from_buffer(&shell_item_buf)?,
+            )),
+            class_type if class_type & 0x70 == 0x20 => Some(ShellItemTypes::Volume(
+                VolumeShellItem::from_buffer(&shell_item_buf)?,
+            )),
+            class_type if class_type & 0x70 == 0x30 => Some(ShellItemTypes::FileEntry(
+                FileEntryShellItem::from_buffer(&shell_item_buf)?,
+            )),
+            class_type if class_type & 0x70 == 0x40 => Some(ShellItemTypes::NetworkLocation(
+                NetworkLocationShellItem::from_buffer(&shell_item_buf)?,
+            )),
+            0x74 => Some(ShellItemTypes::UsersFilesFolder(
+                UsersFilesFolderShellItem::from_buffer(&shell_item_buf)?,
+            )),
+            0x61 => Some(ShellItemTypes::URI(URIShellItem::from_buffer(
+                &shell_item_buf,
+            )?)),
+            0x01 => Some(ShellItemTypes::ControlPanelCategory(
+                ControlPanelCategoryShellItem::from_buffer(&shell_item_buf)?,
+            )),
+            0x71 => Some(ShellItemTypes::ControlPanelItem(
+                ControlPanelItemShellItem::from_buffer(&shell_item_buf)?,
+            )),
+            _ => Some(ShellItemTypes::Unimplemented(UnimplementedShellItem(
+                shell_item_buf.to_vec(),
+            ))),
         };
 
         Ok(Self {
             size,
             class_type,
             shell_item_data,
+            data: shell_item_buf,
         })
     }
+
+    /// Returns the shell item in its binary form.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = self.size.to_le_bytes().to_vec();
+        bytes.extend_from_slice(&self.data);
+        bytes
+    }
 }
 
 impl Name for ShellItem {
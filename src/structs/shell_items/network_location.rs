@@ -2,10 +2,10 @@ use std::io::{Cursor, Read, Seek, SeekFrom};
 use byteorder::ReadBytesExt;
 use crate::{utils::read_utf8_string, ReaderError};
 use super::Name;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// [NetworkLocationShellItem](https://github.com/libyal/libfwsi/blob/main/documentation/Windows%20Shell%20Item%20format.asciidoc#network_location_shell_item) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct NetworkLocationShellItem {
     pub location: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2,9 +2,9 @@ use std::io::{Result, Cursor, Read};
 use byteorder::ReadBytesExt;
 use crate::structs::guid;
 use super::Name;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub enum RootShellItemSortIndex {
     INTERNET_EXPLORER1 = 0,
     LIBRARIES = 66,
@@ -36,7 +36,7 @@ impl From<u8> for RootShellItemSortIndex {
 }
 
 /// RootShellItem struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RootShellItem {
     pub sort_index: RootShellItemSortIndex,
     pub guid: guid::Guid
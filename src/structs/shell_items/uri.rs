@@ -5,12 +5,12 @@ use crate::{
     utils::{read_utf16_string, read_utf8_string}
 };
 use byteorder::{LittleEndian, ReadBytesExt};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::io::{Cursor, Read, Seek, SeekFrom};
 use super::Name;
 
 /// [URIShellItem](https://github.com/libyal/libfwsi/blob/main/documentation/Windows%20Shell%20Item%20format.asciidoc#37-uri-shell-item) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct URIShellItem {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_time: Option<FileTime>,
@@ -38,7 +38,6 @@ impl URIShellItem {
         let mut str1 = None;
         let mut str2 = None;
         let mut str3 = None;
-        let uri;
         if data_size > 0 {
             r.seek(SeekFrom::Current(8))?; // Remove unknow bytes
             connect_time = Some(FileTime::new(r.read_u64::<LittleEndian>()?));
@@ -72,17 +71,14 @@ impl URIShellItem {
             }
         }
 
-        if flags & 0x80 > 0 {
-            uri = read_utf16_string(r, None)?;
+        let uri = if flags & 0x80 > 0 {
+            read_utf16_string(r, None)?
         } else {
-            uri = read_utf8_string(r, None)?;
-        }
+            read_utf8_string(r, None)?
+        };
 
         r.seek(SeekFrom::Current(2))?; // Remove unknow bytes
-        let extention_block = match ExtraDataBlock::from_reader(r) {
-            Ok(edb) => edb,
-            Err(_) => None
-        };
+        let extention_block = ExtraDataBlock::from_reader(r).unwrap_or_default();
 
         Ok(Self {
             connect_time,
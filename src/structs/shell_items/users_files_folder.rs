@@ -4,11 +4,11 @@ use crate::utils::read_utf8_string;
 use crate::ReaderError;
 use byteorder::{LittleEndian, ReadBytesExt};
 use guid::Guid;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::io::{Cursor, Read, Seek, SeekFrom};
 
 /// [UsersFilesFolderShellItem](https://github.com/EricZimmerman/Lnk/tree/master/Lnk/ShellItems) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct UsersFilesFolderShellItem {
     file_entry: Box<Option<ShellItem>>,
     delegate_guid: Guid,
@@ -33,8 +33,7 @@ impl UsersFilesFolderShellItem {
             file_entry = Some(ShellItem::from_buffer(&shell_item_data)?);
         } else if sig == "CF" {
             // TODO: implement this
-            return Err(ReaderError::from(std::io::Error::new(
-                std::io::ErrorKind::Other,
+            return Err(ReaderError::from(std::io::Error::other(
                 "UsersFilesFolderShellItem with signitaure 'CF' is not implemented",
             )));
         }
@@ -2,12 +2,11 @@ use std::io::{Result, Cursor, Read};
 use byteorder::ReadBytesExt;
 use crate::utils;
 use super::Name;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// [VolumeShellItem](https://github.com/libyal/libfwsi/blob/main/documentation/Windows%20Shell%20Item%20format.asciidoc#volume_shell_item) struct parser.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct VolumeShellItem {
-    #[serde(skip_serializing)]
     pub has_name: bool,
     pub is_removable_media: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,10 +32,7 @@ impl VolumeShellItem {
             r.read_u8()?; // Remove unknow byte
         }
         else {
-            name = match utils::read_utf8_string(r, None) {
-                Ok(n) => Some(n),
-                Err(_) => None
-            };
+            name = utils::read_utf8_string(r, None).ok();
         }
 
         Ok(Self {
@@ -0,0 +1,509 @@
+use super::{LinkFlags, ShellLinkHeader, ShowCommand};
+use crate::date_time::FileTime;
+use crate::structs::extra_data::{
+    EnvironmentVariableDataBlock, IconEnvironmentDataBlock, KnownFolderDataBlock,
+    PropertyStoreDataBlock, ShimDataBlock, TrackerDataBlock,
+};
+use crate::structs::shell_items::IDList;
+use crate::structs::{Guid, PropertyStore, StringData};
+use crate::utils::encode_cp1252;
+use crate::ReaderError;
+use std::io::{Error, ErrorKind};
+
+/// Encode a string as a NULL-terminated UTF-16LE string.
+fn encode_utf16_nul(string: &str) -> Vec<u8> {
+    string
+        .encode_utf16()
+        .chain(std::iter::once(0))
+        .flat_map(|c| c.to_le_bytes())
+        .collect()
+}
+
+/// Encode a string as a NULL-terminated CP1252 string.
+fn encode_cp1252_nul(string: &str) -> Vec<u8> {
+    let mut bytes = encode_cp1252(string);
+    bytes.push(0);
+    bytes
+}
+
+/// Returns an error if `len` does not fit in the size field of `structure`.
+fn check_size(structure: &str, len: usize, max: usize) -> Result<(), ReaderError> {
+    match len > max {
+        true => Err(ReaderError::from(Error::new(
+            ErrorKind::InvalidInput,
+            format!(
+                "ShellLinkBuilder : {} is too big ({}, the maximum is {})",
+                structure, len, max
+            ),
+        ))),
+        false => Ok(()),
+    }
+}
+
+/// Builds Shell Link (.LNK) files. The structural link flags (`HasLinkTargetIDList`, `HasLinkInfo`, `HasName`, etc)
+/// are set from the data added to the builder. The output can be parsed with `ShellLink::from_buffer`.
+///
+/// # Examples
+///
+/// ```
+/// use winparsingtools::structs::shell_link::{ShellLink, ShellLinkBuilder};
+///
+/// let lnk = ShellLinkBuilder::new()
+///     .local_base_path("C:\\Windows\\System32\\cmd.exe", 3, 0x1234ABCD, "OS")
+///     .arguments("/c whoami")
+///     .build()
+///     .unwrap();
+/// let shell_link = ShellLink::from_buffer(&lnk).unwrap();
+/// assert_eq!(shell_link.command_line_arguments.unwrap().string, "/c whoami");
+/// ```
+#[derive(Debug)]
+pub struct ShellLinkBuilder {
+    link_flags: u32,
+    file_attributes: u32,
+    ctime: Option<FileTime>,
+    atime: Option<FileTime>,
+    mtime: Option<FileTime>,
+    file_size: u32,
+    icon_index: i32,
+    show_command: ShowCommand,
+    hot_key: u16,
+    is_unicode: bool,
+    link_target_id_list: Option<IDList>,
+    local_base_path: Option<String>,
+    drive_type: u32,
+    drive_serial_number: u32,
+    volume_label: String,
+    net_name: Option<String>,
+    device_name: Option<String>,
+    network_provider_type: Option<u32>,
+    common_path_suffix: String,
+    name_string: Option<StringData>,
+    relative_path: Option<StringData>,
+    working_dir: Option<StringData>,
+    command_line_arguments: Option<StringData>,
+    icon_location: Option<StringData>,
+    extra_data_blocks: Vec<Vec<u8>>,
+    trailing_data: Vec<u8>,
+}
+
+impl Default for ShellLinkBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShellLinkBuilder {
+    /// Create a builder for a Unicode shell link with no data and `SW_SHOWNORMAL` show command.
+    pub fn new() -> Self {
+        Self {
+            link_flags: 0,
+            file_attributes: 0,
+            ctime: None,
+            atime: None,
+            mtime: None,
+            file_size: 0,
+            icon_index: 0,
+            show_command: ShowCommand::SW_SHOWNORMAL,
+            hot_key: 0,
+            is_unicode: true,
+            link_target_id_list: None,
+            local_base_path: None,
+            drive_type: 0,
+            drive_serial_number: 0,
+            volume_label: String::new(),
+            net_name: None,
+            device_name: None,
+            network_provider_type: None,
+            common_path_suffix: String::new(),
+            name_string: None,
+            relative_path: None,
+            working_dir: None,
+            command_line_arguments: None,
+            icon_location: None,
+            extra_data_blocks: vec![],
+            trailing_data: vec![],
+        }
+    }
+
+    /// Additional link flags to set in the header (e.g. `LinkFlags::RunAsUser`).
+    pub fn link_flags(mut self, link_flags: &[LinkFlags]) -> Self {
+        for flag in link_flags {
+            self.link_flags |= flag.to_u32();
+        }
+        self
+    }
+
+    /// The link target file attributes (e.g. `FILE_ATTRIBUTE_ARCHIVE` = `0x20`).
+    pub fn file_attributes(mut self, file_attributes: u32) -> Self {
+        self.file_attributes = file_attributes;
+        self
+    }
+
+    pub fn creation_time(mut self, ctime: FileTime) -> Self {
+        self.ctime = Some(ctime);
+        self
+    }
+
+    pub fn access_time(mut self, atime: FileTime) -> Self {
+        self.atime = Some(atime);
+        self
+    }
+
+    pub fn write_time(mut self, mtime: FileTime) -> Self {
+        self.mtime = Some(mtime);
+        self
+    }
+
+    pub fn file_size(mut self, file_size: u32) -> Self {
+        self.file_size = file_size;
+        self
+    }
+
+    pub fn icon_index(mut self, icon_index: i32) -> Self {
+        self.icon_index = icon_index;
+        self
+    }
+
+    pub fn show_command(mut self, show_command: ShowCommand) -> Self {
+        self.show_command = show_command;
+        self
+    }
+
+    pub fn hot_key(mut self, hot_key: u16) -> Self {
+        self.hot_key = hot_key;
+        self
+    }
+
+    /// Write the StringData structures as UTF-16 (`true`, the default) or CP1252 (`false`).
+    pub fn unicode(mut self, is_unicode: bool) -> Self {
+        self.is_unicode = is_unicode;
+        self
+    }
+
+    /// The link target IDList, see `IDList::from_path`.
+    pub fn link_target_id_list(mut self, id_list: IDList) -> Self {
+        self.link_target_id_list = Some(id_list);
+        self
+    }
+
+    /// The LinkInfo local base path and the VolumeID of the volume it is stored on.
+    pub fn local_base_path(
+        mut self,
+        local_base_path: &str,
+        drive_type: u32,
+        drive_serial_number: u32,
+        volume_label: &str,
+    ) -> Self {
+        self.local_base_path = Some(local_base_path.to_string());
+        self.drive_type = drive_type;
+        self.drive_serial_number = drive_serial_number;
+        self.volume_label = volume_label.to_string();
+        self
+    }
+
+    /// The LinkInfo network share (e.g. `\\server\share`), mapped drive (e.g. `Z:`) and network provider type.
+    pub fn network_share(
+        mut self,
+        net_name: &str,
+        device_name: Option<&str>,
+        network_provider_type: Option<u32>,
+    ) -> Self {
+        self.net_name = Some(net_name.to_string());
+        self.device_name = device_name.map(|d| d.to_string());
+        self.network_provider_type = network_provider_type;
+        self
+    }
+
+    /// The LinkInfo common path suffix, appended to the network share to build the link target path.
+    pub fn common_path_suffix(mut self, common_path_suffix: &str) -> Self {
+        self.common_path_suffix = common_path_suffix.to_string();
+        self
+    }
+
+    pub fn name(mut self, name: &str) -> Self {
+        self.name_string = Some(StringData::from(name));
+        self
+    }
+
+    pub fn relative_path(mut self, relative_path: &str) -> Self {
+        self.relative_path = Some(StringData::from(relative_path));
+        self
+    }
+
+    pub fn working_dir(mut self, working_dir: &str) -> Self {
+        self.working_dir = Some(StringData::from(working_dir));
+        self
+    }
+
+    pub fn arguments(mut self, arguments: &str) -> Self {
+        self.command_line_arguments = Some(StringData::from(arguments));
+        self
+    }
+
+    pub fn icon_location(mut self, icon_location: &str) -> Self {
+        self.icon_location = Some(StringData::from(icon_location));
+        self
+    }
+
+    /// Add an `EnvironmentVariableDataBlock` and set the `HasExpString` flag.
+    pub fn environment_variable(mut self, target: &str) -> Self {
+        self.link_flags |= LinkFlags::HasExpString.to_u32();
+        self.extra_data_blocks.push(
+            EnvironmentVariableDataBlock {
+                size: EnvironmentVariableDataBlock::SIZE,
+                signature: EnvironmentVariableDataBlock::SIGNATURE,
+                target_ansi: target.to_string(),
+                target_unicode: target.to_string(),
+            }
+            .to_bytes(),
+        );
+        self
+    }
+
+    /// Add an `IconEnvironmentDataBlock` and set the `HasExpIcon` flag.
+    pub fn icon_environment(mut self, target: &str) -> Self {
+        self.link_flags |= LinkFlags::HasExpIcon.to_u32();
+        self.extra_data_blocks.push(
+            IconEnvironmentDataBlock {
+                size: IconEnvironmentDataBlock::SIZE,
+                signature: IconEnvironmentDataBlock::SIGNATURE,
+                target_ansi: target.to_string(),
+                target_unicode: target.to_string(),
+            }
+            .to_bytes(),
+        );
+        self
+    }
+
+    /// Add a `TrackerDataBlock`, the birth droids are the same as the current droids.
+    pub fn tracker(mut self, machine_id: &str, droid_volume_id: Guid, droid_file_id: Guid) -> Self {
+        self.extra_data_blocks.push(
+            TrackerDataBlock {
+                size: TrackerDataBlock::SIZE,
+                signature: TrackerDataBlock::SIGNATURE,
+                length: 0x58,
+                version: 0,
+                machine_id: machine_id.to_string(),
+                birth_droid_volume_id: droid_volume_id.clone(),
+                birth_droid_file_id: droid_file_id.clone(),
+                droid_volume_id,
+                droid_file_id,
+                mac_address: String::new(),
+                droid_file_time: None,
+            }
+            .to_bytes(),
+        );
+        self
+    }
+
+    /// Add a `KnownFolderDataBlock`, `offset` is the offset of the first child of the folder in the link target IDList.
+    pub fn known_folder(mut self, known_folder_id: Guid, offset: u32) -> Self {
+        self.extra_data_blocks.push(
+            KnownFolderDataBlock {
+                size: KnownFolderDataBlock::SIZE,
+                signature: KnownFolderDataBlock::SIGNATURE,
+                known_folder_id,
+                offset,
+            }
+            .to_bytes(),
+        );
+        self
+    }
+
+    /// Add a `ShimDataBlock` and set the `RunWithShimLayer` flag.
+    pub fn shim(mut self, layer_name: &str) -> Self {
+        self.link_flags |= LinkFlags::RunWithShimLayer.to_u32();
+        self.extra_data_blocks.push(
+            ShimDataBlock {
+                size: 0,
+                signature: ShimDataBlock::SIGNATURE,
+                layer_name: layer_name.to_string(),
+            }
+            .to_bytes(),
+        );
+        self
+    }
+
+    /// Add a `PropertyStoreDataBlock` with the property store.
+    pub fn property_store(mut self, property_store: PropertyStore) -> Self {
+        self.extra_data_blocks.push(
+            PropertyStoreDataBlock {
+                size: 0,
+                signature: PropertyStoreDataBlock::SIGNATURE,
+                property_store,
+            }
+            .to_bytes(),
+        );
+        self
+    }
+
+    /// Add a raw extra data block, `block` contains the whole block including the size and signature fields.
+    pub fn extra_data_block(mut self, block: Vec<u8>) -> Self {
+        self.extra_data_blocks.push(block);
+        self
+    }
+
+    /// Data written after the terminal block.
+    pub fn trailing_data(mut self, trailing_data: Vec<u8>) -> Self {
+        self.trailing_data = trailing_data;
+        self
+    }
+
+    fn build_link_info(&self) -> Vec<u8> {
+        let header_size: u32 = match self.is_unicode {
+            true => 0x24,
+            false => 0x1C,
+        };
+        let mut flags = 0;
+        let mut data = vec![];
+        let mut volume_id_offset = 0;
+        let mut local_base_path_offset = 0;
+        let mut common_network_relative_link_offset = 0;
+        let mut local_base_path_offset_unicode = 0;
+        let mut common_path_suffix_offset_unicode = 0;
+
+        if let Some(local_base_path) = &self.local_base_path {
+            flags |= 0x1;
+            volume_id_offset = header_size + data.len() as u32;
+            let volume_label = encode_cp1252_nul(&self.volume_label);
+            data.extend_from_slice(&(0x10 + volume_label.len() as u32).to_le_bytes());
+            data.extend_from_slice(&self.drive_type.to_le_bytes());
+            data.extend_from_slice(&self.drive_serial_number.to_le_bytes());
+            data.extend_from_slice(&0x10u32.to_le_bytes());
+            data.extend_from_slice(&volume_label);
+            local_base_path_offset = header_size + data.len() as u32;
+            data.extend_from_slice(&encode_cp1252_nul(local_base_path));
+        }
+
+        if let Some(net_name) = &self.net_name {
+            flags |= 0x2;
+            common_network_relative_link_offset = header_size + data.len() as u32;
+            let net_name = encode_cp1252_nul(net_name);
+            let device_name = self.device_name.as_deref().map(encode_cp1252_nul);
+            let mut link_flags = 0u32;
+            if device_name.is_some() {
+                link_flags |= 0x1;
+            }
+            if self.network_provider_type.is_some() {
+                link_flags |= 0x2;
+            }
+            let device_name_offset = match &device_name {
+                Some(_) => 0x14 + net_name.len() as u32,
+                None => 0,
+            };
+            let device_name = device_name.unwrap_or_default();
+            let size = 0x14 + net_name.len() as u32 + device_name.len() as u32;
+            data.extend_from_slice(&size.to_le_bytes());
+            data.extend_from_slice(&link_flags.to_le_bytes());
+            data.extend_from_slice(&0x14u32.to_le_bytes());
+            data.extend_from_slice(&device_name_offset.to_le_bytes());
+            data.extend_from_slice(&self.network_provider_type.unwrap_or(0).to_le_bytes());
+            data.extend_from_slice(&net_name);
+            data.extend_from_slice(&device_name);
+        }
+
+        let common_path_suffix_offset = header_size + data.len() as u32;
+        data.extend_from_slice(&encode_cp1252_nul(&self.common_path_suffix));
+
+        if self.is_unicode {
+            if let Some(local_base_path) = &self.local_base_path {
+                local_base_path_offset_unicode = header_size + data.len() as u32;
+                data.extend_from_slice(&encode_utf16_nul(local_base_path));
+            }
+            common_path_suffix_offset_unicode = header_size + data.len() as u32;
+            data.extend_from_slice(&encode_utf16_nul(&self.common_path_suffix));
+        }
+
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&(header_size + data.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&header_size.to_le_bytes());
+        bytes.extend_from_slice(&(flags as u32).to_le_bytes());
+        bytes.extend_from_slice(&volume_id_offset.to_le_bytes());
+        bytes.extend_from_slice(&local_base_path_offset.to_le_bytes());
+        bytes.extend_from_slice(&common_network_relative_link_offset.to_le_bytes());
+        bytes.extend_from_slice(&common_path_suffix_offset.to_le_bytes());
+        if self.is_unicode {
+            bytes.extend_from_slice(&local_base_path_offset_unicode.to_le_bytes());
+            bytes.extend_from_slice(&common_path_suffix_offset_unicode.to_le_bytes());
+        }
+        bytes.extend_from_slice(&data);
+        bytes
+    }
+
+    /// Returns the shell link in its binary form.
+    pub fn build(&self) -> Result<Vec<u8>, ReaderError> {
+        let has_link_info = self.local_base_path.is_some() || self.net_name.is_some();
+        let mut link_flags = self.link_flags;
+        let mut set_flag = |flag: LinkFlags, is_set: bool| {
+            if is_set {
+                link_flags |= flag.to_u32();
+            }
+        };
+        set_flag(LinkFlags::HasLinkTargetIDList, self.link_target_id_list.is_some());
+        set_flag(LinkFlags::HasLinkInfo, has_link_info);
+        set_flag(LinkFlags::HasName, self.name_string.is_some());
+        set_flag(LinkFlags::HasRelativePath, self.relative_path.is_some());
+        set_flag(LinkFlags::HasWorkingDir, self.working_dir.is_some());
+        set_flag(LinkFlags::HasArguments, self.command_line_arguments.is_some());
+        set_flag(LinkFlags::HasIconLocation, self.icon_location.is_some());
+        set_flag(LinkFlags::IsUnicode, self.is_unicode);
+
+        let mut bytes = vec![];
+        bytes.extend_from_slice(&ShellLinkHeader::HEADER_SIZE.to_le_bytes());
+        // 00021401-0000-0000-C000-000000000046
+        bytes.extend_from_slice(
+            &Guid::new(0x00021401, 0, 0, [0xC0, 0, 0, 0, 0, 0, 0, 0x46]).to_bytes(),
+        );
+        bytes.extend_from_slice(&link_flags.to_le_bytes());
+        bytes.extend_from_slice(&self.file_attributes.to_le_bytes());
+        for time in [&self.ctime, &self.atime, &self.mtime] {
+            match time {
+                Some(time) => bytes.extend_from_slice(&time.to_bytes()),
+                None => bytes.extend_from_slice(&[0; 8]),
+            }
+        }
+        bytes.extend_from_slice(&self.file_size.to_le_bytes());
+        bytes.extend_from_slice(&self.icon_index.to_le_bytes());
+        bytes.extend_from_slice(&self.show_command.to_u32().to_le_bytes());
+        bytes.extend_from_slice(&self.hot_key.to_le_bytes());
+        bytes.extend_from_slice(&[0; 10]);
+
+        if let Some(id_list) = &self.link_target_id_list {
+            let id_list = id_list.to_bytes();
+            check_size("LinkTargetIDList", id_list.len(), u16::MAX as usize)?;
+            bytes.extend_from_slice(&(id_list.len() as u16).to_le_bytes());
+            bytes.extend_from_slice(&id_list);
+        }
+
+        if has_link_info {
+            bytes.extend_from_slice(&self.build_link_info());
+        }
+
+        for string_data in [
+            &self.name_string,
+            &self.relative_path,
+            &self.working_dir,
+            &self.command_line_arguments,
+            &self.icon_location,
+        ]
+        .iter()
+        .filter_map(|s| s.as_ref())
+        {
+            // The count field of StringData is the number of characters in the output encoding
+            let count = match self.is_unicode {
+                true => string_data.string.encode_utf16().count(),
+                false => encode_cp1252(&string_data.string).len(),
+            };
+            check_size("StringData", count, u16::MAX as usize)?;
+            bytes.extend_from_slice(&string_data.to_bytes(self.is_unicode));
+        }
+
+        for block in &self.extra_data_blocks {
+            bytes.extend_from_slice(block);
+        }
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(&self.trailing_data);
+
+        Ok(bytes)
+    }
+}
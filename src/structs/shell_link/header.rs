@@ -0,0 +1,228 @@
+use crate::date_time::FileTime;
+use crate::file_system::FileAttributesFlags;
+use crate::structs::Guid;
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Cursor, Error, ErrorKind, Read};
+use std::str::FromStr;
+
+/// [LinkFlags](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-shllink/ae350202-3ba9-4790-9e9e-98935f4ee5af) parser.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub enum LinkFlags {
+    HasLinkTargetIDList,
+    HasLinkInfo,
+    HasName,
+    HasRelativePath,
+    HasWorkingDir,
+    HasArguments,
+    HasIconLocation,
+    IsUnicode,
+    ForceNoLinkInfo,
+    HasExpString,
+    RunInSeparateProcess,
+    Unused1,
+    HasDarwinID,
+    RunAsUser,
+    HasExpIcon,
+    NoPidlAlias,
+    Unused2,
+    RunWithShimLayer,
+    ForceNoLinkTrack,
+    EnableTargetMetadata,
+    DisableLinkPathTracking,
+    DisableKnownFolderTracking,
+    DisableKnownFolderAlias,
+    AllowLinkToLink,
+    UnaliasOnSave,
+    PreferEnvironmentPath,
+    KeepLocalIDListForUNCTarget,
+}
+
+impl LinkFlags {
+    pub fn from_u32(flags: u32) -> Vec<LinkFlags> {
+        let mut res = vec![];
+        if flags & 0x00000001 !=0 { res.push(LinkFlags::HasLinkTargetIDList); }
+        if flags & 0x00000002 !=0 { res.push(LinkFlags::HasLinkInfo); }
+        if flags & 0x00000004 !=0 { res.push(LinkFlags::HasName); }
+        if flags & 0x00000008 !=0 { res.push(LinkFlags::HasRelativePath); }
+        if flags & 0x00000010 !=0 { res.push(LinkFlags::HasWorkingDir); }
+        if flags & 0x00000020 !=0 { res.push(LinkFlags::HasArguments); }
+        if flags & 0x00000040 !=0 { res.push(LinkFlags::HasIconLocation); }
+        if flags & 0x00000080 !=0 { res.push(LinkFlags::IsUnicode); }
+        if flags & 0x00000100 !=0 { res.push(LinkFlags::ForceNoLinkInfo); }
+        if flags & 0x00000200 !=0 { res.push(LinkFlags::HasExpString); }
+        if flags & 0x00000400 !=0 { res.push(LinkFlags::RunInSeparateProcess); }
+        if flags & 0x00000800 !=0 { res.push(LinkFlags::Unused1); }
+        if flags & 0x00001000 !=0 { res.push(LinkFlags::HasDarwinID); }
+        if flags & 0x00002000 !=0 { res.push(LinkFlags::RunAsUser); }
+        if flags & 0x00004000 !=0 { res.push(LinkFlags::HasExpIcon); }
+        if flags & 0x00008000 !=0 { res.push(LinkFlags::NoPidlAlias); }
+        if flags & 0x00010000 !=0 { res.push(LinkFlags::Unused2); }
+        if flags & 0x00020000 !=0 { res.push(LinkFlags::RunWithShimLayer); }
+        if flags & 0x00040000 !=0 { res.push(LinkFlags::ForceNoLinkTrack); }
+        if flags & 0x00080000 !=0 { res.push(LinkFlags::EnableTargetMetadata); }
+        if flags & 0x00100000 !=0 { res.push(LinkFlags::DisableLinkPathTracking); }
+        if flags & 0x00200000 !=0 { res.push(LinkFlags::DisableKnownFolderTracking); }
+        if flags & 0x00400000 !=0 { res.push(LinkFlags::DisableKnownFolderAlias); }
+        if flags & 0x00800000 !=0 { res.push(LinkFlags::AllowLinkToLink); }
+        if flags & 0x01000000 !=0 { res.push(LinkFlags::UnaliasOnSave); }
+        if flags & 0x02000000 !=0 { res.push(LinkFlags::PreferEnvironmentPath); }
+        if flags & 0x04000000 !=0 { res.push(LinkFlags::KeepLocalIDListForUNCTarget); }
+        res
+    }
+
+    /// Returns the value of the flag in the header link flags.
+    pub fn to_u32(&self) -> u32 {
+        match self {
+            LinkFlags::HasLinkTargetIDList => 0x00000001,
+            LinkFlags::HasLinkInfo => 0x00000002,
+            LinkFlags::HasName => 0x00000004,
+            LinkFlags::HasRelativePath => 0x00000008,
+            LinkFlags::HasWorkingDir => 0x00000010,
+            LinkFlags::HasArguments => 0x00000020,
+            LinkFlags::HasIconLocation => 0x00000040,
+            LinkFlags::IsUnicode => 0x00000080,
+            LinkFlags::ForceNoLinkInfo => 0x00000100,
+            LinkFlags::HasExpString => 0x00000200,
+            LinkFlags::RunInSeparateProcess => 0x00000400,
+            LinkFlags::Unused1 => 0x00000800,
+            LinkFlags::HasDarwinID => 0x00001000,
+            LinkFlags::RunAsUser => 0x00002000,
+            LinkFlags::HasExpIcon => 0x00004000,
+            LinkFlags::NoPidlAlias => 0x00008000,
+            LinkFlags::Unused2 => 0x00010000,
+            LinkFlags::RunWithShimLayer => 0x00020000,
+            LinkFlags::ForceNoLinkTrack => 0x00040000,
+            LinkFlags::EnableTargetMetadata => 0x00080000,
+            LinkFlags::DisableLinkPathTracking => 0x00100000,
+            LinkFlags::DisableKnownFolderTracking => 0x00200000,
+            LinkFlags::DisableKnownFolderAlias => 0x00400000,
+            LinkFlags::AllowLinkToLink => 0x00800000,
+            LinkFlags::UnaliasOnSave => 0x01000000,
+            LinkFlags::PreferEnvironmentPath => 0x02000000,
+            LinkFlags::KeepLocalIDListForUNCTarget => 0x04000000,
+        }
+    }
+}
+
+impl Display for LinkFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// The expected window state of the application launched by the link.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum ShowCommand {
+    SW_SHOWNORMAL,
+    SW_SHOWMAXIMIZED,
+    SW_SHOWMINNOACTIVE,
+    UNKNOWN(u32),
+}
+
+impl From<u32> for ShowCommand {
+    fn from(num: u32) -> Self {
+        match num {
+            0x01 => ShowCommand::SW_SHOWNORMAL,
+            0x03 => ShowCommand::SW_SHOWMAXIMIZED,
+            0x07 => ShowCommand::SW_SHOWMINNOACTIVE,
+            other => ShowCommand::UNKNOWN(other),
+        }
+    }
+}
+
+impl ShowCommand {
+    /// Returns the value of the show command in the header.
+    pub fn to_u32(&self) -> u32 {
+        match self {
+            ShowCommand::SW_SHOWNORMAL => 0x01,
+            ShowCommand::SW_SHOWMAXIMIZED => 0x03,
+            ShowCommand::SW_SHOWMINNOACTIVE => 0x07,
+            ShowCommand::UNKNOWN(other) => *other,
+        }
+    }
+}
+
+/// [ShellLinkHeader](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-shllink/c3376b21-0931-45e4-b2fc-a48ac0e60d15) struct parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShellLinkHeader {
+    #[serde(skip, default = "ShellLinkHeader::default_header_size")]
+    pub header_size: u32,
+    #[serde(skip, default = "ShellLinkHeader::default_link_clsid")]
+    pub link_clsid: Guid,
+    pub link_flags: Vec<LinkFlags>,
+    pub file_attr_flags: Vec<FileAttributesFlags>,
+    pub ctime: FileTime,
+    pub atime: FileTime,
+    pub mtime: FileTime,
+    pub file_size: u32,
+    pub icon_index: i32,
+    pub show_command: ShowCommand,
+    /// The low byte is the virtual key code and the high byte is the modifier keys.
+    pub hot_key: u16,
+}
+
+impl ShellLinkHeader {
+    pub const HEADER_SIZE: u32 = 0x4C;
+    /// `00021401-0000-0000-C000-000000000046`
+    pub const LINK_CLSID: &'static str = "00021401-0000-0000-C000-000000000046";
+
+    fn default_header_size() -> u32 {
+        Self::HEADER_SIZE
+    }
+
+    fn default_link_clsid() -> Guid {
+        // Unwraping here is safe because `LINK_CLSID` is a valid GUID.
+        Guid::from_str(Self::LINK_CLSID).unwrap()
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let header_size = r.read_u32::<LittleEndian>()?;
+        let link_clsid = Guid::from_reader(r)?;
+        if header_size != Self::HEADER_SIZE || link_clsid.to_string() != Self::LINK_CLSID {
+            return Err(ReaderError::from(Error::new(
+                ErrorKind::InvalidData,
+                format!(
+                    "ShellLinkHeader : invalid header size '{:#X}' or CLSID '{}'",
+                    header_size, link_clsid
+                ),
+            )));
+        }
+        let link_flags = LinkFlags::from_u32(r.read_u32::<LittleEndian>()?);
+        let file_attr_flags = FileAttributesFlags::from_u32(r.read_u32::<LittleEndian>()?);
+        let ctime = FileTime::new(r.read_u64::<LittleEndian>()?);
+        let atime = FileTime::new(r.read_u64::<LittleEndian>()?);
+        let mtime = FileTime::new(r.read_u64::<LittleEndian>()?);
+        let file_size = r.read_u32::<LittleEndian>()?;
+        let icon_index = r.read_i32::<LittleEndian>()?;
+        let show_command = ShowCommand::from(r.read_u32::<LittleEndian>()?);
+        let hot_key = r.read_u16::<LittleEndian>()?;
+        let mut reserved = [0; 10];
+        r.read_exact(&mut reserved)?;
+
+        Ok(Self {
+            header_size,
+            link_clsid,
+            link_flags,
+            file_attr_flags,
+            ctime,
+            atime,
+            mtime,
+            file_size,
+            icon_index,
+            show_command,
+            hot_key,
+        })
+    }
+
+    /// Returns `true` if `flag` is set in the header link flags.
+    pub fn has_flag(&self, flag: LinkFlags) -> bool {
+        self.link_flags.contains(&flag)
+    }
+}
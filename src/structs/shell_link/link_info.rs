@@ -0,0 +1,242 @@
+use crate::utils::{read_cp1252_string, read_utf16_string};
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::io::{Cursor, Read};
+
+/// Read a NULL-terminated string at `offset` from the start of `buf`, returns `None` if the offset is `0`.
+fn read_string_at(
+    buf: &[u8],
+    offset: u32,
+    is_unicode: bool,
+) -> Result<Option<String>, ReaderError> {
+    if offset == 0 {
+        return Ok(None);
+    }
+    let mut r = Cursor::new(buf);
+    r.set_position(offset as u64);
+    match is_unicode {
+        true => Ok(Some(read_utf16_string(&mut r, None)?)),
+        false => Ok(Some(read_cp1252_string(&mut r, None)?)),
+    }
+}
+
+/// The type of the drive the link target is stored on.
+#[derive(Debug, Serialize, Deserialize)]
+pub enum DriveType {
+    DRIVE_UNKNOWN,
+    DRIVE_NO_ROOT_DIR,
+    DRIVE_REMOVABLE,
+    DRIVE_FIXED,
+    DRIVE_REMOTE,
+    DRIVE_CDROM,
+    DRIVE_RAMDISK,
+    UNKNOWN(u32),
+}
+
+impl From<u32> for DriveType {
+    fn from(num: u32) -> Self {
+        match num {
+            0 => DriveType::DRIVE_UNKNOWN,
+            1 => DriveType::DRIVE_NO_ROOT_DIR,
+            2 => DriveType::DRIVE_REMOVABLE,
+            3 => DriveType::DRIVE_FIXED,
+            4 => DriveType::DRIVE_REMOTE,
+            5 => DriveType::DRIVE_CDROM,
+            6 => DriveType::DRIVE_RAMDISK,
+            other => DriveType::UNKNOWN(other),
+        }
+    }
+}
+
+impl Display for DriveType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:?}", self)
+    }
+}
+
+/// [VolumeID](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-shllink/b7b3eea7-dbff-4275-bd58-83ba3f12d87a) struct parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct VolumeID {
+    pub size: u32,
+    pub drive_type: DriveType,
+    pub drive_serial_number: u32,
+    pub volume_label: String,
+}
+
+impl VolumeID {
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        let mut r = Cursor::new(buf);
+        let size = r.read_u32::<LittleEndian>()?;
+        let drive_type = DriveType::from(r.read_u32::<LittleEndian>()?);
+        let drive_serial_number = r.read_u32::<LittleEndian>()?;
+        let volume_label_offset = r.read_u32::<LittleEndian>()?;
+        let volume_label = match volume_label_offset {
+            0x14 => {
+                let volume_label_offset_unicode = r.read_u32::<LittleEndian>()?;
+                read_string_at(buf, volume_label_offset_unicode, true)?
+            }
+            offset => read_string_at(buf, offset, false)?,
+        };
+
+        Ok(Self {
+            size,
+            drive_type,
+            drive_serial_number,
+            volume_label: volume_label.unwrap_or_default(),
+        })
+    }
+}
+
+/// [CommonNetworkRelativeLink](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-shllink/23bb5877-e3dd-4799-9f50-79f05f938537) struct parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CommonNetworkRelativeLink {
+    pub size: u32,
+    pub flags: u32,
+    pub net_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub device_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_provider_type: Option<u32>,
+}
+
+impl CommonNetworkRelativeLink {
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        let mut r = Cursor::new(buf);
+        let size = r.read_u32::<LittleEndian>()?;
+        let flags = r.read_u32::<LittleEndian>()?;
+        let net_name_offset = r.read_u32::<LittleEndian>()?;
+        let device_name_offset = r.read_u32::<LittleEndian>()?;
+        let network_provider_type = r.read_u32::<LittleEndian>()?;
+        let (net_name, device_name) = match net_name_offset > 0x14 {
+            true => {
+                let net_name_offset_unicode = r.read_u32::<LittleEndian>()?;
+                let device_name_offset_unicode = r.read_u32::<LittleEndian>()?;
+                (
+                    read_string_at(buf, net_name_offset_unicode, true)?,
+                    read_string_at(buf, device_name_offset_unicode, true)?,
+                )
+            }
+            false => (
+                read_string_at(buf, net_name_offset, false)?,
+                read_string_at(buf, device_name_offset, false)?,
+            ),
+        };
+
+        Ok(Self {
+            size,
+            flags,
+            net_name: net_name.unwrap_or_default(),
+            // ValidDevice
+            device_name: device_name.filter(|_| flags & 0x1 != 0),
+            // ValidNetType
+            network_provider_type: Some(network_provider_type).filter(|_| flags & 0x2 != 0),
+        })
+    }
+}
+
+/// [LinkInfo](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-shllink/6813269d-0cc8-4be2-933f-e96e8e3412dc) struct parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LinkInfo {
+    pub size: u32,
+    pub header_size: u32,
+    pub flags: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub volume_id: Option<VolumeID>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_base_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_network_relative_link: Option<CommonNetworkRelativeLink>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_path_suffix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub local_base_path_unicode: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub common_path_suffix_unicode: Option<String>,
+}
+
+impl LinkInfo {
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let size = r.read_u32::<LittleEndian>()?;
+        let mut buf = size.to_le_bytes().to_vec();
+        r.take(size.saturating_sub(4) as u64)
+            .read_to_end(&mut buf)?;
+        let mut r = Cursor::new(&buf);
+        r.set_position(4);
+
+        let header_size = r.read_u32::<LittleEndian>()?;
+        let flags = r.read_u32::<LittleEndian>()?;
+        let volume_id_offset = r.read_u32::<LittleEndian>()?;
+        let local_base_path_offset = r.read_u32::<LittleEndian>()?;
+        let common_network_relative_link_offset = r.read_u32::<LittleEndian>()?;
+        let common_path_suffix_offset = r.read_u32::<LittleEndian>()?;
+        let (local_base_path_offset_unicode, common_path_suffix_offset_unicode) =
+            match header_size >= 0x24 {
+                true => (r.read_u32::<LittleEndian>()?, r.read_u32::<LittleEndian>()?),
+                false => (0, 0),
+            };
+
+        let has_volume_id_and_local_base_path = flags & 0x1 != 0;
+        let has_common_network_relative_link = flags & 0x2 != 0;
+        let mut volume_id = None;
+        let mut local_base_path = None;
+        let mut local_base_path_unicode = None;
+        if has_volume_id_and_local_base_path {
+            if volume_id_offset as usize >= buf.len() {
+                return Err(ReaderError::from(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "LinkInfo : VolumeID offset is outside of the LinkInfo structure",
+                )));
+            }
+            volume_id = Some(VolumeID::from_buffer(&buf[volume_id_offset as usize..])?);
+            local_base_path = read_string_at(&buf, local_base_path_offset, false)?;
+            local_base_path_unicode = read_string_at(&buf, local_base_path_offset_unicode, true)?;
+        }
+        let mut common_network_relative_link = None;
+        if has_common_network_relative_link {
+            if common_network_relative_link_offset as usize >= buf.len() {
+                return Err(ReaderError::from(std::io::Error::new(
+                    std::io::ErrorKind::UnexpectedEof,
+                    "LinkInfo : CommonNetworkRelativeLink offset is outside of the LinkInfo structure",
+                )));
+            }
+            common_network_relative_link = Some(CommonNetworkRelativeLink::from_buffer(
+                &buf[common_network_relative_link_offset as usize..],
+            )?);
+        }
+        let common_path_suffix = read_string_at(&buf, common_path_suffix_offset, false)?;
+        let common_path_suffix_unicode =
+            read_string_at(&buf, common_path_suffix_offset_unicode, true)?;
+
+        Ok(Self {
+            size,
+            header_size,
+            flags,
+            volume_id,
+            local_base_path,
+            common_network_relative_link,
+            common_path_suffix,
+            local_base_path_unicode,
+            common_path_suffix_unicode,
+        })
+    }
+
+    /// Returns the Unicode local base path if it is set, otherwise the ANSI local base path.
+    pub fn local_base_path(&self) -> Option<&str> {
+        self.local_base_path_unicode
+            .as_deref()
+            .or(self.local_base_path.as_deref())
+    }
+
+    /// Returns the Unicode common path suffix if it is set, otherwise the ANSI common path suffix.
+    pub fn common_path_suffix(&self) -> Option<&str> {
+        self.common_path_suffix_unicode
+            .as_deref()
+            .or(self.common_path_suffix.as_deref())
+    }
+}
@@ -0,0 +1,231 @@
+//! This module contains the [Shell Link (.LNK)](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-shllink/16cb4ca1-9339-4d0c-a68d-bf1d6cc0f943) file parser.
+#![allow(non_camel_case_types)]
+#![allow(clippy::upper_case_acronyms)]
+#![cfg(feature = "lnk")]
+mod builder;
+mod header;
+mod link_info;
+mod target;
+
+pub use builder::ShellLinkBuilder;
+pub use header::{LinkFlags, ShellLinkHeader, ShowCommand};
+pub use link_info::{CommonNetworkRelativeLink, DriveType, LinkInfo, VolumeID};
+pub use target::{LinkTarget, TargetPathCandidate, TargetPathSource};
+
+use crate::structs::extra_data::{ExtraData, ExtraDataTypes};
+use crate::structs::shell_items::IDList;
+use crate::structs::StringData;
+use crate::traits::{Normalize, Path};
+use crate::ReaderError;
+use byteorder::{LittleEndian, ReadBytesExt};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Cursor, Read};
+
+/// [ShellLink](https://learn.microsoft.com/en-us/openspecs/windows_protocols/ms-shllink/16cb4ca1-9339-4d0c-a68d-bf1d6cc0f943) struct parser.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ShellLink {
+    /// The path of the parsed LNK file, set by `ShellLink::from_path`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_path: Option<String>,
+    pub header: ShellLinkHeader,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_target_id_list: Option<IDList>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub link_info: Option<LinkInfo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name_string: Option<StringData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub relative_path: Option<StringData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub working_dir: Option<StringData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub command_line_arguments: Option<StringData>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon_location: Option<StringData>,
+    pub extra_data: ExtraData,
+}
+
+impl ShellLink {
+    /// The keys returned by `Normalize::normalize`, in the order they should be written as CSV columns.
+    /// The names follow the columns of LECmd. New columns are only ever added to the end of this list.
+    ///
+    /// | Column | Value |
+    /// |---|---|
+    /// | `SourceFile` | Path of the LNK file (`ShellLink::from_path`) |
+    /// | `TargetCreated`, `TargetModified`, `TargetAccessed` | Header timestamps |
+    /// | `FileSize` | Header target file size |
+    /// | `VolumeSerialNumber`, `VolumeLabel`, `DriveType` | LinkInfo VolumeID |
+    /// | `LocalPath` | LinkInfo local base path |
+    /// | `NetworkPath` | LinkInfo network share name |
+    /// | `CommonPath` | LinkInfo common path suffix |
+    /// | `MachineID`, `MachineMACAddress`, `TrackerCreatedOn` | TrackerDataBlock |
+    /// | `VolumeDroid`, `FileDroid`, `VolumeDroidBirth`, `FileDroidBirth` | TrackerDataBlock droids |
+    /// | `Arguments`, `WorkingDirectory` | StringData |
+    /// | `Target` | Resolved target path, see `ShellLink::resolve_target` |
+    ///
+    /// Columns without a value are set to an empty string.
+    pub const NORMALIZED_COLUMNS: [&'static str; 21] = [
+        "SourceFile",
+        "TargetCreated",
+        "TargetModified",
+        "TargetAccessed",
+        "FileSize",
+        "VolumeSerialNumber",
+        "VolumeLabel",
+        "DriveType",
+        "LocalPath",
+        "NetworkPath",
+        "CommonPath",
+        "MachineID",
+        "MachineMACAddress",
+        "TrackerCreatedOn",
+        "VolumeDroid",
+        "FileDroid",
+        "VolumeDroidBirth",
+        "FileDroidBirth",
+        "Arguments",
+        "WorkingDirectory",
+        "Target",
+    ];
+
+    /// Parse the LNK file at `path`, the path is kept in `source_path`.
+    pub fn from_path(path: &str) -> Result<Self, ReaderError> {
+        let mut shell_link = Self::from_reader(&mut BufReader::new(File::open(path)?))?;
+        shell_link.source_path = Some(path.to_string());
+        Ok(shell_link)
+    }
+
+    pub fn from_buffer(buf: &[u8]) -> Result<Self, ReaderError> {
+        Self::from_reader(&mut Cursor::new(buf))
+    }
+
+    pub fn from_reader<R: Read>(r: &mut R) -> Result<Self, ReaderError> {
+        let header = ShellLinkHeader::from_reader(r)?;
+
+        let link_target_id_list = match header.has_flag(LinkFlags::HasLinkTargetIDList) {
+            true => {
+                let id_list_size = r.read_u16::<LittleEndian>()?;
+                let mut id_list_data = vec![0; id_list_size as usize];
+                r.read_exact(&mut id_list_data)?;
+                Some(IDList::from_buffer(&id_list_data)?)
+            }
+            false => None,
+        };
+
+        let link_info = match header.has_flag(LinkFlags::HasLinkInfo) {
+            true => Some(LinkInfo::from_reader(r)?),
+            false => None,
+        };
+
+        let is_unicode = header.has_flag(LinkFlags::IsUnicode);
+        let mut read_string_data = |flag: LinkFlags| -> Result<Option<StringData>, ReaderError> {
+            match (header.has_flag(flag), is_unicode) {
+                (true, true) => Ok(Some(StringData::from_reader(r)?)),
+                (true, false) => Ok(Some(StringData::from_reader_cp1252(r)?)),
+                (false, _) => Ok(None),
+            }
+        };
+        let name_string = read_string_data(LinkFlags::HasName)?;
+        let relative_path = read_string_data(LinkFlags::HasRelativePath)?;
+        let working_dir = read_string_data(LinkFlags::HasWorkingDir)?;
+        let command_line_arguments = read_string_data(LinkFlags::HasArguments)?;
+        let icon_location = read_string_data(LinkFlags::HasIconLocation)?;
+
+        let extra_data = ExtraData::from_reader(r)?;
+
+        Ok(Self {
+            source_path: None,
+            header,
+            link_target_id_list,
+            link_info,
+            name_string,
+            relative_path,
+            working_dir,
+            command_line_arguments,
+            icon_location,
+            extra_data,
+        })
+    }
+
+    /// Resolve the link target path from all the structures that record it, see `LinkTarget`.
+    /// `link_path` is the path of the LNK file and is used to resolve the relative path.
+    pub fn resolve_target(&self, link_path: Option<&str>) -> LinkTarget {
+        LinkTarget::resolve(self, link_path)
+    }
+}
+
+impl Path for ShellLink {
+    fn path(&self) -> Option<String> {
+        self.resolve_target(None).path
+    }
+}
+
+impl Normalize for ShellLink {
+    fn normalize(&self) -> HashMap<String, String> {
+        let mut values: HashMap<&str, String> = HashMap::new();
+        values.insert("SourceFile", self.source_path.clone().unwrap_or_default());
+        values.insert("TargetCreated", self.header.ctime.to_string());
+        values.insert("TargetModified", self.header.mtime.to_string());
+        values.insert("TargetAccessed", self.header.atime.to_string());
+        values.insert("FileSize", self.header.file_size.to_string());
+
+        if let Some(link_info) = &self.link_info {
+            if let Some(volume_id) = &link_info.volume_id {
+                values.insert(
+                    "VolumeSerialNumber",
+                    format!("{:08X}", volume_id.drive_serial_number),
+                );
+                values.insert("VolumeLabel", volume_id.volume_label.clone());
+                values.insert("DriveType", volume_id.drive_type.to_string());
+            }
+            if let Some(local_base_path) = link_info.local_base_path() {
+                values.insert("LocalPath", local_base_path.to_string());
+            }
+            if let Some(network) = &link_info.common_network_relative_link {
+                values.insert("NetworkPath", network.net_name.clone());
+            }
+            if let Some(common_path_suffix) = link_info.common_path_suffix() {
+                values.insert("CommonPath", common_path_suffix.to_string());
+            }
+        }
+
+        let tracker = self.extra_data.blocks.iter().find_map(|block| match block {
+            ExtraDataTypes::Tracker(tracker) => Some(tracker),
+            _ => None,
+        });
+        if let Some(tracker) = tracker {
+            values.insert("MachineID", tracker.machine_id.clone());
+            values.insert("MachineMACAddress", tracker.mac_address.clone());
+            if let Some(droid_file_time) = &tracker.droid_file_time {
+                values.insert("TrackerCreatedOn", droid_file_time.to_string());
+            }
+            values.insert("VolumeDroid", tracker.droid_volume_id.to_string());
+            values.insert("FileDroid", tracker.droid_file_id.to_string());
+            values.insert(
+                "VolumeDroidBirth",
+                tracker.birth_droid_volume_id.to_string(),
+            );
+            values.insert("FileDroidBirth", tracker.birth_droid_file_id.to_string());
+        }
+
+        if let Some(arguments) = &self.command_line_arguments {
+            values.insert("Arguments", arguments.string.clone());
+        }
+        if let Some(working_dir) = &self.working_dir {
+            values.insert("WorkingDirectory", working_dir.string.clone());
+        }
+        values.insert("Target", self.path().unwrap_or_default());
+
+        Self::NORMALIZED_COLUMNS
+            .iter()
+            .map(|column| {
+                (
+                    column.to_string(),
+                    values.remove(column).unwrap_or_default(),
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,192 @@
+use super::ShellLink;
+use crate::structs::extra_data::ExtraDataTypes;
+use crate::traits::Path;
+use serde::{Deserialize, Serialize};
+
+/// The structure of the shell link a target path was taken from.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum TargetPathSource {
+    /// `LinkInfo` local base path followed by the common path suffix.
+    LinkInfoLocalPath,
+    /// `LinkInfo` network share name followed by the common path suffix.
+    LinkInfoNetworkPath,
+    /// Path built from the link target `IDList`.
+    IDList,
+    /// The `RELATIVE_PATH` StringData.
+    RelativePath,
+    /// The `EnvironmentVariableDataBlock` target.
+    EnvironmentVariable,
+}
+
+/// A target path recorded in the shell link.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TargetPathCandidate {
+    pub source: TargetPathSource,
+    pub path: String,
+}
+
+/// The result of resolving the target of a shell link. The target path is taken from the first available source in this order:
+/// `LinkInfoLocalPath`, `LinkInfoNetworkPath`, `IDList`, `EnvironmentVariable` and `RelativePath`.
+/// Sources that point to a different path than the resolved one are listed in `disagreements`. This usually means
+/// the link was modified or the target was moved.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LinkTarget {
+    pub path: Option<String>,
+    pub source: Option<TargetPathSource>,
+    pub candidates: Vec<TargetPathCandidate>,
+    pub disagreements: Vec<TargetPathCandidate>,
+}
+
+impl LinkTarget {
+    /// Resolve the target of `shell_link`. `link_path` is the path of the LNK file, it is used to resolve the
+    /// relative path. If it is not known the relative path is only compared to the end of the other paths.
+    pub fn resolve(shell_link: &ShellLink, link_path: Option<&str>) -> Self {
+        let mut candidates = vec![];
+        let mut push = |source: TargetPathSource, path: Option<String>| {
+            if let Some(path) = path.filter(|p| !p.is_empty()) {
+                candidates.push(TargetPathCandidate { source, path });
+            }
+        };
+
+        let mut device_name = None;
+        if let Some(link_info) = &shell_link.link_info {
+            let suffix = link_info.common_path_suffix().unwrap_or_default();
+            push(
+                TargetPathSource::LinkInfoLocalPath,
+                link_info.local_base_path().map(|base| join(base, suffix)),
+            );
+            if let Some(network) = &link_info.common_network_relative_link {
+                push(
+                    TargetPathSource::LinkInfoNetworkPath,
+                    Some(join(&network.net_name, suffix)),
+                );
+                device_name = network
+                    .device_name
+                    .as_ref()
+                    .map(|device| (join(device, suffix), join(&network.net_name, suffix)));
+            }
+        }
+        push(
+            TargetPathSource::IDList,
+            shell_link
+                .link_target_id_list
+                .as_ref()
+                .and_then(|id_list| id_list.path())
+                .map(|path| strip_root_items(&path)),
+        );
+        push(
+            TargetPathSource::EnvironmentVariable,
+            shell_link
+                .extra_data
+                .blocks
+                .iter()
+                .find_map(|block| match block {
+                    ExtraDataTypes::EnvironmentVariable(block) => Some(block.target().to_string()),
+                    _ => None,
+                }),
+        );
+        push(
+            TargetPathSource::RelativePath,
+            shell_link
+                .relative_path
+                .as_ref()
+                .map(|relative_path| match link_path {
+                    Some(link_path) => resolve_relative(link_path, &relative_path.string),
+                    None => relative_path.string.clone(),
+                }),
+        );
+
+        let winner = candidates.first().cloned();
+        let disagreements = match &winner {
+            Some(winner) => candidates
+                .iter()
+                .skip(1)
+                .filter(|candidate| {
+                    let mut forms = vec![winner.path.as_str()];
+                    if let Some((device_path, network_path)) = &device_name {
+                        forms.push(device_path);
+                        forms.push(network_path);
+                    }
+                    !forms.iter().any(|form| paths_agree(form, candidate))
+                })
+                .cloned()
+                .collect(),
+            None => vec![],
+        };
+
+        Self {
+            path: winner.as_ref().map(|w| w.path.clone()),
+            source: winner.map(|w| w.source),
+            candidates,
+            disagreements,
+        }
+    }
+
+    pub fn has_disagreements(&self) -> bool {
+        !self.disagreements.is_empty()
+    }
+}
+
+/// Join a base path with a path suffix (e.g. `\\server\share` and `dir\file.txt`).
+fn join(base: &str, suffix: &str) -> String {
+    match (base.ends_with('\\'), suffix.is_empty()) {
+        (_, true) | (true, _) => format!("{}{}", base, suffix),
+        (false, false) => format!("{}\\{}", base, suffix),
+    }
+}
+
+/// Remove the shell items names that come before the file system path (e.g. `MY_COMPUTER\C:\Windows` becomes `C:\Windows`).
+fn strip_root_items(path: &str) -> String {
+    let components = path.split('\\').collect::<Vec<&str>>();
+    match components
+        .iter()
+        .position(|c| c.len() == 2 && c.ends_with(':'))
+    {
+        Some(index) => components[index..].join("\\"),
+        None => path.to_string(),
+    }
+}
+
+/// Resolve `relative_path` against the directory of the LNK file at `link_path`.
+fn resolve_relative(link_path: &str, relative_path: &str) -> String {
+    let link_path = link_path.replace('/', "\\");
+    let mut components = link_path.split('\\').collect::<Vec<&str>>();
+    components.pop(); // the LNK file name
+    for component in relative_path.split('\\') {
+        match component {
+            "." | "" => {}
+            ".." => {
+                if components.len() > 1 {
+                    components.pop();
+                }
+            }
+            other => components.push(other),
+        }
+    }
+    components.join("\\")
+}
+
+/// The lowercase path components without `.` and `..`.
+fn components(path: &str) -> Vec<String> {
+    path.replace('/', "\\")
+        .split('\\')
+        .filter(|c| !c.is_empty() && *c != "." && *c != "..")
+        .map(|c| c.to_lowercase())
+        .collect()
+}
+
+/// Returns true if `candidate` points to `path`. Paths that are not absolute (relative paths and paths
+/// that start with an environment variable) are compared to the end of `path`.
+fn paths_agree(path: &str, candidate: &TargetPathCandidate) -> bool {
+    let is_absolute = candidate.path.starts_with("\\\\") || candidate.path.get(1..2) == Some(":");
+    let path = components(path);
+    let mut other = components(&candidate.path);
+    if other.first().map(|c| c.starts_with('%')).unwrap_or(false) {
+        // Variables such as %windir% can not be expanded, compare what follows them
+        other.remove(0);
+    }
+    match is_absolute {
+        true => path == other,
+        false => !other.is_empty() && path.ends_with(&other),
+    }
+}
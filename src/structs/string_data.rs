@@ -1,9 +1,9 @@
 use crate::{
-    utils::{read_cp1252_string, read_utf16_string, read_utf8_string},
+    utils::{encode_cp1252, read_cp1252_string, read_utf16_string, read_utf8_string},
     ReaderError,
 };
 use byteorder::{LittleEndian, ReadBytesExt};
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 use std::io::{Cursor, Read};
 
@@ -39,6 +39,34 @@ impl StringData {
 
         Ok(Self { size, string })
     }
+
+    /// Returns the StringData in its binary form, the string is written as UTF-16 if `is_unicode`
+    /// is `true` otherwise as CP1252 (characters that can not be encoded are written as `?`).
+    pub fn to_bytes(&self, is_unicode: bool) -> Vec<u8> {
+        let mut bytes = vec![];
+        match is_unicode {
+            true => {
+                let chars = self.string.encode_utf16().collect::<Vec<u16>>();
+                bytes.extend_from_slice(&(chars.len() as u16).to_le_bytes());
+                chars.iter().for_each(|c| bytes.extend_from_slice(&c.to_le_bytes()));
+            }
+            false => {
+                let chars = encode_cp1252(&self.string);
+                bytes.extend_from_slice(&(chars.len() as u16).to_le_bytes());
+                bytes.extend_from_slice(&chars);
+            }
+        }
+        bytes
+    }
+}
+
+impl From<&str> for StringData {
+    fn from(string: &str) -> Self {
+        Self {
+            size: string.encode_utf16().count() as u16,
+            string: string.to_string(),
+        }
+    }
 }
 
 impl Display for StringData {
@@ -55,3 +83,12 @@ impl Serialize for StringData {
         serializer.serialize_str(&self.to_string())
     }
 }
+
+impl<'de> Deserialize<'de> for StringData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(StringData::from(String::deserialize(deserializer)?.as_str()))
+    }
+}
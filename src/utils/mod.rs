@@ -1,6 +1,6 @@
 //! Utilites used for formating data.
 mod rot13;
-use encoding_rs::WINDOWS_1252;
+use encoding_rs::{Encoding, WINDOWS_1252};
 pub use rot13::Rot13;
 
 use byteorder::{LittleEndian, ReadBytesExt};
@@ -8,6 +8,8 @@ use std::io::{self, Read};
 use std::{char::decode_utf16, str::from_utf8};
 
 use crate::ReaderError;
+#[cfg(any(feature = "property_store", feature = "shell_items"))]
+use serde::{de::Error as _, Deserialize, Deserializer, Serializer};
 // https://github.com/omerbenamram/mft/blob/master/src/utils.rs
 /// Read UTF-16LE string from a stream and return it as `String`.
 pub fn read_utf16_string<T: Read>(
@@ -123,6 +125,121 @@ pub fn read_cp1252_string<R: Read>(
     }
 }
 
+/// Encode a string using CP1252, characters that can not be encoded are replaced with `?`.
+pub fn encode_cp1252(string: &str) -> Vec<u8> {
+    let mut buffer = vec![];
+    let mut char_buffer = [0; 4];
+    for c in string.chars() {
+        let (bytes, _encoding_used, had_errors) =
+            WINDOWS_1252.encode(c.encode_utf8(&mut char_buffer));
+        match had_errors {
+            true => buffer.push(b'?'),
+            false => buffer.extend_from_slice(&bytes),
+        }
+    }
+    buffer
+}
+
+/// The `CP_WINUNICODE` code page (UTF-16LE).
+pub const CP_WINUNICODE: u16 = 1200;
+
+/// Returns the encoding of a Windows code page identifier. Unknown code pages are treated as CP1252,
+/// UTF-16 code pages (`1200` and `1201`) return `None`.
+fn code_page_encoding(code_page: u16) -> Option<&'static Encoding> {
+    let encoding = match code_page {
+        1200 | 1201 => return None,
+        437 | 850 | 1252 | 20127 | 28591 => WINDOWS_1252,
+        866 => encoding_rs::IBM866,
+        874 => encoding_rs::WINDOWS_874,
+        932 => encoding_rs::SHIFT_JIS,
+        936 => encoding_rs::GBK,
+        949 => encoding_rs::EUC_KR,
+        950 => encoding_rs::BIG5,
+        1250 => encoding_rs::WINDOWS_1250,
+        1251 => encoding_rs::WINDOWS_1251,
+        1253 => encoding_rs::WINDOWS_1253,
+        1254 => encoding_rs::WINDOWS_1254,
+        1255 => encoding_rs::WINDOWS_1255,
+        1256 => encoding_rs::WINDOWS_1256,
+        1257 => encoding_rs::WINDOWS_1257,
+        1258 => encoding_rs::WINDOWS_1258,
+        10000 => encoding_rs::MACINTOSH,
+        20866 => encoding_rs::KOI8_R,
+        21866 => encoding_rs::KOI8_U,
+        28592 => encoding_rs::ISO_8859_2,
+        28595 => encoding_rs::ISO_8859_5,
+        28597 => encoding_rs::ISO_8859_7,
+        50220 => encoding_rs::ISO_2022_JP,
+        51932 => encoding_rs::EUC_JP,
+        54936 => encoding_rs::GB18030,
+        65001 => encoding_rs::UTF_8,
+        _ => WINDOWS_1252,
+    };
+    Some(encoding)
+}
+
+/// Read a string of `len` bytes encoded with the Windows code page `code_page` (e.g. `1252`, `932` or `CP_WINUNICODE`).
+/// The string ends at the first NUL character, invalid characters are replaced with `U+FFFD`.
+pub fn read_code_page_string<R: Read>(
+    stream: &mut R,
+    len: usize,
+    code_page: u16,
+) -> Result<String, ReaderError> {
+    let mut buffer = vec![];
+    stream.take(len as u64).read_to_end(&mut buffer)?;
+    if buffer.len() < len {
+        return Err(ReaderError::from(io::Error::from(
+            io::ErrorKind::UnexpectedEof,
+        )));
+    }
+    match code_page_encoding(code_page) {
+        Some(encoding) => {
+            let end = buffer
+                .iter()
+                .position(|&byte| byte == 0x00)
+                .unwrap_or(buffer.len());
+            let (cow, _had_errors) = encoding.decode_without_bom_handling(&buffer[..end]);
+            Ok(cow.into())
+        }
+        None => {
+            let chars = buffer.chunks_exact(2).map(|c| match code_page {
+                1201 => u16::from_be_bytes([c[0], c[1]]),
+                _ => u16::from_le_bytes([c[0], c[1]]),
+            });
+            Ok(decode_utf16(chars.take_while(|&c| c != 0x00))
+                .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+                .collect())
+        }
+    }
+}
+
+/// Encode a string using the Windows code page `code_page` without a NUL terminator.
+/// Characters that can not be encoded are replaced with `?`.
+pub fn encode_code_page(string: &str, code_page: u16) -> Vec<u8> {
+    match code_page_encoding(code_page) {
+        Some(encoding) => {
+            let mut buffer = vec![];
+            let mut char_buffer = [0; 4];
+            for c in string.chars() {
+                let (bytes, _encoding_used, had_errors) =
+                    encoding.encode(c.encode_utf8(&mut char_buffer));
+                match had_errors {
+                    true => buffer.push(b'?'),
+                    false => buffer.extend_from_slice(&bytes),
+                }
+            }
+            buffer
+        }
+        None => string
+            .encode_utf16()
+            .flat_map(|c| match code_page {
+                1201 => c.to_be_bytes(),
+                _ => c.to_le_bytes(),
+            })
+            .collect(),
+    }
+}
+
 #[allow(dead_code)]
 pub fn read_uleb128<R: Read>(reader: &mut R) -> io::Result<u64> {
     let mut result: u64 = 0;
@@ -143,10 +260,40 @@ pub fn read_uleb128<R: Read>(reader: &mut R) -> io::Result<u64> {
     Ok(result)
 }
 
-pub fn bytes_to_hex(bytes: &Vec<u8>) -> String {
+pub fn bytes_to_hex(bytes: &[u8]) -> String {
     bytes
         .iter()
         .map(|b| format!("{:02X}", b))
         .collect::<Vec<String>>()
         .join("")
 }
+
+/// Returns the bytes of a hex string (e.g. `0A1B`), `None` if the string is not valid hex.
+pub fn hex_to_bytes(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+/// Serialize raw bytes as a hex string.
+#[cfg(any(feature = "property_store", feature = "shell_items"))]
+pub(crate) fn serialize_hex<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&bytes_to_hex(bytes))
+}
+
+/// Deserialize raw bytes from a hex string.
+#[cfg(any(feature = "property_store", feature = "shell_items"))]
+pub(crate) fn deserialize_hex<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let hex = String::deserialize(deserializer)?;
+    hex_to_bytes(&hex).ok_or_else(|| D::Error::custom(format!("invalid hex string '{}'", hex)))
+}
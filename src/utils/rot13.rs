@@ -6,9 +6,9 @@ pub struct Rot13(Vec<u8>);
 impl Rot13 {
     // https://github.com/marekventur/rust-rot13/blob/master/src/lib.rs
     fn decode(&self) -> String{
-        let decoded = (*self.0).into_iter().map(|c| match *c as char {
-            'A' ..= 'M' | 'a' ..= 'm' => (*c as u8) + 13,
-            'N' ..= 'Z' | 'n' ..= 'z' => (*c as u8) - 13,
+        let decoded = self.0.iter().map(|c| match *c as char {
+            'A' ..= 'M' | 'a' ..= 'm' => *c + 13,
+            'N' ..= 'Z' | 'n' ..= 'z' => *c - 13,
             _ => *c
         }).collect::<Vec<u8>>();
         format!("{}",String::from_utf8_lossy(&decoded)).to_owned()
@@ -1,11 +1,35 @@
+use std::io::{Read, Seek, SeekFrom};
+use std::str::FromStr;
 use winparsingtools::{
-    date_time::{DosDateTime, FileTime},
+    cfb::{CompoundFile, DocumentSummaryInformation, PropertySetStream, SummaryInformation},
+    date_time::{
+        DateTimeKind, DosDateTime, DotNetTicks, DynamicTimeZoneInformation, FileTime, FileTimeState, OleDate, SystemTime,
+        TimeInterpretation, TimeZoneInformation, TimeZoneRule, TimestampFormat, WithTimestampFormat, timestamp_format,
+        with_input_timestamp_format, with_timestamp_format, RawTimestamp, TimestampPrecision, WinTimestamp,
+    },
     file_system::{FileAttributesFlags, FileReference},
+    jump_lists::{
+        app_id, app_id_from_path, app_id_name, AutomaticDestinations, CategoryType, CustomDestinations, CustomDestinationsEntry,
+        KnownCategory,
+    },
     structs::shell_items::{IDList, ShellItem},
+    structs::extra_data::{
+        ConsoleDataBlock, ConsoleFEDataBlock, DarwinDataBlock, FontFamily, EnvironmentVariableDataBlock,
+        ExtraData, ExtraDataTypes, IconEnvironmentDataBlock, KnownFolderDataBlock,
+        PropertyStoreDataBlock, SpecialFolderDataBlock, VistaAndAboveIDListDataBlock,
+    },
+    structs::shell_link::{LinkFlags, ShellLink, ShellLinkBuilder, ShowCommand, TargetPathSource},
     structs::ExtraDataBlock,
+    structs::Guid,
+    structs::{
+        property_key_name, register_property_key, ArrayDimension, PropVariant, PropVariantArray, PropVariantVector,
+        PropertyIdentifier, PropertyKey,
+        PropertyStore,
+    },
     structs::StringData,
     utils::bytes_to_hex,
     utils::read_uleb128,
+    traits::{Normalize, Path},
     utils::Rot13,
 };
 
@@ -42,7 +66,7 @@ fn extra_data_block_test() {
     ];
     println!(
         "{:?}\n",
-        ExtraDataBlock::from_buffer(&extra_data_block).unwrap()
+        ExtraDataBlock::from_buffer(extra_data_block).unwrap()
     );
 }
 
@@ -50,7 +74,7 @@ fn extra_data_block_test() {
 #[test]
 fn file_reference_test() {
     let file_ref: &[u8] = &[0x26, 0x74, 0x02, 0x00, 0x00, 0x00, 0x08, 0x00];
-    println!("{:?}\n", FileReference::from_buffer(&file_ref).unwrap());
+    println!("{:?}\n", FileReference::from_buffer(file_ref).unwrap());
 }
 
 #[cfg(test)]
@@ -64,7 +88,7 @@ fn shell_items_test() {
         0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x39, 0xD2, 0x1B, 0x01, 0x74, 0x00, 0x65, 0x00, 0x73,
         0x00, 0x74, 0x00, 0x2E, 0x00, 0x74, 0x00, 0x78, 0x00, 0x74, 0x00, 0x00, 0x00, 0x18, 0x00,
     ];
-    println!("{:?}\n", ShellItem::from_buffer(&shell_item_data).unwrap());
+    println!("{:?}\n", ShellItem::from_buffer(shell_item_data).unwrap());
 }
 
 #[cfg(test)]
@@ -75,30 +99,32 @@ fn string_data_test() {
         0x00, 0x74, 0x00, 0x78, 0x00, 0x74, 0x00,
     ];
 
-    println!("{}\n", StringData::from_buffer(&string_data).unwrap());
+    println!("{}\n", StringData::from_buffer(string_data).unwrap());
 }
 
+#[cfg(test)]
+const ID_LIST_DATA: &[u8] = &[
+    0x14, 0x00, 0x1F, 0x50, 0xE0, 0x4F, 0xD0, 0x20, 0xEA, 0x3A, 0x69, 0x10, 0xA2, 0xD8, 0x08,
+    0x00, 0x2B, 0x30, 0x30, 0x9D, 0x19, 0x00, 0x2F, 0x43, 0x3A, 0x5C, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x56, 0x00, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x51, 0x77, 0x56, 0x10, 0x00, 0x57,
+    0x69, 0x6E, 0x64, 0x6F, 0x77, 0x73, 0x00, 0x40, 0x00, 0x09, 0x00, 0x04, 0x00, 0xEF, 0xBE,
+    0x73, 0x4E, 0xAC, 0x24, 0x10, 0x51, 0x77, 0x56, 0x2E, 0x00, 0x00, 0x00, 0x1C, 0x0D, 0x06,
+    0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0xAA, 0x8F, 0xE3, 0x00, 0x57, 0x00, 0x69, 0x00, 0x6E, 0x00, 0x64,
+    0x00, 0x6F, 0x00, 0x77, 0x00, 0x73, 0x00, 0x00, 0x00, 0x16, 0x00, 0x4E, 0x00, 0x31, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x10, 0x51, 0x40, 0x58, 0x10, 0x00, 0x54, 0x65, 0x6D, 0x70, 0x00,
+    0x00, 0x3A, 0x00, 0x09, 0x00, 0x04, 0x00, 0xEF, 0xBE, 0x73, 0x4E, 0x98, 0x26, 0x10, 0x51,
+    0x40, 0x58, 0x2E, 0x00, 0x00, 0x00, 0xF6, 0x1B, 0x06, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x50, 0xC3,
+    0x85, 0x00, 0x54, 0x00, 0x65, 0x00, 0x6D, 0x00, 0x70, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00,
+    0x00,
+];
+
 #[cfg(test)]
 #[test]
 fn id_list_test() {
-    let id_list_data: &[u8] = &[
-        0x14, 0x00, 0x1F, 0x50, 0xE0, 0x4F, 0xD0, 0x20, 0xEA, 0x3A, 0x69, 0x10, 0xA2, 0xD8, 0x08,
-        0x00, 0x2B, 0x30, 0x30, 0x9D, 0x19, 0x00, 0x2F, 0x43, 0x3A, 0x5C, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x56, 0x00, 0x31, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x51, 0x77, 0x56, 0x10, 0x00, 0x57,
-        0x69, 0x6E, 0x64, 0x6F, 0x77, 0x73, 0x00, 0x40, 0x00, 0x09, 0x00, 0x04, 0x00, 0xEF, 0xBE,
-        0x73, 0x4E, 0xAC, 0x24, 0x10, 0x51, 0x77, 0x56, 0x2E, 0x00, 0x00, 0x00, 0x1C, 0x0D, 0x06,
-        0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0xAA, 0x8F, 0xE3, 0x00, 0x57, 0x00, 0x69, 0x00, 0x6E, 0x00, 0x64,
-        0x00, 0x6F, 0x00, 0x77, 0x00, 0x73, 0x00, 0x00, 0x00, 0x16, 0x00, 0x4E, 0x00, 0x31, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x10, 0x51, 0x40, 0x58, 0x10, 0x00, 0x54, 0x65, 0x6D, 0x70, 0x00,
-        0x00, 0x3A, 0x00, 0x09, 0x00, 0x04, 0x00, 0xEF, 0xBE, 0x73, 0x4E, 0x98, 0x26, 0x10, 0x51,
-        0x40, 0x58, 0x2E, 0x00, 0x00, 0x00, 0xF6, 0x1B, 0x06, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00,
-        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x50, 0xC3,
-        0x85, 0x00, 0x54, 0x00, 0x65, 0x00, 0x6D, 0x00, 0x70, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00,
-        0x00,
-    ];
-    println!("{:?}\n", IDList::from_buffer(&id_list_data).unwrap());
+    println!("{:?}\n", IDList::from_buffer(ID_LIST_DATA).unwrap());
 }
 
 #[cfg(test)]
@@ -126,3 +152,1537 @@ fn bytes_to_hex_test() {
     println!("{}", res);
     assert!("4141414141414141" == res);
 }
+
+/// Build a string path extra data block (`EnvironmentVariable`, `IconEnvironment` or `Darwin`).
+#[cfg(test)]
+fn string_path_block(signature: u32, ansi: &str, unicode: &str) -> Vec<u8> {
+    let mut data = vec![];
+    data.extend_from_slice(&0x314u32.to_le_bytes());
+    data.extend_from_slice(&signature.to_le_bytes());
+    let mut ansi_bytes = ansi.as_bytes().to_vec();
+    ansi_bytes.resize(260, 0);
+    data.extend_from_slice(&ansi_bytes);
+    let mut unicode_bytes: Vec<u8> = unicode.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+    unicode_bytes.resize(520, 0);
+    data.extend_from_slice(&unicode_bytes);
+    data
+}
+
+#[cfg(test)]
+#[test]
+fn environment_variable_data_block_test() {
+    let data = string_path_block(0xA0000001, "%windir%\\system32\\cmd.exe", "");
+    let block = EnvironmentVariableDataBlock::from_buffer(&data).unwrap();
+    assert_eq!(block.target_ansi, "%windir%\\system32\\cmd.exe");
+    assert_eq!(block.target(), "%windir%\\system32\\cmd.exe");
+
+    let data = string_path_block(0xA0000007, "icon.ico", "%SystemRoot%\\icon.ico");
+    let block = IconEnvironmentDataBlock::from_buffer(&data).unwrap();
+    assert_eq!(block.target(), "%SystemRoot%\\icon.ico");
+    assert!(EnvironmentVariableDataBlock::from_buffer(&data).is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn darwin_data_block_test() {
+    let descriptor = "xb'BV5!!!!!!!!!MKKSkProPlusFiles>kZK{[5]P{-mozx9J'NvH";
+    let data = string_path_block(0xA0000006, descriptor, descriptor);
+    let block = DarwinDataBlock::from_buffer(&data).unwrap();
+    let descriptor = block.descriptor.unwrap();
+    assert_eq!(
+        descriptor.product_code.to_string(),
+        "90140000-0011-0000-0000-0000000FF1CE"
+    );
+    assert_eq!(descriptor.feature_name.unwrap(), "ProPlusFiles");
+    assert_eq!(
+        descriptor.component_code.unwrap().to_string(),
+        "A1B2C3D4-1111-2222-3333-444455556666"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn console_data_block_test() {
+    let mut data = vec![];
+    data.extend_from_slice(&0xCCu32.to_le_bytes());
+    data.extend_from_slice(&0xA0000002u32.to_le_bytes());
+    data.extend_from_slice(&0x07u16.to_le_bytes()); // fill attributes
+    data.extend_from_slice(&0xF5u16.to_le_bytes()); // popup fill attributes
+    for v in [120i16, 9001, 120, 30, 0, 0] {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    data.extend_from_slice(&[0; 8]); // unused
+    data.extend_from_slice(&0x00100000u32.to_le_bytes()); // font size
+    data.extend_from_slice(&0x36u32.to_le_bytes()); // FF_MODERN | TMPF_VECTOR | TMPF_TRUETYPE
+    data.extend_from_slice(&400u32.to_le_bytes());
+    let mut face_name: Vec<u8> = "Consolas".encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+    face_name.resize(64, 0);
+    data.extend_from_slice(&face_name);
+    for v in [25u32, 0, 1, 1, 1, 50, 4, 0] {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    for i in 0..16u32 {
+        data.extend_from_slice(&(i * 0x111111).to_le_bytes());
+    }
+    let block = ConsoleDataBlock::from_buffer(&data).unwrap();
+    assert_eq!(block.face_name, "Consolas");
+    assert_eq!(block.screen_buffer_size_y, 9001);
+    assert_eq!(format!("{:?}", block.font_family), "FF_MODERN");
+    assert_eq!(block.font_pitch.len(), 2);
+    assert!(block.quick_edit && block.insert_mode && !block.full_screen);
+    assert_eq!(block.history_buffer_size, 50);
+    assert_eq!(block.color_table[15], 0xFFFFFF);
+
+    let data: &[u8] = &[
+        0x0C, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0xA0, 0xE9, 0xFD, 0x00, 0x00,
+    ];
+    assert_eq!(ConsoleFEDataBlock::from_buffer(data).unwrap().code_page, 65001);
+}
+
+#[cfg(test)]
+#[test]
+fn console_font_family_test() {
+    // FontFamily of the default "Command Prompt" console (Consolas): FF_MODERN | TMPF_VECTOR | TMPF_TRUETYPE
+    assert!(matches!(FontFamily::from(0x0036), FontFamily::FF_MODERN));
+    assert!(matches!(FontFamily::from(0x0000_0036), FontFamily::FF_MODERN));
+    // Only the family nibble is used, the bits above the low byte are ignored
+    assert!(matches!(FontFamily::from(0x0190_0036), FontFamily::FF_MODERN));
+    assert!(matches!(FontFamily::from(0x0426), FontFamily::FF_SWISS));
+    assert!(matches!(FontFamily::from(0x0070), FontFamily::UNKNOWN(0x70)));
+}
+
+#[cfg(test)]
+#[test]
+fn folder_data_blocks_test() {
+    let id_list = IDList::from_buffer(ID_LIST_DATA).unwrap();
+
+    let mut data = vec![];
+    data.extend_from_slice(&0x1Cu32.to_le_bytes());
+    data.extend_from_slice(&0xA000000Bu32.to_le_bytes());
+    data.extend_from_slice(&[
+        0x04, 0xF4, 0x8B, 0xF3, 0x43, 0x1D, 0xF2, 0x42, 0x93, 0x05, 0x67, 0xDE, 0x0B, 0x28, 0xFC,
+        0x23,
+    ]);
+    data.extend_from_slice(&131u32.to_le_bytes());
+    let block = KnownFolderDataBlock::from_buffer(&data).unwrap();
+    assert_eq!(block.folder_name(), "Windows");
+    assert_eq!(block.resolve_path(&id_list).unwrap(), "Windows\\Temp");
+
+    let data: &[u8] = &[
+        0x10, 0x00, 0x00, 0x00, 0x05, 0x00, 0x00, 0xA0, 0x24, 0x00, 0x00, 0x00, 0x83, 0x00, 0x00,
+        0x00,
+    ];
+    let block = SpecialFolderDataBlock::from_buffer(data).unwrap();
+    assert_eq!(block.resolve_path(&id_list).unwrap(), "Windows\\Temp");
+    assert!(block.resolve_path(&IDList::from_buffer(&[0, 0]).unwrap()).is_none());
+
+    let mut data = vec![];
+    data.extend_from_slice(&(ID_LIST_DATA.len() as u32 + 8).to_le_bytes());
+    data.extend_from_slice(&0xA000000Cu32.to_le_bytes());
+    data.extend_from_slice(ID_LIST_DATA);
+    let block = VistaAndAboveIDListDataBlock::from_buffer(&data).unwrap();
+    assert_eq!(block.path(), id_list.path());
+}
+
+#[cfg(test)]
+#[test]
+fn extra_data_test() {
+    let droid: &[u8] = &[
+        0xA2, 0xC6, 0xB9, 0xD3, 0x5E, 0x3C, 0xEE, 0x11, 0x9A, 0x8B, 0x00, 0x0C, 0x29, 0xAA, 0xBB,
+        0xCC,
+    ];
+    let mut data = vec![];
+    // TrackerDataBlock
+    data.extend_from_slice(&0x60u32.to_le_bytes());
+    data.extend_from_slice(&0xA0000003u32.to_le_bytes());
+    data.extend_from_slice(&0x58u32.to_le_bytes());
+    data.extend_from_slice(&0u32.to_le_bytes());
+    let mut machine_id = b"desktop-1".to_vec();
+    machine_id.resize(16, 0);
+    data.extend_from_slice(&machine_id);
+    for _ in 0..4 {
+        data.extend_from_slice(droid);
+    }
+    // ShimDataBlock
+    let mut layer_name: Vec<u8> = "WinXPSp3".encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+    layer_name.resize(0x80, 0);
+    data.extend_from_slice(&0x88u32.to_le_bytes());
+    data.extend_from_slice(&0xA0000008u32.to_le_bytes());
+    data.extend_from_slice(&layer_name);
+    // Unknown signature
+    data.extend_from_slice(&[0x0C, 0x00, 0x00, 0x00, 0xFF, 0x00, 0x00, 0xA0, 0x41, 0x42, 0x43, 0x44]);
+    // ConsoleFEDataBlock with a wrong size
+    data.extend_from_slice(&[0x0A, 0x00, 0x00, 0x00, 0x04, 0x00, 0x00, 0xA0, 0xE9, 0xFD]);
+    // Terminal block followed by a payload
+    data.extend_from_slice(&[0x00, 0x00, 0x00, 0x00, 0x4D, 0x5A, 0x90, 0x00]);
+
+    let extra_data = ExtraData::from_buffer(&data).unwrap();
+    assert_eq!(extra_data.blocks.len(), 4);
+    match &extra_data.blocks[0] {
+        ExtraDataTypes::Tracker(tracker) => {
+            assert_eq!(tracker.machine_id, "desktop-1");
+            assert_eq!(tracker.mac_address, "00:0c:29:aa:bb:cc");
+            assert_eq!(
+                tracker.droid_file_time.as_ref().unwrap().to_string(),
+                "2023-08-16T18:00:47Z"
+            );
+        }
+        other => panic!("expected a TrackerDataBlock, found {:?}", other),
+    }
+    match &extra_data.blocks[1] {
+        ExtraDataTypes::Shim(shim) => assert_eq!(shim.layer_name, "WinXPSp3"),
+        other => panic!("expected a ShimDataBlock, found {:?}", other),
+    }
+    assert_eq!(extra_data.blocks[2].signature(), 0xA00000FF);
+    assert!(matches!(extra_data.blocks[3], ExtraDataTypes::Unknown(_)));
+    assert_eq!(extra_data.terminal_block, Some(0));
+    assert_eq!(extra_data.trailing_data, vec![0x4D, 0x5A, 0x90, 0x00]);
+    println!("{}", serde_json::to_string(&extra_data).unwrap());
+
+    // Truncated block
+    let extra_data = ExtraData::from_buffer(&data[..0x70]).unwrap();
+    assert_eq!(extra_data.blocks.len(), 1);
+    assert_eq!(extra_data.terminal_block, None);
+    assert_eq!(extra_data.trailing_data.len(), 0x10);
+}
+
+/// Build a serialized property storage, `values` contains the serialized values without the value size field.
+#[cfg(test)]
+fn property_storage(format_id: &[u8; 16], values: &[Vec<u8>]) -> Vec<u8> {
+    let mut data = vec![];
+    data.extend_from_slice(&0x53505331u32.to_le_bytes());
+    data.extend_from_slice(format_id);
+    for value in values {
+        data.extend_from_slice(&(value.len() as u32 + 4).to_le_bytes());
+        data.extend_from_slice(value);
+    }
+    data.extend_from_slice(&[0; 4]);
+    let mut storage = (data.len() as u32 + 4).to_le_bytes().to_vec();
+    storage.extend_from_slice(&data);
+    storage
+}
+
+#[cfg(test)]
+#[test]
+fn property_store_data_block_test() {
+    let link_format_id = [
+        0xFC, 0xB3, 0xB4, 0xB9, 0x51, 0x2B, 0x42, 0x4A, 0xB5, 0xD8, 0x32, 0x41, 0x46, 0xAF, 0xCF,
+        0x25,
+    ];
+    let string_name_format_id = [
+        0x05, 0xD5, 0xCD, 0xD5, 0x9C, 0x2E, 0x1B, 0x10, 0x93, 0x97, 0x08, 0x00, 0x2B, 0x2C, 0xF9,
+        0xAE,
+    ];
+    let mut target_value = vec![];
+    target_value.extend_from_slice(&2u32.to_le_bytes());
+    target_value.push(0);
+    target_value.extend_from_slice(&[0x1F, 0x00, 0x00, 0x00]);
+    target_value.extend_from_slice(&9u32.to_le_bytes());
+    target_value.extend("C:\\a.exe\0".encode_utf16().flat_map(|c| c.to_le_bytes()));
+    target_value.extend_from_slice(&[0, 0]);
+    let mut named_value = vec![];
+    named_value.extend_from_slice(&8u32.to_le_bytes());
+    named_value.push(0);
+    named_value.extend("Foo\0".encode_utf16().flat_map(|c| c.to_le_bytes()));
+    named_value.extend_from_slice(&[0x40, 0x00, 0x00, 0x00]);
+    named_value.extend_from_slice(&132516443881399495u64.to_le_bytes());
+
+    let mut store = property_storage(&link_format_id, &[target_value]);
+    store.extend(property_storage(&string_name_format_id, &[named_value]));
+    store.extend_from_slice(&[0; 4]);
+    let mut data = vec![];
+    data.extend_from_slice(&(store.len() as u32 + 8).to_le_bytes());
+    data.extend_from_slice(&0xA0000009u32.to_le_bytes());
+    data.extend_from_slice(&store);
+
+    let block = PropertyStoreDataBlock::from_buffer(&data).unwrap();
+    assert_eq!(block.property_store.storages.len(), 2);
+    match block.get("b9b4b3fc-2b51-4a42-b5d8-324146afcf25", 2) {
+        Some(PropVariant::LPWStr(path)) => assert_eq!(path, "C:\\a.exe"),
+        other => panic!("expected a string, found {:?}", other),
+    }
+    let named = &block.property_store.storages[1].values[0];
+    assert!(matches!(&named.identifier, PropertyIdentifier::Name(name) if name == "Foo"));
+    assert!(
+        matches!(&named.value, PropVariant::FileTime(ft) if ft.to_string() == "2020-12-05T12:19:48Z")
+    );
+    println!("{}", serde_json::to_string(&block).unwrap());
+
+    // Block size larger than the data
+    data[..4].copy_from_slice(&0xFFFFFFF0u32.to_le_bytes());
+    assert!(PropertyStoreDataBlock::from_buffer(&data).is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn property_store_test() {
+    let volume_format_id = [
+        0x04, 0x46, 0x40, 0x44, 0x2F, 0x3D, 0x8C, 0x41, 0x87, 0x6C, 0xC6, 0x18, 0x5E, 0xD8, 0xF8,
+        0x3A,
+    ];
+    let string_name_format_id = [
+        0x05, 0xD5, 0xCD, 0xD5, 0x9C, 0x2E, 0x1B, 0x10, 0x93, 0x97, 0x08, 0x00, 0x2B, 0x2C, 0xF9,
+        0xAE,
+    ];
+    let mut size_value = vec![];
+    size_value.extend_from_slice(&3u32.to_le_bytes());
+    size_value.push(0);
+    size_value.extend_from_slice(&[0x15, 0x00, 0x00, 0x00]);
+    size_value.extend_from_slice(&0x1000u64.to_le_bytes());
+    let mut named_value = vec![];
+    named_value.extend_from_slice(&8u32.to_le_bytes());
+    named_value.push(0);
+    named_value.extend("Bar\0".encode_utf16().flat_map(|c| c.to_le_bytes()));
+    named_value.extend_from_slice(&[0x13, 0x00, 0x00, 0x00]);
+    named_value.extend_from_slice(&7u32.to_le_bytes());
+
+    let mut data = property_storage(&volume_format_id, &[size_value]);
+    data.extend(property_storage(&string_name_format_id, &[named_value]));
+    data.extend_from_slice(&[0; 4]);
+    data.extend_from_slice(&[0xFF; 4]); // data after the property store
+
+    let mut cursor = std::io::Cursor::new(&data);
+    let store = PropertyStore::from_reader(&mut cursor).unwrap();
+    assert_eq!(cursor.position() as usize, data.len() - 4);
+    let entries = store.entries().collect::<Vec<_>>();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].0.to_string(), "44404604-3D2F-418C-876C-C6185ED8F83A");
+    assert!(matches!(entries[0].1, PropertyIdentifier::Id(3)));
+    assert!(matches!(
+        store.get("44404604-3d2f-418c-876c-c6185ed8f83a", 3),
+        Some(PropVariant::UI8(0x1000))
+    ));
+    assert!(matches!(store.get_by_name("Bar"), Some(PropVariant::UI4(7))));
+    assert_eq!(store.to_bytes(), data[..data.len() - 4].to_vec());
+    assert_eq!(PropertyStore::from_buffer(&store.to_bytes()).unwrap().entries().count(), 2);
+    println!("{}", serde_json::to_string(&store).unwrap());
+
+    // Property storage bigger than the remaining data
+    let mut truncated = property_storage(&volume_format_id, &[]);
+    truncated[..4].copy_from_slice(&0x100u32.to_le_bytes());
+    let error = PropertyStore::from_reader(&mut std::io::Cursor::new(&truncated)).unwrap_err();
+    assert!(error.to_string().contains("property storage is bigger than the property store"));
+    assert!(PropertyStore::from_buffer(&truncated).is_err());
+
+    // Value size larger than the data
+    data[24..28].copy_from_slice(&0xFFFFFFF0u32.to_le_bytes());
+    assert!(PropertyStore::from_buffer(&data).is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn property_key_test() {
+    assert_eq!(
+        property_key_name("b725f130-47ef-101a-a5f1-02608c9eebac", 14).as_deref(),
+        Some("System.DateModified")
+    );
+    assert_eq!(
+        property_key_name("{446D16B1-8DAD-4870-A748-402EA43D788C}", 104).as_deref(),
+        Some("System.VolumeId")
+    );
+    let storage_format_id = Guid::new(0xB725F130, 0x47EF, 0x101A, [0xA5, 0xF1, 0x02, 0x60, 0x8C, 0x9E, 0xEB, 0xAC]);
+    assert_eq!(PropertyKey::new(storage_format_id.clone(), 10).to_string(), "System.ItemNameDisplay");
+    let unknown_format_id = Guid::new(0x2E4D9C2F, 0x9A55, 0x4A41, [0x91, 0x6C, 0x51, 0x3B, 0x2C, 0x0D, 0x10, 0x77]);
+    let key = PropertyKey::new(unknown_format_id, 3);
+    assert_eq!(key.to_string(), "{2E4D9C2F-9A55-4A41-916C-513B2C0D1077}/3");
+    register_property_key("{2e4d9c2f-9a55-4a41-916c-513b2c0d1077}", 3, "Contoso.Sync.State");
+    assert_eq!(serde_json::to_string(&key).unwrap(), "\"Contoso.Sync.State\"");
+
+    let mut name_value = vec![];
+    name_value.extend_from_slice(&10u32.to_le_bytes());
+    name_value.push(0);
+    name_value.extend_from_slice(&[0x1F, 0x00, 0x00, 0x00]);
+    name_value.extend_from_slice(&2u32.to_le_bytes());
+    name_value.extend("a\0".encode_utf16().flat_map(|c| c.to_le_bytes()));
+    let mut unknown_value = vec![];
+    unknown_value.extend_from_slice(&99u32.to_le_bytes());
+    unknown_value.push(0);
+    unknown_value.extend_from_slice(&[0x13, 0x00, 0x00, 0x00, 1, 0, 0, 0]);
+    let mut data = property_storage(&storage_format_id.to_bytes(), &[name_value, unknown_value]);
+    data.extend_from_slice(&[0; 4]);
+    let store = PropertyStore::from_buffer(&data).unwrap();
+    assert_eq!(
+        serde_json::to_string(&store).unwrap(),
+        "[{\"format_id\":\"B725F130-47EF-101A-A5F1-02608C9EEBAC\",\"values\":[{\"identifier\":10,\"name\":\"System.ItemNameDisplay\",\"value\":{\"vt\":\"LPWStr\",\"value\":\"a\"}},{\"identifier\":99,\"value\":{\"vt\":\"UI4\",\"value\":1}}]}]"
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn prop_variant_test() {
+    // VT_VECTOR | VT_I2 with the padding after the elements
+    let data = [0x02, 0x10, 0, 0, 3, 0, 0, 0, 1, 0, 0xFE, 0xFF, 3, 0, 0, 0];
+    let value = PropVariant::from_buffer(&data).unwrap();
+    match &value {
+        PropVariant::Vector(vector) => {
+            assert!(matches!(vector.values[..], [PropVariant::I2(1), PropVariant::I2(-2), PropVariant::I2(3)]))
+        }
+        other => panic!("expected a vector, found {:?}", other),
+    }
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"vt":"Vector","value":{"element_type":2,"values":[{"vt":"I2","value":1},{"vt":"I2","value":-2},{"vt":"I2","value":3}]}}"#);
+    assert_eq!(value.to_bytes(), data.to_vec());
+
+    // VT_VECTOR | VT_LPSTR with the CP1251 code page followed by a VT_BOOL
+    let mut data = vec![0x1E, 0x10, 0, 0, 2, 0, 0, 0];
+    data.extend_from_slice(&[5, 0, 0, 0, 0xCF, 0xF0, 0xE8, 0xE2, 0, 0, 0, 0]);
+    data.extend_from_slice(&[2, 0, 0, 0, b'a', 0, 0, 0]);
+    data.extend_from_slice(&[0x0B, 0, 0, 0, 0xFF, 0xFF, 0, 0]);
+    let mut cursor = std::io::Cursor::new(&data);
+    let value = PropVariant::from_reader_with_code_page(&mut cursor, 1251).unwrap();
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"vt":"Vector","value":{"element_type":30,"values":[{"vt":"LPStr","value":"Прив"},{"vt":"LPStr","value":"a"}]}}"#);
+    assert_eq!(value.to_bytes_with_code_page(1251), data[..28].to_vec());
+    assert!(matches!(PropVariant::from_reader(&mut cursor).unwrap(), PropVariant::Bool(true)));
+
+    // VT_ARRAY | VT_VARIANT with two dimensions
+    let mut data = vec![0x0C, 0x20, 0, 0, 0x0C, 0, 0, 0, 2, 0, 0, 0];
+    data.extend_from_slice(&[2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 1, 0, 0, 0]);
+    data.extend_from_slice(&[0x11, 0, 0, 0, 7, 0, 0, 0]);
+    data.extend_from_slice(&[0x48, 0, 0, 0]);
+    data.extend_from_slice(&Guid::new(0x00021401, 0, 0, [0xC0, 0, 0, 0, 0, 0, 0, 0x46]).to_bytes());
+    let value = PropVariant::from_buffer(&data).unwrap();
+    match &value {
+        PropVariant::Array(array) => {
+            assert_eq!(array.dimensions.len(), 2);
+            assert_eq!(array.dimensions[1].index_offset, 1);
+            assert!(matches!(array.values[0], PropVariant::UI1(7)));
+            assert!(
+                matches!(&array.values[1], PropVariant::Clsid(clsid) if clsid.to_string() == "00021401-0000-0000-C000-000000000046")
+            );
+        }
+        other => panic!("expected an array, found {:?}", other),
+    }
+    assert_eq!(value.vt(), PropVariant::VT_ARRAY | PropVariant::VT_VARIANT);
+    assert_eq!(value.to_bytes(), data);
+
+    // VT_DECIMAL, VT_BLOB and an unknown type
+    let mut data = vec![0x0E, 0, 0, 0, 0, 0, 2, 0x80, 0, 0, 0, 0];
+    data.extend_from_slice(&12345u64.to_le_bytes());
+    let value = PropVariant::from_buffer(&data).unwrap();
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"vt":"Decimal","value":"-123.45"}"#);
+    assert_eq!(value.to_bytes(), data);
+    let data = [0x41, 0, 0, 0, 3, 0, 0, 0, 0xAA, 0xBB, 0xCC, 0];
+    let value = PropVariant::from_buffer(&data).unwrap();
+    assert_eq!(serde_json::to_string(&value).unwrap(), r#"{"vt":"Blob","value":"AABBCC"}"#);
+    assert_eq!(value.to_bytes(), data.to_vec());
+    let value = PropVariant::from_buffer(&[0x99, 0, 0, 0, 1, 2]).unwrap();
+    assert!(matches!(&value, PropVariant::Unknown { vt: 0x99, data } if data == &vec![1, 2]));
+
+    // Vectors and arrays of unknown types, and element counts larger than the data
+    assert!(PropVariant::from_buffer(&[0xFF, 0x1F, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF]).is_err());
+    assert!(PropVariant::from_buffer(&[0x0C, 0x10, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 0x99, 0, 0, 0]).is_err());
+    assert!(PropVariant::from_buffer(&[0x11, 0x10, 0, 0, 0xFF, 0xFF, 0xFF, 0xFF, 1, 2, 3]).is_err());
+    let mut data = vec![0x11, 0x20, 0, 0, 0x11, 0, 0, 0, 2, 0, 0, 0];
+    for _ in 0..2 {
+        data.extend_from_slice(&0xFFFFFFFFu32.to_le_bytes());
+        data.extend_from_slice(&0u32.to_le_bytes());
+    }
+    data.extend_from_slice(&[1, 2, 3]);
+    assert!(PropVariant::from_buffer(&data).is_err());
+    // Variant elements can't be vectors, deeply nested vectors are rejected instead of overflowing the stack
+    let data = [0x0C, 0x10, 0, 0, 1, 0, 0, 0].repeat(5000);
+    assert!(PropVariant::from_buffer(&data).is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn shell_link_builder_test() {
+    let droid = Guid::new(0x94AB3B4E, 0x3C0B, 0x11EE, [0x82, 0x3A, 0x00, 0x0C, 0x29, 0xAA, 0xBB, 0xCC]);
+    let lnk = ShellLinkBuilder::new()
+        .creation_time(FileTime::new(132516443881399495))
+        .file_attributes(0x20)
+        .file_size(201216)
+        .link_flags(&[LinkFlags::RunAsUser])
+        .show_command(ShowCommand::SW_SHOWMAXIMIZED)
+        .link_target_id_list(IDList::from_path("C:\\Windows\\notepad.exe").unwrap())
+        .local_base_path("C:\\Windows\\notepad.exe", 3, 0x1234ABCD, "OS")
+        .network_share("\\\\server\\share", Some("Z:"), Some(0x20000))
+        .name("Notepad")
+        .relative_path("..\\..\\Windows\\notepad.exe")
+        .working_dir("C:\\Windows")
+        .arguments("C:\\Users\\user\\Desktop\\ملاحظات.txt")
+        .environment_variable("%windir%\\notepad.exe")
+        .tracker("desktop-pc", droid.clone(), droid)
+        .shim("WinXPSp3")
+        .build()
+        .unwrap();
+
+    let shell_link = ShellLink::from_buffer(&lnk).unwrap();
+    assert_eq!(shell_link.header.ctime.to_string(), "2020-12-05T12:19:48Z");
+    assert_eq!(shell_link.header.file_size, 201216);
+    assert!(shell_link.header.has_flag(LinkFlags::HasExpString));
+    assert!(shell_link.header.has_flag(LinkFlags::RunWithShimLayer));
+    assert!(shell_link.header.has_flag(LinkFlags::RunAsUser));
+    assert!(!shell_link.header.has_flag(LinkFlags::HasIconLocation));
+    assert!(matches!(shell_link.header.show_command, ShowCommand::SW_SHOWMAXIMIZED));
+    assert_eq!(shell_link.link_target_id_list.unwrap().path().unwrap(), "MY_COMPUTER\\C:\\Windows\\notepad.exe");
+
+    let link_info = shell_link.link_info.unwrap();
+    assert_eq!(link_info.local_base_path(), Some("C:\\Windows\\notepad.exe"));
+    assert_eq!(link_info.volume_id.unwrap().drive_serial_number, 0x1234ABCD);
+    let network = link_info.common_network_relative_link.unwrap();
+    assert_eq!(network.net_name, "\\\\server\\share");
+    assert_eq!(network.device_name.as_deref(), Some("Z:"));
+
+    assert_eq!(shell_link.name_string.unwrap().string, "Notepad");
+    assert_eq!(shell_link.working_dir.unwrap().string, "C:\\Windows");
+    assert_eq!(
+        shell_link.command_line_arguments.unwrap().string,
+        "C:\\Users\\user\\Desktop\\ملاحظات.txt"
+    );
+    let blocks = &shell_link.extra_data.blocks;
+    assert_eq!(blocks.len(), 3);
+    match &blocks[1] {
+        ExtraDataTypes::Tracker(tracker) => {
+            assert_eq!(tracker.machine_id, "desktop-pc");
+            assert_eq!(tracker.mac_address, "00:0c:29:aa:bb:cc");
+        }
+        other => panic!("expected a TrackerDataBlock, got {:?}", other),
+    }
+    assert_eq!(shell_link.extra_data.terminal_block, Some(0));
+}
+
+#[cfg(test)]
+#[test]
+fn shell_link_target_test() {
+    let lnk = ShellLinkBuilder::new()
+        .link_target_id_list(IDList::from_path("C:\\Windows\\notepad.exe").unwrap())
+        .local_base_path("C:\\Windows\\notepad.exe", 3, 0x1234ABCD, "OS")
+        .relative_path("..\\..\\..\\Windows\\notepad.exe")
+        .environment_variable("%windir%\\notepad.exe")
+        .build()
+        .unwrap();
+    let shell_link = ShellLink::from_buffer(&lnk).unwrap();
+    let target = shell_link.resolve_target(Some("C:\\Users\\user\\Desktop\\notepad.lnk"));
+    assert_eq!(target.path.as_deref(), Some("C:\\Windows\\notepad.exe"));
+    assert_eq!(target.source, Some(TargetPathSource::LinkInfoLocalPath));
+    assert_eq!(target.candidates.len(), 4);
+    assert!(!target.has_disagreements());
+    assert_eq!(shell_link.path().as_deref(), Some("C:\\Windows\\notepad.exe"));
+
+    let lnk = ShellLinkBuilder::new()
+        .network_share("\\\\server\\share", Some("Z:"), None)
+        .common_path_suffix("tools\\setup.exe")
+        .environment_variable("%TEMP%\\payload.exe")
+        .build()
+        .unwrap();
+    let target = ShellLink::from_buffer(&lnk).unwrap().resolve_target(None);
+    assert_eq!(target.path.as_deref(), Some("\\\\server\\share\\tools\\setup.exe"));
+    assert_eq!(target.source, Some(TargetPathSource::LinkInfoNetworkPath));
+    assert_eq!(target.disagreements.len(), 1);
+    assert_eq!(target.disagreements[0].source, TargetPathSource::EnvironmentVariable);
+
+    // StringData limits are in characters, not UTF-8 bytes
+    let arguments = "\u{20AC}".repeat(30000);
+    let lnk = ShellLinkBuilder::new().arguments(&arguments).build().unwrap();
+    let shell_link = ShellLink::from_buffer(&lnk).unwrap();
+    assert_eq!(shell_link.command_line_arguments.unwrap().string, arguments);
+    let lnk = ShellLinkBuilder::new().unicode(false).arguments(&arguments).build();
+    assert!(lnk.is_ok());
+    assert!(ShellLinkBuilder::new().arguments(&"a".repeat(65536)).build().is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn shell_link_normalize_test() {
+    let droid = Guid::new(0x94AB3B4E, 0x3C0B, 0x11EE, [0x82, 0x3A, 0x00, 0x0C, 0x29, 0xAA, 0xBB, 0xCC]);
+    let lnk = ShellLinkBuilder::new()
+        .write_time(FileTime::new(132516443881399495))
+        .file_size(1024)
+        .local_base_path("C:\\Tools\\run.exe", 3, 0x1234ABCD, "OS")
+        .arguments("-v")
+        .tracker("desktop-pc", droid.clone(), droid)
+        .build()
+        .unwrap();
+    let path = std::env::temp_dir().join("winparsingtools_normalize_test.lnk");
+    std::fs::write(&path, &lnk).unwrap();
+    let shell_link = ShellLink::from_path(path.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    let normalized = shell_link.normalize();
+    assert_eq!(normalized.len(), ShellLink::NORMALIZED_COLUMNS.len());
+    assert_eq!(normalized["SourceFile"], path.to_str().unwrap());
+    assert_eq!(normalized["TargetModified"], "2020-12-05T12:19:48Z");
+    assert_eq!(normalized["FileSize"], "1024");
+    assert_eq!(normalized["VolumeSerialNumber"], "1234ABCD");
+    assert_eq!(normalized["DriveType"], "DRIVE_FIXED");
+    assert_eq!(normalized["LocalPath"], "C:\\Tools\\run.exe");
+    assert_eq!(normalized["NetworkPath"], "");
+    assert_eq!(normalized["MachineMACAddress"], "00:0c:29:aa:bb:cc");
+    assert_eq!(normalized["FileDroid"], "94AB3B4E-3C0B-11EE-823A-000C29AABBCC");
+    assert_eq!(normalized["Arguments"], "-v");
+    assert_eq!(normalized["Target"], "C:\\Tools\\run.exe");
+}
+
+/// Build a version 3 compound file with `streams` stored under the root storage. Streams smaller than
+/// 4096 bytes are stored in the mini stream.
+#[cfg(test)]
+fn compound_file(streams: &[(&str, Vec<u8>)]) -> Vec<u8> {
+    const END: u32 = 0xFFFFFFFE;
+    let sectors = |len: usize, size: usize| len.div_ceil(size);
+    let chain = |table: &mut Vec<u32>, start: usize, count: usize| {
+        for i in 0..count {
+            table[start + i] = if i + 1 == count { END } else { (start + i + 1) as u32 };
+        }
+    };
+
+    let mut mini_stream = vec![];
+    let mut mini_fat = vec![];
+    let mut large_streams = vec![];
+    let mut starts = vec![];
+    for (_, data) in streams {
+        if data.len() < 4096 {
+            let start = mini_fat.len();
+            let count = sectors(data.len(), 64);
+            mini_fat.resize(start + count, 0);
+            chain(&mut mini_fat, start, count);
+            starts.push(if count == 0 { END } else { start as u32 });
+            mini_stream.extend_from_slice(data);
+            mini_stream.resize((start + count) * 64, 0);
+        } else {
+            starts.push(u32::MAX); // set below
+            large_streams.push(data.clone());
+        }
+    }
+    let dir_sectors = sectors(streams.len() + 1, 4);
+    let mini_fat_sectors = sectors(mini_fat.len(), 128);
+    let mini_stream_sectors = sectors(mini_stream.len(), 512);
+    let mut fat = vec![0xFFFFFFFFu32; 128];
+    fat[0] = 0xFFFFFFFD;
+    let mut next = 1;
+    let dir_start = next;
+    chain(&mut fat, next, dir_sectors);
+    next += dir_sectors;
+    let mini_fat_start = next;
+    chain(&mut fat, next, mini_fat_sectors);
+    next += mini_fat_sectors;
+    let mini_stream_start = next;
+    chain(&mut fat, next, mini_stream_sectors);
+    next += mini_stream_sectors;
+    let mut large_data = vec![];
+    for (index, (_, data)) in streams.iter().enumerate() {
+        if data.len() >= 4096 {
+            let count = sectors(data.len(), 512);
+            starts[index] = next as u32;
+            chain(&mut fat, next, count);
+            next += count;
+            large_data.extend_from_slice(data);
+            large_data.resize((next - mini_stream_start - mini_stream_sectors) * 512, 0);
+        }
+    }
+
+    let mut file = vec![];
+    file.extend_from_slice(&[0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1]);
+    file.extend_from_slice(&[0; 16]);
+    for v in [0x3Eu16, 3, 0xFFFE, 9, 6, 0, 0, 0] {
+        file.extend_from_slice(&v.to_le_bytes());
+    }
+    for v in [0u32, 1, dir_start as u32, 0, 4096] {
+        file.extend_from_slice(&v.to_le_bytes());
+    }
+    let mini_fat_first = if mini_fat_sectors == 0 { END } else { mini_fat_start as u32 };
+    for v in [mini_fat_first, mini_fat_sectors as u32, END, 0, 0] {
+        file.extend_from_slice(&v.to_le_bytes());
+    }
+    file.truncate(0x4C);
+    let mut difat = vec![0xFFFFFFFFu32; 109];
+    difat[0] = 0;
+    difat.iter().for_each(|v| file.extend_from_slice(&v.to_le_bytes()));
+    fat.iter().for_each(|v| file.extend_from_slice(&v.to_le_bytes()));
+
+    let entry = |name: &str, object_type: u8, right: u32, child: u32, start: u32, size: u64| {
+        let mut entry = vec![];
+        let mut name_data: Vec<u8> = name.encode_utf16().chain([0]).flat_map(|c| c.to_le_bytes()).collect();
+        let name_size = name_data.len() as u16;
+        name_data.resize(64, 0);
+        entry.extend_from_slice(&name_data);
+        entry.extend_from_slice(&name_size.to_le_bytes());
+        entry.extend_from_slice(&[object_type, 1]);
+        entry.extend_from_slice(&0xFFFFFFFFu32.to_le_bytes());
+        entry.extend_from_slice(&right.to_le_bytes());
+        entry.extend_from_slice(&child.to_le_bytes());
+        entry.extend_from_slice(&[0; 20]);
+        entry.extend_from_slice(&132516443881399495u64.to_le_bytes());
+        entry.extend_from_slice(&132516443881399495u64.to_le_bytes());
+        entry.extend_from_slice(&start.to_le_bytes());
+        entry.extend_from_slice(&size.to_le_bytes());
+        entry
+    };
+    let mut directory = vec![];
+    let root_start = if mini_stream_sectors == 0 { END } else { mini_stream_start as u32 };
+    let root_child = if streams.is_empty() { 0xFFFFFFFF } else { 1 };
+    directory.extend(entry("Root Entry", 5, 0xFFFFFFFF, root_child, root_start, mini_stream.len() as u64));
+    for (index, (name, data)) in streams.iter().enumerate() {
+        let right = if index + 1 == streams.len() { 0xFFFFFFFF } else { index as u32 + 2 };
+        directory.extend(entry(name, 2, right, 0xFFFFFFFF, starts[index], data.len() as u64));
+    }
+    directory.resize(dir_sectors * 512, 0);
+    file.extend_from_slice(&directory);
+    let mut mini_fat_data: Vec<u8> = mini_fat.iter().flat_map(|v| v.to_le_bytes()).collect();
+    mini_fat_data.resize(mini_fat_sectors * 512, 0xFF);
+    file.extend_from_slice(&mini_fat_data);
+    mini_stream.resize(mini_stream_sectors * 512, 0);
+    file.extend_from_slice(&mini_stream);
+    file.extend_from_slice(&large_data);
+    file
+}
+
+#[cfg(test)]
+#[test]
+fn compound_file_test() {
+    let small: Vec<u8> = (0..100u8).collect();
+    let large: Vec<u8> = (0..5000u32).map(|i| (i % 251) as u8).collect();
+    let data = compound_file(&[("DestList", small.clone()), ("1", large.clone()), ("Empty", vec![])]);
+    let mut compound_file = CompoundFile::from_reader(std::io::Cursor::new(data)).unwrap();
+    assert_eq!(compound_file.header.sector_size(), 512);
+    assert_eq!(compound_file.root().name, "Root Entry");
+    assert_eq!(compound_file.root().mtime.as_ref().unwrap().to_string(), "2020-12-05T12:19:48Z");
+    let names: Vec<String> = compound_file.walk().iter().map(|(path, _)| path.clone()).collect();
+    assert_eq!(names, vec!["DestList", "1", "Empty"]);
+
+    assert_eq!(compound_file.read_stream("destlist").unwrap(), small);
+    assert_eq!(compound_file.read_stream("1").unwrap(), large);
+    assert!(compound_file.read_stream("Empty").unwrap().is_empty());
+    assert!(compound_file.open_stream("missing").is_err());
+
+    let mut stream = compound_file.open_stream("1").unwrap();
+    stream.seek(SeekFrom::Start(4090)).unwrap();
+    let mut buf = [0; 20];
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf[..], &large[4090..4110]);
+    assert_eq!(stream.seek(SeekFrom::End(-10)).unwrap(), 4990);
+
+    // A DIFAT sector that points to itself with a huge DIFAT sector count
+    let mut data = self::compound_file(&[]);
+    data.truncate(1024);
+    data[68..72].copy_from_slice(&0u32.to_le_bytes());
+    data[72..76].copy_from_slice(&0xFFFFFFFFu32.to_le_bytes());
+    data[1020..1024].copy_from_slice(&0u32.to_le_bytes());
+    let error = CompoundFile::from_reader(std::io::Cursor::new(data)).unwrap_err();
+    assert!(error.to_string().contains("Loop in the DIFAT chain"));
+
+    // A storage whose child is the root storage
+    let mut data = self::compound_file(&[("DestList", small), ("Storage", vec![])]);
+    let entry = 1024 + 2 * 128;
+    data[entry + 66] = 1;
+    data[entry + 76..entry + 80].copy_from_slice(&0u32.to_le_bytes());
+    let cyclic_file = CompoundFile::from_reader(std::io::Cursor::new(data)).unwrap();
+    let names: Vec<String> = cyclic_file.walk().iter().map(|(path, _)| path.clone()).collect();
+    assert_eq!(names, vec!["DestList", "Storage"]);
+}
+
+/// A property set format ID and `(property_id, serialized_value)` pairs.
+#[cfg(test)]
+type TestPropertySet = ([u8; 16], Vec<(u32, Vec<u8>)>);
+
+/// Build a property set stream from the property sets.
+#[cfg(test)]
+fn property_set_stream(property_sets: &[TestPropertySet]) -> Vec<u8> {
+    let mut data = vec![0xFE, 0xFF, 0, 0, 0x0A, 0, 0x02, 0];
+    data.extend_from_slice(&[0; 16]);
+    data.extend_from_slice(&(property_sets.len() as u32).to_le_bytes());
+    let mut offset = data.len() + property_sets.len() * 20;
+    let mut sets = vec![];
+    for (format_id, properties) in property_sets {
+        let mut set = vec![];
+        let mut values = vec![];
+        let header_size = 8 + properties.len() * 8;
+        for (id, value) in properties {
+            set.extend_from_slice(&id.to_le_bytes());
+            set.extend_from_slice(&((header_size + values.len()) as u32).to_le_bytes());
+            values.extend_from_slice(value);
+        }
+        let mut property_set = ((header_size + values.len()) as u32).to_le_bytes().to_vec();
+        property_set.extend_from_slice(&(properties.len() as u32).to_le_bytes());
+        property_set.extend(set);
+        property_set.extend(values);
+        data.extend_from_slice(format_id);
+        data.extend_from_slice(&(offset as u32).to_le_bytes());
+        offset += property_set.len();
+        sets.extend(property_set);
+    }
+    data.extend(sets);
+    data
+}
+
+#[cfg(test)]
+#[test]
+fn summary_information_test() {
+    let summary_format_id = Guid::new(0xF29F85E0, 0x4FF9, 0x1068, [0xAB, 0x91, 0x08, 0x00, 0x2B, 0x27, 0xB3, 0xD9]).to_bytes();
+    let summary = property_set_stream(&[(
+        summary_format_id,
+        vec![
+            (1, PropVariant::I2(1252).to_bytes()),
+            (2, PropVariant::LPStr("Q3 Résumé".to_string()).to_bytes_with_code_page(1252)),
+            (4, PropVariant::LPStr("alice".to_string()).to_bytes_with_code_page(1252)),
+            (7, PropVariant::LPStr("Normal.dotm".to_string()).to_bytes_with_code_page(1252)),
+            (8, PropVariant::LPStr("bob".to_string()).to_bytes_with_code_page(1252)),
+            (10, PropVariant::FileTime(FileTime::new(3_000_000_000)).to_bytes()),
+            (12, PropVariant::FileTime(FileTime::new(132516443881399495)).to_bytes()),
+            (13, PropVariant::FileTime(FileTime::new(0)).to_bytes()),
+            (14, PropVariant::I4(3).to_bytes()),
+            (18, PropVariant::LPStr("Microsoft Office Word".to_string()).to_bytes_with_code_page(1252)),
+        ],
+    )]);
+
+    let mut user_defined = vec![];
+    user_defined.extend_from_slice(&2u32.to_le_bytes());
+    for (id, name) in [(2u32, "Client"), (3, "Reviewed")] {
+        let name = format!("{}\0", name).encode_utf16().flat_map(|c| c.to_le_bytes()).collect::<Vec<u8>>();
+        user_defined.extend_from_slice(&id.to_le_bytes());
+        user_defined.extend_from_slice(&(name.len() as u32 / 2).to_le_bytes());
+        user_defined.extend_from_slice(&name);
+        user_defined.resize(user_defined.len().div_ceil(4) * 4, 0);
+    }
+    let document_summary = property_set_stream(&[
+        (
+            Guid::new(0xD5CDD502, 0x2E9C, 0x101B, [0x93, 0x97, 0x08, 0x00, 0x2B, 0x2C, 0xF9, 0xAE]).to_bytes(),
+            vec![
+                (1, PropVariant::I2(1200u16 as i16).to_bytes()),
+                (15, PropVariant::LPStr("Contoso".to_string()).to_bytes()),
+                (16, PropVariant::Bool(false).to_bytes()),
+            ],
+        ),
+        (
+            Guid::new(0xD5CDD505, 0x2E9C, 0x101B, [0x93, 0x97, 0x08, 0x00, 0x2B, 0x2C, 0xF9, 0xAE]).to_bytes(),
+            vec![
+                (0, user_defined),
+                (1, PropVariant::I2(1200u16 as i16).to_bytes()),
+                (2, PropVariant::LPStr("Fabrikam".to_string()).to_bytes()),
+                (3, PropVariant::Bool(true).to_bytes()),
+            ],
+        ),
+    ]);
+
+    let stream = PropertySetStream::from_buffer(&summary).unwrap();
+    assert_eq!(stream.property_sets[0].code_page, 1252);
+    assert_eq!(stream.property_sets[0].properties[0].name.as_deref(), Some("System.Title"));
+
+    let data = compound_file(&[
+        ("\u{5}SummaryInformation", summary),
+        ("\u{5}DocumentSummaryInformation", document_summary),
+    ]);
+    let mut compound_file = CompoundFile::from_reader(std::io::Cursor::new(data)).unwrap();
+    let summary = SummaryInformation::from_compound_file(&mut compound_file).unwrap();
+    assert_eq!(summary.title.as_deref(), Some("Q3 Résumé"));
+    assert_eq!(summary.author.as_deref(), Some("alice"));
+    assert_eq!(summary.last_saved_by.as_deref(), Some("bob"));
+    assert_eq!(summary.template.as_deref(), Some("Normal.dotm"));
+    assert_eq!(summary.total_editing_time, Some(300));
+    assert_eq!(summary.created.as_ref().unwrap().to_string(), "2020-12-05T12:19:48Z");
+    assert!(summary.last_saved.is_none());
+    assert_eq!(summary.page_count, Some(3));
+    println!("{}", serde_json::to_string(&summary).unwrap());
+
+    let document_summary = DocumentSummaryInformation::from_compound_file(&mut compound_file).unwrap();
+    assert_eq!(document_summary.company.as_deref(), Some("Contoso"));
+    assert_eq!(document_summary.links_dirty, Some(false));
+    assert!(matches!(document_summary.user_defined_properties.get("Client"), Some(PropVariant::LPStr(client)) if client == "Fabrikam"));
+    assert!(matches!(document_summary.user_defined_properties.get("Reviewed"), Some(PropVariant::Bool(true))));
+    println!("{}", serde_json::to_string(&document_summary).unwrap());
+}
+
+/// Build a DestList stream with an entry for every `(entry_id, pin_status, path)`.
+#[cfg(test)]
+fn dest_list(version: u32, entries: &[(u32, i32, &str)]) -> Vec<u8> {
+    let mut data = vec![];
+    for v in [version, entries.len() as u32, 1, 0, entries.len() as u32, 0, 7, 0] {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    for (entry_id, pin_status, path) in entries {
+        data.extend_from_slice(&0u64.to_le_bytes());
+        data.extend_from_slice(&[0x11; 16]);
+        data.extend_from_slice(&Guid::new(0x94AB3B4E, 0x3C0B, 0x11EE, [0x82, 0x3A, 0x00, 0x0C, 0x29, 0xAA, 0xBB, 0xCC]).to_bytes());
+        data.extend_from_slice(&[0x11; 32]);
+        let mut netbios_name = b"desktop-pc".to_vec();
+        netbios_name.resize(16, 0);
+        data.extend_from_slice(&netbios_name);
+        data.extend_from_slice(&entry_id.to_le_bytes());
+        data.extend_from_slice(&3.0f32.to_le_bytes());
+        data.extend_from_slice(&132516443881399495u64.to_le_bytes());
+        data.extend_from_slice(&pin_status.to_le_bytes());
+        if version > 1 {
+            data.extend_from_slice(&u32::MAX.to_le_bytes());
+            data.extend_from_slice(&5u32.to_le_bytes());
+            data.extend_from_slice(&0u64.to_le_bytes());
+        }
+        data.extend_from_slice(&(path.encode_utf16().count() as u16).to_le_bytes());
+        data.extend(path.encode_utf16().flat_map(|c| c.to_le_bytes()));
+        if version > 1 {
+            data.extend_from_slice(&0u32.to_le_bytes());
+        }
+    }
+    data
+}
+
+#[cfg(test)]
+#[test]
+fn automatic_destinations_test() {
+    let lnk = ShellLinkBuilder::new()
+        .local_base_path("C:\\Users\\user\\Documents\\report.docx", 3, 0x1234ABCD, "OS")
+        .build()
+        .unwrap();
+    for version in [1, 4] {
+        let entries = [(0x1, -1, "C:\\Users\\user\\Documents\\report.docx"), (0xA, 0, "C:\\missing.txt")];
+        let data = compound_file(&[("1", lnk.clone()), ("DestList", dest_list(version, &entries))]);
+        let jump_list = AutomaticDestinations::from_reader(&mut std::io::Cursor::new(data)).unwrap();
+        assert_eq!(jump_list.version, version);
+        assert_eq!(jump_list.revision, 7);
+        assert_eq!(jump_list.entries.len(), 2);
+
+        let entry = &jump_list.entries[0];
+        assert_eq!(entry.dest_list_entry.netbios_name, "desktop-pc");
+        assert_eq!(entry.dest_list_entry.mac_address, "00:0c:29:aa:bb:cc");
+        assert_eq!(entry.dest_list_entry.last_access.to_string(), "2020-12-05T12:19:48Z");
+        assert_eq!(entry.dest_list_entry.access_count, if version == 1 { 3 } else { 5 });
+        assert!(!entry.dest_list_entry.pinned);
+        assert_eq!(
+            entry.link.as_ref().unwrap().path().as_deref(),
+            Some("C:\\Users\\user\\Documents\\report.docx")
+        );
+
+        let entry = &jump_list.entries[1];
+        assert_eq!(entry.dest_list_entry.stream_name(), "a");
+        assert_eq!(entry.dest_list_entry.pin_order, Some(0));
+        assert_eq!(entry.dest_list_entry.path, "C:\\missing.txt");
+        assert!(entry.link.is_none());
+    }
+    for version in [0, 2, 5] {
+        let entries = [(0x1, -1, "C:\\Windows\\notepad.exe")];
+        let data = compound_file(&[("1", lnk.clone()), ("DestList", dest_list(version, &entries))]);
+        assert!(AutomaticDestinations::from_reader(&mut std::io::Cursor::new(data)).is_err());
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn custom_destinations_test() {
+    let link = |url: &str| {
+        let mut entry = Guid::new(0x00021401, 0, 0, [0xC0, 0, 0, 0, 0, 0, 0, 0x46]).to_bytes().to_vec();
+        entry.extend(ShellLinkBuilder::new().name(url).arguments(url).build().unwrap());
+        entry
+    };
+    let footer = 0xBABFFBABu32.to_le_bytes();
+    let mut data = vec![];
+    for v in [2u32, 3, 0] {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    // Custom category with a corrupt entry between two valid entries
+    data.extend_from_slice(&0u32.to_le_bytes());
+    data.extend_from_slice(&(12u16).to_le_bytes());
+    data.extend("Pinned sites".encode_utf16().flat_map(|c| c.to_le_bytes()));
+    data.extend_from_slice(&3u32.to_le_bytes());
+    data.extend(link("https://example.com"));
+    let mut corrupt = link("https://corrupt.example.com");
+    corrupt[16 + 4] = 0xFF; // invalid LinkCLSID in the LNK header
+    data.extend(corrupt);
+    data.extend(link("https://example.org"));
+    data.extend_from_slice(&footer);
+    // Known category
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&footer);
+    // Tasks category
+    data.extend_from_slice(&2u32.to_le_bytes());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend(link("/new-window"));
+    data.extend_from_slice(&footer);
+
+    let jump_list = CustomDestinations::from_buffer(&data).unwrap();
+    assert_eq!(jump_list.version, 2);
+    assert!(jump_list.error.is_none());
+    assert_eq!(jump_list.categories.len(), 3);
+
+    let custom = &jump_list.categories[0];
+    assert_eq!(custom.category_type, CategoryType::Custom);
+    assert_eq!(custom.name.as_deref(), Some("Pinned sites"));
+    assert_eq!(custom.entries.len(), 3);
+    let arguments = |entry: &CustomDestinationsEntry| {
+        entry.link.as_ref().map(|link| link.command_line_arguments.as_ref().unwrap().string.clone())
+    };
+    assert_eq!(arguments(&custom.entries[0]).as_deref(), Some("https://example.com"));
+    assert!(custom.entries[1].link.is_none());
+    assert!(custom.entries[1].error.is_some());
+    assert_eq!(arguments(&custom.entries[2]).as_deref(), Some("https://example.org"));
+
+    assert_eq!(jump_list.categories[1].known_category, Some(KnownCategory::Recent));
+    assert_eq!(jump_list.categories[2].category_type, CategoryType::Tasks);
+    assert_eq!(arguments(&jump_list.categories[2].entries[0]).as_deref(), Some("/new-window"));
+
+    // Tasks category with more entries than the file has, truncated in the second entry
+    let mut data = vec![];
+    for v in [2u32, 1, 0, 2, 0xFFFFFFFF] {
+        data.extend_from_slice(&v.to_le_bytes());
+    }
+    data.extend(link("/new-window"));
+    let second = link("/new-private-window");
+    data.extend_from_slice(&second[..second.len() / 2]);
+    let jump_list = CustomDestinations::from_buffer(&data).unwrap();
+    assert_eq!(jump_list.categories.len(), 1);
+    let entries = &jump_list.categories[0].entries;
+    assert_eq!(entries.len(), 2);
+    assert_eq!(arguments(&entries[0]).as_deref(), Some("/new-window"));
+    assert!(entries[1].error.is_some());
+}
+
+#[cfg(test)]
+#[test]
+fn jump_list_app_id_test() {
+    assert_eq!(app_id("Microsoft.Windows.Explorer"), "f01b4d95cf55d32a");
+    assert_eq!(app_id_from_path("C:\\Windows\\System32\\notepad.exe"), "9b9cdc69c1c24e2b");
+    assert_eq!(app_id_from_path("c:\\windows\\explorer.exe"), "1b4dd67f29cb1962");
+    assert_eq!(
+        app_id_from_path("D:\\Program Files (x86)\\Google\\Chrome\\Application\\chrome.exe"),
+        "116351484fbe207a"
+    );
+    assert_eq!(app_id_name("9B9CDC69C1C24E2B"), Some("Notepad (64-bit)"));
+    assert_eq!(app_id_name(&app_id("Chrome")), Some("Google Chrome"));
+    assert_eq!(app_id_name("0000000000000000"), None);
+
+    let path = std::env::temp_dir().join("1b4dd67f29cb1962.customDestinations-ms");
+    std::fs::write(&path, [2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+    let jump_list = CustomDestinations::from_path(path.to_str().unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(jump_list.app_id.as_deref(), Some("1b4dd67f29cb1962"));
+    assert_eq!(jump_list.application_name(), Some("Windows Explorer (Pinned and Recent)"));
+}
+
+#[cfg(test)]
+#[test]
+fn system_time_test() {
+    let data = [0xE5, 0x07, 0x03, 0x00, 0x05, 0x00, 0x05, 0x00, 0x0E, 0x00, 0x09, 0x00, 0x1E, 0x00, 0xF4, 0x01];
+    let system_time = SystemTime::from_buffer(&data).unwrap();
+    assert!(system_time.is_valid());
+    assert_eq!(system_time.to_string(), "2021-03-05T14:09:30Z");
+    assert_eq!(system_time.to_datetime().unwrap().timestamp_millis(), 1614953370500);
+    assert_eq!(system_time.to_bytes(), data);
+    assert_eq!(serde_json::to_string(&system_time).unwrap(), "\"2021-03-05T14:09:30Z\"");
+
+    let mut invalid = system_time.clone();
+    invalid.day = 31;
+    invalid.month = 4;
+    assert!(invalid.validate().unwrap_err().to_string().ends_with("SystemTime : Invalid day '31'"));
+    assert!(invalid.to_datetime().is_none());
+    assert_eq!(invalid.to_string(), "2021-04-31T14:09:30Z");
+    let empty = SystemTime::from_buffer(&[0; 16]).unwrap();
+    assert!(empty.is_empty());
+    assert_eq!(empty.to_string(), "0000-00-00T00:00:00Z");
+
+    // The last Sunday of March at 02:00 and the first Sunday of November at 02:00
+    let dst_start = SystemTime::from_buffer(&[0, 0, 3, 0, 0, 0, 5, 0, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+    let rule = dst_start.day_of_week_in_month().unwrap();
+    assert_eq!(dst_start.to_string(), "Last Sunday of March at 02:00:00");
+    assert_eq!(rule.date_in_year(2021).unwrap().to_string(), "2021-03-28 02:00:00");
+    assert_eq!(rule.date_in_year(2020).unwrap().to_string(), "2020-03-29 02:00:00");
+    let dst_end = SystemTime::from_buffer(&[0, 0, 11, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap();
+    assert_eq!(dst_end.day_of_week_in_month().unwrap().date_in_year(2021).unwrap().to_string(), "2021-11-07 02:00:00");
+}
+
+#[cfg(test)]
+#[test]
+fn ole_date_dot_net_ticks_test() {
+    let ole_date = OleDate::from_buffer(&44260.5_f64.to_le_bytes()).unwrap();
+    assert_eq!(ole_date.to_string(), "2021-03-05T12:00:00Z");
+    assert_eq!(serde_json::to_string(&ole_date).unwrap(), "\"2021-03-05T12:00:00Z\"");
+    assert_eq!(ole_date.to_bytes(), 44260.5_f64.to_le_bytes());
+    // The fractional part of negative dates is added to the day
+    assert_eq!(OleDate::new(-1.25).to_string(), "1899-12-29T06:00:00Z");
+    assert_eq!(OleDate::new(-0.5).to_string(), "1899-12-30T12:00:00Z");
+    assert_eq!(OleDate::new(-1.0).to_string(), "1899-12-29T00:00:00Z");
+    assert_eq!(OleDate::new(OleDate::MIN).to_string(), "0100-01-01T00:00:00Z");
+    assert_eq!(OleDate::new(f64::NAN).to_datetime(), None);
+    assert_eq!(OleDate::new(3_000_000.0).to_string(), "3000000");
+    let date_time = OleDate::new(-1.25).to_datetime().unwrap();
+    assert_eq!(OleDate::from(date_time).to_f64(), -1.25);
+    assert_eq!(OleDate::from(ole_date.to_datetime().unwrap()), ole_date);
+    // 1/3 of a day is rounded to milliseconds
+    assert_eq!(OleDate::new(1.0 / 3.0).to_datetime().unwrap().timestamp_millis() % 1000, 0);
+
+    let ticks = DotNetTicks::from_buffer(&0x48D8DFE04B2F8900_u64.to_le_bytes()).unwrap();
+    assert_eq!(ticks.kind(), DateTimeKind::Utc);
+    assert_eq!(ticks.ticks(), 637505501700000000);
+    assert_eq!(ticks.to_string(), "2021-03-05T14:09:30Z");
+    assert_eq!(serde_json::to_string(&ticks).unwrap(), "\"2021-03-05T14:09:30Z\"");
+    assert_eq!(DotNetTicks::from(ticks.to_datetime().unwrap()), ticks);
+    let unspecified = DotNetTicks::from_ticks(637505501700000001, DateTimeKind::Unspecified);
+    assert_eq!(unspecified.to_u64(), 637505501700000001);
+    assert_eq!(unspecified.to_datetime().unwrap().timestamp_subsec_nanos(), 100);
+    // 0001-01-01 00:00 local time with a +02:00 offset is stored as negative UTC ticks
+    let local = DotNetTicks::new(0xBFFFFFEF3C773000);
+    assert_eq!(local.kind(), DateTimeKind::Local);
+    assert_eq!(local.ticks(), -72_000_000_000);
+    assert_eq!(local.to_string(), "0000-12-31T22:00:00Z");
+    assert_eq!(DotNetTicks::new(0x3FFF_FFFF_FFFF_FFFF).to_string(), "4611686018427387903");
+}
+
+#[cfg(test)]
+#[test]
+fn file_time_precision_test() {
+    let file_time = FileTime::try_new(132516443881399495).unwrap();
+    assert_eq!(file_time.to_u64(), 132516443881399495);
+    assert_eq!(file_time.state(), FileTimeState::Set);
+    assert_eq!(file_time.to_string(), "2020-12-05T12:19:48Z");
+    assert_eq!(format!("{:#}", file_time), "2020-12-05T12:19:48.1399495Z");
+    assert_eq!(file_time.to_datetime().timestamp_subsec_nanos(), 139949500);
+    assert_eq!(FileTime::from(file_time.to_datetime()), file_time);
+
+    let not_set = FileTime::new(0);
+    assert_eq!(not_set.state(), FileTimeState::NotSet);
+    assert_eq!(format!("{:#}", not_set), "1601-01-01T00:00:00.0000000Z");
+    let never = FileTime::try_new(FileTime::NEVER).unwrap();
+    assert_eq!(never.state(), FileTimeState::Never);
+    assert_eq!(format!("{:#}", never), "+30828-09-14T02:48:05.4775807Z");
+    assert!(FileTime::try_new(u64::MAX)
+        .unwrap_err()
+        .to_string()
+        .ends_with("FileTime : Invalid timestamp '0xFFFFFFFFFFFFFFFF'"));
+    // Values above the "never" sentinel still convert without overflowing
+    assert_eq!(FileTime::new(u64::MAX).to_datetime().timestamp(), 1833029933770);
+}
+
+#[cfg(test)]
+#[test]
+fn dos_date_time_interpretation_test() {
+    let dos_date_time = DosDateTime::from_u32(2583122118).unwrap();
+    assert!(dos_date_time.is_valid());
+    assert_eq!(dos_date_time.interpretation(), &TimeInterpretation::Utc);
+    assert_eq!(dos_date_time.to_string(), "2020-06-06T19:15:46Z");
+    let fixed = dos_date_time.clone().with_interpretation(TimeInterpretation::FixedOffset(-150));
+    assert_eq!(fixed.to_string(), "2020-06-06T19:15:46-02:30");
+    assert_eq!(fixed.to_datetime().unwrap().to_string(), "2020-06-06 21:45:46 UTC");
+
+    // Pacific Time, daylight saving time from the second Sunday of March to the first Sunday of November
+    let pacific = TimeZoneRule {
+        bias: 480,
+        standard_bias: 0,
+        daylight_bias: -60,
+        standard_date: SystemTime::from_buffer(&[0, 0, 11, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+        daylight_date: SystemTime::from_buffer(&[0, 0, 3, 0, 0, 0, 2, 0, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+    };
+    let local = dos_date_time.clone().with_interpretation(TimeInterpretation::TimeZone(pacific.clone()));
+    assert_eq!(local.to_string(), "2020-06-06T19:15:46-07:00");
+    assert_eq!(serde_json::to_string(&local).unwrap(), "\"2020-06-06T19:15:46-07:00\"");
+    assert_eq!(local.to_datetime().unwrap().to_string(), "2020-06-07 02:15:46 UTC");
+    let winter = DosDateTime::new(2020, 12, 1, 8, 0, 0).with_interpretation(TimeInterpretation::TimeZone(pacific));
+    assert_eq!(winter.to_string(), "2020-12-01T08:00:00-08:00");
+
+    // Daylight saving time spans the end of the year in AUS Eastern Standard Time
+    let sydney = TimeZoneRule {
+        bias: -600,
+        standard_bias: 0,
+        daylight_bias: -60,
+        standard_date: SystemTime::from_buffer(&[0, 0, 4, 0, 0, 0, 1, 0, 3, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+        daylight_date: SystemTime::from_buffer(&[0, 0, 10, 0, 0, 0, 1, 0, 2, 0, 0, 0, 0, 0, 0, 0]).unwrap(),
+    };
+    assert!(sydney.is_daylight_time(&DosDateTime::new(2021, 1, 15, 12, 0, 0).to_naive_datetime().unwrap()));
+    assert_eq!(local.clone().with_interpretation(TimeInterpretation::TimeZone(sydney.clone())).to_string(), "2020-06-06T19:15:46+10:00");
+    assert_eq!(TimeZoneRule::fixed(-180).utc_offset(&local.to_naive_datetime().unwrap()), 180);
+
+    // Invalid fields are kept instead of being replaced with 1980-01-01
+    let invalid = DosDateTime::from_u32(0).unwrap();
+    assert!(!invalid.is_valid());
+    assert!(invalid.to_datetime().is_none());
+    assert_eq!(invalid.to_string(), "1980-00-00T00:00:00Z");
+    assert_eq!(invalid.with_interpretation(TimeInterpretation::TimeZone(sydney)).to_string(), "1980-00-00T00:00:00+10:00");
+    assert!(!DosDateTime::from_u32(0x001F_0021).unwrap().is_valid());
+}
+
+#[cfg(test)]
+#[test]
+fn time_zone_information_test() {
+    // The `TZI` value of Pacific Standard Time
+    let tzi = [
+        0xE0, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xC4, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x0B, 0x00, 0x00, 0x00, 0x01, 0x00, 0x02, 0x00,
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x02, 0x00, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+    ];
+    let rule = TimeZoneRule::from_buffer(&tzi).unwrap();
+    assert_eq!((rule.bias, rule.standard_bias, rule.daylight_bias), (480, 0, -60));
+    assert_eq!(rule.daylight_date.to_string(), "Second Sunday of March at 02:00:00");
+    assert_eq!(rule.to_bytes(), tzi.to_vec());
+    // Until 2006 daylight saving time started on the first Sunday of April and ended on the last Sunday of October
+    let mut rule_2006 = rule.clone();
+    rule_2006.daylight_date.month = 4;
+    rule_2006.daylight_date.day = 1;
+    rule_2006.standard_date.month = 10;
+    rule_2006.standard_date.day = 5;
+
+    let time_zone_information = TimeZoneInformation {
+        bias: 480,
+        standard_name: "Pacific Standard Time".to_string(),
+        standard_date: rule.standard_date.clone(),
+        standard_bias: 0,
+        daylight_name: "Pacific Daylight Time".to_string(),
+        daylight_date: rule.daylight_date.clone(),
+        daylight_bias: -60,
+    };
+    let bytes = time_zone_information.to_bytes();
+    assert_eq!(bytes.len(), 172);
+    assert_eq!(TimeZoneInformation::from_buffer(&bytes).unwrap(), time_zone_information);
+    assert_eq!(time_zone_information.rule(), rule);
+
+    let mut dynamic = DynamicTimeZoneInformation {
+        time_zone_information,
+        time_zone_key_name: "Pacific Standard Time".to_string(),
+        dynamic_daylight_time_disabled: false,
+    };
+    let bytes = dynamic.to_bytes();
+    assert_eq!(bytes.len(), 432);
+    let parsed = DynamicTimeZoneInformation::from_buffer(&bytes).unwrap();
+    assert_eq!(parsed, dynamic);
+    let json = serde_json::to_value(&parsed).unwrap();
+    assert_eq!(json["standard_name"], "Pacific Standard Time");
+    assert_eq!(json["time_zone_key_name"], "Pacific Standard Time");
+
+    let dynamic_rules = vec![(2006, rule_2006), (2007, rule.clone())].into_iter().collect();
+    let rules = dynamic.rules(dynamic_rules);
+    let local = |year: u16| DosDateTime::new(year, 3, 20, 12, 0, 0).to_naive_datetime().unwrap();
+    assert_eq!(rules.local_to_utc(&local(2006)).to_string(), "2006-03-20 20:00:00 UTC");
+    assert_eq!(rules.local_to_utc(&local(2007)).to_string(), "2007-03-20 19:00:00 UTC");
+    // Years before the first entry use the first rule and years after the last entry use the last rule
+    assert!(!rules.is_daylight_time(&local(1999)));
+    assert!(rules.is_daylight_time(&local(2030)));
+    let dos_date_time = DosDateTime::new(2006, 3, 20, 12, 0, 0).with_interpretation(rules.into());
+    assert_eq!(dos_date_time.to_string(), "2006-03-20T12:00:00-08:00");
+
+    dynamic.dynamic_daylight_time_disabled = true;
+    let rules = dynamic.rules(vec![(2006, TimeZoneRule::fixed(0))].into_iter().collect());
+    assert_eq!(rules.local_to_utc(&local(2006)).to_string(), "2006-03-20 19:00:00 UTC");
+}
+
+#[cfg(test)]
+#[test]
+fn timestamp_format_test() {
+    let file_time = FileTime::new(132516443881399495);
+    let dos_date_time = DosDateTime::from_u32(2583122118).unwrap();
+    assert_eq!(dos_date_time.to_u32(), 2583122118);
+    let local = dos_date_time.clone().with_interpretation(TimeInterpretation::FixedOffset(180));
+    let to_json = |format: TimestampFormat| {
+        with_timestamp_format(format, || {
+            serde_json::to_string(&(&file_time, &dos_date_time, &local, OleDate::new(-1.25))).unwrap()
+        })
+    };
+    assert_eq!(
+        to_json(TimestampFormat::Rfc3339),
+        r#"["2020-12-05T12:19:48Z","2020-06-06T19:15:46Z","2020-06-06T19:15:46+03:00","1899-12-29T06:00:00Z"]"#
+    );
+    assert_eq!(
+        to_json(TimestampFormat::Rfc3339Nanos),
+        r#"["2020-12-05T12:19:48.139949500Z","2020-06-06T19:15:46.000000000Z","2020-06-06T19:15:46.000000000+03:00","1899-12-29T06:00:00.000000000Z"]"#
+    );
+    assert_eq!(to_json(TimestampFormat::EpochMillis), "[1607170788139,1591470946000,1591460146000,-2209226400000]");
+    assert_eq!(
+        to_json(TimestampFormat::Raw),
+        r#"[132516443881399495,2583122118,{"raw":2583122118,"interpretation":{"FixedOffset":180}},-1.25]"#
+    );
+    assert_eq!(
+        to_json(TimestampFormat::RawAndFormatted),
+        r#"[{"raw":132516443881399495,"formatted":"2020-12-05T12:19:48Z"},{"raw":2583122118,"formatted":"2020-06-06T19:15:46Z"},{"raw":2583122118,"interpretation":{"FixedOffset":180},"formatted":"2020-06-06T19:15:46+03:00"},{"raw":-1.25,"formatted":"1899-12-29T06:00:00Z"}]"#
+    );
+    // Invalid timestamps are formatted when the format needs a valid timestamp
+    let invalid = DosDateTime::from_u32(0).unwrap();
+    assert_eq!(
+        serde_json::to_string(&WithTimestampFormat::new(&invalid, TimestampFormat::EpochMillis)).unwrap(),
+        "\"1980-00-00T00:00:00Z\""
+    );
+    let system_time = SystemTime::new(2021, 3, 5, 14, 9, 30, 500);
+    assert_eq!(
+        serde_json::to_string(&WithTimestampFormat::new(&system_time, TimestampFormat::Raw)).unwrap(),
+        "\"E5070300050005000E0009001E00F401\""
+    );
+    let ticks = DotNetTicks::new(0x48D8DFE04B2F8900);
+    assert_eq!(
+        serde_json::to_string(&WithTimestampFormat::new(&ticks, TimestampFormat::EpochMillis)).unwrap(),
+        "1614953370000"
+    );
+    // The scoped format is restored after the call
+    assert_eq!(timestamp_format(), TimestampFormat::Rfc3339);
+    assert_eq!(serde_json::to_string(&file_time).unwrap(), "\"2020-12-05T12:19:48Z\"");
+}
+
+/// Serialize `value` with `TimestampFormat::RawAndFormatted`, deserialize the JSON and make sure the binary form
+/// returned by `to_bytes` is the same for both values.
+#[cfg(test)]
+fn json_round_trip<T, F>(value: &T, to_bytes: F) -> T
+where
+    T: serde::Serialize + serde::de::DeserializeOwned,
+    F: Fn(&T) -> Vec<u8>,
+{
+    let json = with_timestamp_format(TimestampFormat::RawAndFormatted, || serde_json::to_string(value).unwrap());
+    let deserialized = serde_json::from_str::<T>(&json).unwrap();
+    assert_eq!(to_bytes(&deserialized), to_bytes(value));
+    deserialized
+}
+
+/// The binary form of the parts of a `ShellLink` that can be written back.
+#[cfg(test)]
+fn shell_link_bytes(shell_link: &ShellLink) -> Vec<u8> {
+    let mut bytes = vec![];
+    for time in [&shell_link.header.ctime, &shell_link.header.atime, &shell_link.header.mtime] {
+        bytes.extend_from_slice(&time.to_bytes());
+    }
+    bytes.extend_from_slice(&shell_link.header.header_size.to_le_bytes());
+    bytes.extend_from_slice(&shell_link.header.link_clsid.to_bytes());
+    if let Some(id_list) = &shell_link.link_target_id_list {
+        bytes.extend(id_list.to_bytes());
+    }
+    for string_data in [&shell_link.name_string, &shell_link.relative_path, &shell_link.working_dir].iter().filter_map(|string_data| string_data.as_ref()) {
+        bytes.extend(string_data.to_bytes(true));
+    }
+    for block in &shell_link.extra_data.blocks {
+        bytes.extend(match block {
+            ExtraDataTypes::EnvironmentVariable(block) => block.to_bytes(),
+            ExtraDataTypes::Tracker(block) => block.to_bytes(),
+            ExtraDataTypes::PropertyStore(block) => block.to_bytes(),
+            ExtraDataTypes::Unknown(block) => block.to_bytes(),
+            _ => block.signature().to_le_bytes().to_vec(),
+        });
+    }
+    bytes
+}
+
+#[cfg(test)]
+#[test]
+fn deserialize_test() {
+    let droid = Guid::new(0x94AB3B4E, 0x3C0B, 0x11EE, [0x82, 0x3A, 0x00, 0x0C, 0x29, 0xAA, 0xBB, 0xCC]);
+    let lnk = ShellLinkBuilder::new()
+        .creation_time(FileTime::new(132516443881399495))
+        .link_target_id_list(IDList::from_path("C:\\Windows\\notepad.exe").unwrap())
+        .local_base_path("C:\\Windows\\notepad.exe", 3, 0x1234ABCD, "OS")
+        .name("Notepad")
+        .environment_variable("%windir%\\notepad.exe")
+        .tracker("desktop-pc", droid.clone(), droid.clone())
+        .build()
+        .unwrap();
+    let shell_link = json_round_trip(&ShellLink::from_buffer(&lnk).unwrap(), shell_link_bytes);
+    let id_list = shell_link.link_target_id_list.as_ref().unwrap();
+    assert_eq!(id_list.split_at_offset(20).map(|(a, b)| (a.len(), b.len())), Some((1, 3)));
+    assert_eq!(shell_link.name_string.unwrap().string, "Notepad");
+    assert_eq!(shell_link.extra_data.blocks[1].signature(), 0xA0000003);
+    assert_eq!(shell_link.header.link_clsid.to_string(), "00021401-0000-0000-C000-000000000046");
+    json_round_trip(&ShellItem::from_buffer(&[0x05, 0x00, 0xAB, 0xCD, 0xEF]).unwrap(), |item| item.to_bytes());
+
+    let mut values = vec![];
+    values.extend_from_slice(&10u32.to_le_bytes());
+    values.push(0);
+    values.extend_from_slice(&PropVariant::LPWStr("notepad.exe".to_string()).to_bytes());
+    let store_data = [
+        property_storage(&Guid::from_str("{B725F130-47EF-101A-A5F1-02608C9EEBAC}").unwrap().to_bytes(), &[values]),
+        vec![0; 4],
+    ]
+    .concat();
+    let store = json_round_trip(&PropertyStore::from_buffer(&store_data).unwrap(), |store| store.to_bytes());
+    assert_eq!(store.to_bytes(), store_data);
+    let key = serde_json::from_str::<PropertyKey>("\"System.ItemNameDisplay\"").unwrap();
+    assert_eq!(key.id, 10);
+    assert_eq!(serde_json::from_str::<PropertyKey>("\"{B725F130-47EF-101A-A5F1-02608C9EEBAC}/10\"").unwrap().to_string(), "System.ItemNameDisplay");
+    let value = json_round_trip(&PropVariant::from_buffer(&PropVariant::R8(-0.25).to_bytes()).unwrap(), |v| v.to_bytes());
+    assert!(matches!(value, PropVariant::R8(v) if v == -0.25));
+    // Every value keeps its type
+    for value in [
+        PropVariant::UI4(5),
+        PropVariant::I2(-1),
+        PropVariant::Clsid(droid),
+        PropVariant::LPStr("notepad.exe".to_string()),
+        PropVariant::FileTime(FileTime::new(132516443881399495)),
+        PropVariant::Date(OleDate::new(44260.25)),
+        PropVariant::Empty,
+    ] {
+        json_round_trip(&value, |value| value.to_bytes());
+    }
+    let file_time = serde_json::to_string(&PropVariant::FileTime(FileTime::new(132516443881399495))).unwrap();
+    assert!(matches!(serde_json::from_str::<PropVariant>(&file_time).unwrap(), PropVariant::FileTime(_)));
+    // Vectors and arrays keep the type of their elements
+    for data in [
+        vec![0x02, 0x10, 0, 0, 3, 0, 0, 0, 1, 0, 0xFE, 0xFF, 3, 0, 0, 0],
+        PropVariant::Vector(PropVariantVector {
+            element_type: PropVariant::VT_FILETIME,
+            values: vec![PropVariant::FileTime(FileTime::new(132516443881399495))],
+        })
+        .to_bytes(),
+        PropVariant::Array(PropVariantArray {
+            element_type: PropVariant::VT_R4,
+            dimensions: vec![ArrayDimension { size: 2, index_offset: 0 }],
+            values: vec![PropVariant::R4(0.1), PropVariant::R4(-2.5)],
+        })
+        .to_bytes(),
+    ] {
+        json_round_trip(&PropVariant::from_buffer(&data).unwrap(), |value| value.to_bytes());
+    }
+
+    let summary = property_set_stream(&[(
+        Guid::new(0xF29F85E0, 0x4FF9, 0x1068, [0xAB, 0x91, 0x08, 0x00, 0x2B, 0x27, 0xB3, 0xD9]).to_bytes(),
+        vec![
+            (2, PropVariant::LPWStr("Q3".to_string()).to_bytes()),
+            (12, PropVariant::FileTime(FileTime::new(132516443881399495)).to_bytes()),
+        ],
+    )]);
+    let properties = |stream: &PropertySetStream| {
+        let mut bytes = vec![];
+        for property in &stream.property_sets[0].properties {
+            bytes.extend_from_slice(&property.id.to_le_bytes());
+            bytes.extend(property.value.to_bytes());
+        }
+        bytes
+    };
+    let summary_information = json_round_trip(&SummaryInformation::from_buffer(&summary).unwrap(), |summary| {
+        properties(&summary.property_set_stream)
+    });
+    assert_eq!(summary_information.created.unwrap().to_u64(), 132516443881399495);
+    json_round_trip(&PropertySetStream::from_buffer(&summary).unwrap(), properties);
+
+    let data = compound_file(&[("1", lnk), ("DestList", dest_list(4, &[(0x1, -1, "C:\\Windows\\notepad.exe")]))]);
+    json_round_trip(&AutomaticDestinations::from_reader(&mut std::io::Cursor::new(data.clone())).unwrap(), |jump_list| {
+        let mut bytes = vec![];
+        for entry in &jump_list.entries {
+            bytes.extend_from_slice(&entry.dest_list_entry.checksum.to_le_bytes());
+            bytes.extend_from_slice(&entry.dest_list_entry.last_access.to_bytes());
+            bytes.extend(entry.link.as_ref().map(shell_link_bytes).unwrap_or_default());
+        }
+        bytes
+    });
+    let compound_file = CompoundFile::from_reader(std::io::Cursor::new(data)).unwrap();
+    let entries = serde_json::from_value::<Vec<winparsingtools::cfb::DirectoryEntry>>(serde_json::to_value(&compound_file.entries).unwrap()).unwrap();
+    assert_eq!(
+        entries.iter().map(|entry| (entry.child_id, entry.right_sibling_id, entry.starting_sector)).collect::<Vec<_>>(),
+        compound_file.entries.iter().map(|entry| (entry.child_id, entry.right_sibling_id, entry.starting_sector)).collect::<Vec<_>>()
+    );
+    let header = serde_json::from_value::<winparsingtools::cfb::CompoundFileHeader>(serde_json::to_value(&compound_file.header).unwrap()).unwrap();
+    assert_eq!((header.signature, header.difat), (compound_file.header.signature, compound_file.header.difat.clone()));
+
+    // GUIDs are parsed with or without braces
+    assert_eq!(serde_json::from_str::<Guid>("\"{94ab3b4e-3c0b-11ee-823a-000c29aabbcc}\"").unwrap().to_string(), "94AB3B4E-3C0B-11EE-823A-000C29AABBCC");
+    assert!(Guid::from_str("94AB3B4E-3C0B-11EE-823A").is_err());
+
+    // Timestamps are deserialized from every format, the raw value keeps the full precision
+    let file_time = FileTime::new(132516443881399495);
+    let system_time = SystemTime::new(2021, 3, 5, 14, 9, 30, 500);
+    let local = DosDateTime::from_u32(2583122118).unwrap().with_interpretation(TimeInterpretation::FixedOffset(180));
+    for format in [TimestampFormat::Raw, TimestampFormat::RawAndFormatted, TimestampFormat::Rfc3339Nanos] {
+        let json = with_timestamp_format(format, || serde_json::to_string(&(file_time, FileTime::new(FileTime::NEVER), &system_time, &local)).unwrap());
+        let (file_time_value, never, system_time_value, local_value) =
+            serde_json::from_str::<(FileTime, FileTime, SystemTime, DosDateTime)>(&json).unwrap();
+        assert_eq!(file_time_value, file_time);
+        assert_eq!(never.state(), FileTimeState::Never);
+        assert_eq!(system_time_value.to_bytes(), system_time.to_bytes());
+        assert!(local_value == local);
+    }
+    // Integers are read as epoch milliseconds only if the input format is set
+    let json = with_timestamp_format(TimestampFormat::EpochMillis, || serde_json::to_string(&file_time).unwrap());
+    assert_eq!(serde_json::from_str::<FileTime>(&json).unwrap().to_u64(), 1607170788139);
+    let millis = with_input_timestamp_format(TimestampFormat::EpochMillis, || serde_json::from_str::<FileTime>(&json).unwrap());
+    assert_eq!(millis.to_u64(), 132516443881390000);
+    // Only the raw formats round-trip exactly, the default format drops the fractions of a second
+    let exfat = DosDateTime::from_exfat(0x50C699F7, 150, 0x8C);
+    for format in [TimestampFormat::Raw, TimestampFormat::RawAndFormatted] {
+        let json = with_timestamp_format(format, || serde_json::to_string(&(file_time, &exfat)).unwrap());
+        let (file_time_value, exfat_value) = serde_json::from_str::<(FileTime, DosDateTime)>(&json).unwrap();
+        assert!(file_time_value == file_time && exfat_value == exfat);
+        assert_eq!(exfat_value.raw_timestamp(), exfat.raw_timestamp());
+    }
+    let (file_time_value, exfat_value) =
+        serde_json::from_str::<(FileTime, DosDateTime)>(&serde_json::to_string(&(file_time, &exfat)).unwrap()).unwrap();
+    assert_eq!(file_time_value.to_u64(), 132516443880000000);
+    assert_eq!(exfat_value.to_datetime().unwrap().timestamp_subsec_millis(), 0);
+    assert!(exfat_value != exfat);
+    // The formats without the fractions of a second keep the "never" sentinel
+    assert_eq!(serde_json::from_str::<FileTime>(&serde_json::to_string(&FileTime::new(FileTime::NEVER)).unwrap()).unwrap().state(), FileTimeState::Never);
+    let dos_date_time = serde_json::from_str::<DosDateTime>("\"2020-06-06T19:15:46+03:00\"").unwrap();
+    assert_eq!(dos_date_time.interpretation(), &TimeInterpretation::FixedOffset(180));
+    assert_eq!(serde_json::from_str::<DosDateTime>("\"1980-00-00T00:00:00Z\"").unwrap().to_u32(), 0);
+    assert!(serde_json::from_str::<FileTime>("\"not a timestamp\"").is_err());
+}
+
+#[cfg(test)]
+#[test]
+fn win_timestamp_test() {
+    let dos_date_time = DosDateTime::from_u32(2583122118).unwrap();
+    assert_eq!(
+        (dos_date_time.year(), dos_date_time.month(), dos_date_time.day()),
+        (2020, 6, 6)
+    );
+    assert_eq!(
+        (dos_date_time.hour(), dos_date_time.minutes(), dos_date_time.seconds()),
+        (19, 15, 46)
+    );
+    assert_eq!(dos_date_time.raw_timestamp(), RawTimestamp::U32(2583122118));
+    assert_eq!(dos_date_time.precision(), TimestampPrecision::TwoSeconds);
+    assert_eq!(FileTime::new(1).precision().to_duration(), chrono::Duration::nanoseconds(100));
+    assert!(WinTimestamp::to_datetime(&FileTime::new(0x8000000000000000)).is_none());
+    assert!(WinTimestamp::to_datetime(&DosDateTime::from_u32(0).unwrap()).is_none());
+
+    let mut timeline: Vec<Box<dyn WinTimestamp>> = vec![
+        Box::new(SystemTime::new(2021, 3, 5, 14, 9, 30, 500)),
+        Box::new(dos_date_time.clone().with_interpretation(TimeInterpretation::FixedOffset(180))),
+        Box::new(DotNetTicks::new(0x48D8DFE04B2F8900)),
+        Box::new(DosDateTime::from_u32(0).unwrap()),
+        Box::new(FileTime::new(132516443881399495)),
+        Box::new(dos_date_time),
+        Box::new(OleDate::new(44260.5)),
+    ];
+    timeline.sort();
+    assert_eq!(
+        timeline.iter().map(|timestamp| timestamp.to_string()).collect::<Vec<String>>(),
+        [
+            "1980-00-00T00:00:00Z",
+            "2020-06-06T19:15:46+03:00",
+            "2020-06-06T19:15:46Z",
+            "2020-12-05T12:19:48Z",
+            "2021-03-05T12:00:00Z",
+            "2021-03-05T14:09:30Z",
+            "2021-03-05T14:09:30Z",
+        ]
+    );
+    // Timestamps of different types are compared by their UTC time, including the milliseconds
+    assert_eq!(timeline[5].cmp(&timeline[6]), std::cmp::Ordering::Less);
+    let dos_date_time: &dyn WinTimestamp = &DosDateTime::new(2021, 3, 5, 14, 9, 30);
+    assert!(dos_date_time == &DotNetTicks::new(0x48D8DFE04B2F8900) as &dyn WinTimestamp);
+}
+
+#[cfg(test)]
+#[test]
+fn fat_exfat_date_time_test() {
+    // FAT directory entry: the time word, the date word and the create time fine byte
+    let fat = DosDateTime::from_date_time(0x50C6, 0x99F7);
+    assert!(fat == DosDateTime::from_u32(0x99F750C6).unwrap());
+    assert_eq!(fat.precision(), TimestampPrecision::TwoSeconds);
+    let fine = fat.clone().with_hundredths(199);
+    assert_eq!(fine.hundredths(), Some(199));
+    assert_eq!(fine.precision(), TimestampPrecision::TenMilliseconds);
+    assert_eq!(fine.to_string(), "2020-06-06T19:15:47Z");
+    assert_eq!(fine.to_datetime().unwrap().timestamp_millis(), 1591470947990);
+    assert_eq!(fine.to_u32(), 0x99F750C6);
+    assert!(!fat.clone().with_hundredths(200).is_valid());
+
+    // exFAT directory entry: the UTC offset is used only if its valid bit is set
+    let exfat = DosDateTime::from_exfat(0x50C699F7, 5, 0xEC);
+    assert_eq!(exfat.interpretation(), &TimeInterpretation::FixedOffset(-300));
+    assert_eq!(exfat.to_datetime().unwrap().to_string(), "2020-06-07 00:15:46.050 UTC");
+    assert_eq!(DosDateTime::from_exfat(0x50C699F7, 0, 0x80).to_string(), "2020-06-06T19:15:46+00:00");
+    // Without the valid bit the offset is unknown
+    let unknown = DosDateTime::from_exfat(0x50C699F7, 0, 0x6C);
+    assert_eq!(unknown.interpretation(), &TimeInterpretation::UnknownLocal);
+    assert_eq!(unknown.to_string(), "2020-06-06T19:15:46");
+    assert_eq!(unknown.to_datetime().unwrap().to_string(), "2020-06-06 19:15:46 UTC");
+    let unknown_json = serde_json::to_string(&unknown).unwrap();
+    assert_eq!(serde_json::from_str::<DosDateTime>(&unknown_json).unwrap().interpretation(), &TimeInterpretation::UnknownLocal);
+
+    // The raw value keeps the three exFAT fields and the FAT create time fine byte
+    assert_eq!(exfat.raw_timestamp(), RawTimestamp::ExFat { timestamp: 0x50C699F7, increment_10ms: 5, utc_offset: 0xEC });
+    assert_eq!(fine.raw_timestamp(), RawTimestamp::Fat { date_time: 0x99F750C6, increment_10ms: 199 });
+    let json = with_timestamp_format(TimestampFormat::Raw, || serde_json::to_string(&(&exfat, &unknown, &fine)).unwrap());
+    assert_eq!(
+        json,
+        "[{\"raw\":{\"timestamp\":1355192823,\"increment_10ms\":5,\"utc_offset\":236},\"interpretation\":{\"FixedOffset\":-300}},\
+        {\"raw\":{\"timestamp\":1355192823,\"increment_10ms\":0,\"utc_offset\":108},\"interpretation\":\"UnknownLocal\"},\
+        {\"date_time\":2583122118,\"increment_10ms\":199}]"
+    );
+    let (exfat_value, unknown_value, fine_value) = serde_json::from_str::<(DosDateTime, DosDateTime, DosDateTime)>(&json).unwrap();
+    assert!(exfat_value == exfat && unknown_value == unknown && fine_value == fine);
+
+    // The 10 ms increment is kept by the formats with sub-second precision
+    let json = with_timestamp_format(TimestampFormat::Rfc3339Nanos, || serde_json::to_string(&exfat).unwrap());
+    assert_eq!(json, "\"2020-06-06T19:15:46.050000000-05:00\"");
+    assert_eq!(serde_json::from_str::<DosDateTime>(&json).unwrap().to_datetime(), exfat.to_datetime());
+    let json = with_timestamp_format(TimestampFormat::EpochMillis, || serde_json::to_string(&fine).unwrap());
+    let millis = with_input_timestamp_format(TimestampFormat::EpochMillis, || serde_json::from_str::<DosDateTime>(&json).unwrap());
+    assert!(millis == fine);
+}